@@ -89,6 +89,11 @@ where
         self.inner.canonical_head_number.load(Ordering::Relaxed)
     }
 
+    /// Returns the timestamp of the canonical head.
+    pub fn get_canonical_timestamp(&self) -> u64 {
+        self.inner.canonical_head.read().timestamp()
+    }
+
     /// Returns the `BlockNumHash` of the safe header.
     pub fn get_safe_num_hash(&self) -> Option<BlockNumHash> {
         self.inner.safe_block.borrow().as_ref().map(SealedHeader::num_hash)
@@ -248,6 +253,7 @@ mod tests {
         // Assert that the tracker now uses the second header as its canonical head
         let canonical_head = tracker.get_canonical_head();
         assert_eq!(canonical_head, header2);
+        assert_eq!(tracker.get_canonical_timestamp(), header2.timestamp);
     }
 
     #[test]
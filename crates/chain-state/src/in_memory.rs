@@ -13,8 +13,8 @@ use reth_ethereum_primitives::EthPrimitives;
 use reth_execution_types::{BlockExecutionOutput, BlockExecutionResult, Chain, ExecutionOutcome};
 use reth_metrics::{metrics::Gauge, Metrics};
 use reth_primitives_traits::{
-    BlockBody as _, IndexedTx, NodePrimitives, RecoveredBlock, SealedBlock, SealedHeader,
-    SignedTransaction,
+    BlockBody as _, InMemorySize, IndexedTx, NodePrimitives, RecoveredBlock, SealedBlock,
+    SealedHeader, SignedTransaction,
 };
 use reth_storage_api::StateProviderBox;
 use reth_trie::{
@@ -133,10 +133,15 @@ impl<N: NodePrimitives> InMemoryState<N> {
         self.pending.borrow().clone()
     }
 
-    #[cfg(test)]
-    fn block_count(&self) -> usize {
+    /// Returns the number of blocks in the in-memory state.
+    pub(crate) fn block_count(&self) -> usize {
         self.blocks.read().len()
     }
+
+    /// Returns an estimate of the memory held by the blocks in the in-memory state, in bytes.
+    pub(crate) fn bytes_estimate(&self) -> usize {
+        self.blocks.read().values().map(|state| state.block_ref().size_estimate()).sum()
+    }
 }
 
 /// Inner type to provide in memory state. It includes a chain tracker to be
@@ -403,6 +408,21 @@ impl<N: NodePrimitives> CanonicalInMemoryState<N> {
             .map(|state| BlockNumHash { number: state.number(), hash: state.hash() })
     }
 
+    /// Returns the number of canonical blocks held in memory that are not persisted yet.
+    ///
+    /// This is bounded by the engine's persistence threshold and can be used to tune it.
+    pub fn buffered_block_count(&self) -> usize {
+        self.inner.in_memory_state.block_count()
+    }
+
+    /// Returns an approximate memory footprint, in bytes, of the canonical blocks held in memory
+    /// that are not persisted yet.
+    ///
+    /// See [`ExecutedBlock::size_estimate`] for what is accounted for.
+    pub fn buffered_bytes_estimate(&self) -> usize {
+        self.inner.in_memory_state.bytes_estimate()
+    }
+
     /// Returns the current `ChainInfo`.
     pub fn chain_info(&self) -> ChainInfo {
         self.inner.chain_info_tracker.chain_info()
@@ -413,6 +433,11 @@ impl<N: NodePrimitives> CanonicalInMemoryState<N> {
         self.inner.chain_info_tracker.get_canonical_block_number()
     }
 
+    /// Returns the timestamp of the latest canonical block.
+    pub fn get_canonical_timestamp(&self) -> u64 {
+        self.inner.chain_info_tracker.get_canonical_timestamp()
+    }
+
     /// Returns the `BlockNumHash` of the safe head.
     pub fn get_safe_num_hash(&self) -> Option<BlockNumHash> {
         self.inner.chain_info_tracker.get_safe_num_hash()
@@ -841,6 +866,14 @@ impl<N: NodePrimitives> ExecutedBlock<N> {
         &self.execution_output
     }
 
+    /// Returns an approximate memory footprint of the block and its receipts, in bytes.
+    ///
+    /// The bundle state and trie data are not accounted for.
+    pub fn size_estimate(&self) -> usize {
+        self.recovered_block.size() +
+            self.execution_output.receipts.iter().map(InMemorySize::size).sum::<usize>()
+    }
+
     /// Returns the trie data, computing it synchronously if not already cached.
     ///
     /// Uses `OnceLock::get_or_init` internally:
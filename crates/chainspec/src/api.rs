@@ -7,7 +7,7 @@ use alloy_primitives::{B256, U256};
 use core::fmt::{Debug, Display};
 use reth_ethereum_forks::EthereumHardforks;
 use reth_network_peers::NodeRecord;
-use reth_primitives_traits::{AlloyBlockHeader, BlockHeader};
+use reth_primitives_traits::{header::HeaderMut, AlloyBlockHeader, BlockHeader, SealedHeader};
 
 /// Trait representing type configuring a chain spec.
 #[auto_impl::auto_impl(&, Arc)]
@@ -135,3 +135,29 @@ impl<H: BlockHeader> EthChainSpec for ChainSpec<H> {
         self.get_final_paris_total_difficulty()
     }
 }
+
+/// Trait for chain specs whose genesis header can be replaced.
+pub trait GenesisHeaderMut: EthChainSpec {
+    /// Replaces the genesis header, and with it the genesis hash, of the chain spec.
+    fn set_genesis_header(&mut self, header: SealedHeader<Self::Header>);
+
+    /// Overrides the state root of the genesis header.
+    ///
+    /// This is intended for chains with a genesis state root that differs from the one computed
+    /// from the allocation, e.g. because of predeployed precompiles. Note that the genesis hash,
+    /// and thus the fork ID, changes with the state root.
+    fn set_genesis_state_root(&mut self, state_root: B256)
+    where
+        Self::Header: HeaderMut,
+    {
+        let mut header = self.genesis_header().clone();
+        header.set_state_root(state_root);
+        self.set_genesis_header(SealedHeader::seal_slow(header));
+    }
+}
+
+impl<H: BlockHeader> GenesisHeaderMut for ChainSpec<H> {
+    fn set_genesis_header(&mut self, header: SealedHeader<Self::Header>) {
+        self.genesis_header = header;
+    }
+}
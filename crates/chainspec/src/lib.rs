@@ -26,7 +26,7 @@ pub use alloy_chains::{Chain, ChainKind, NamedChain};
 pub use reth_ethereum_forks::*;
 
 pub use alloy_evm::EvmLimitParams;
-pub use api::EthChainSpec;
+pub use api::{EthChainSpec, GenesisHeaderMut};
 pub use info::ChainInfo;
 #[cfg(any(test, feature = "test-utils"))]
 pub use spec::test_fork_ids;
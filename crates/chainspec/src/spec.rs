@@ -1329,6 +1329,22 @@ mod tests {
         }
     }
 
+    #[test]
+    fn genesis_state_root_override() {
+        use crate::GenesisHeaderMut;
+
+        let mut spec = SEPOLIA.as_ref().clone();
+        let state_root = B256::with_last_byte(1);
+        spec.set_genesis_state_root(state_root);
+
+        assert_eq!(spec.genesis_header().state_root, state_root);
+        assert_eq!(spec.genesis_hash(), spec.genesis_header.hash_slow());
+        assert_ne!(spec.genesis_hash(), SEPOLIA_GENESIS_HASH);
+        // the fork ID is derived from the overridden genesis hash
+        assert_eq!(spec.fork_id(&Head::default()).hash, ForkHash::from(spec.genesis_hash()));
+        assert_ne!(spec.latest_fork_id(), SEPOLIA.latest_fork_id());
+    }
+
     #[test]
     fn test_hardfork_list_display_mainnet() {
         assert_eq!(
@@ -4,7 +4,8 @@ use crate::launcher::Launcher;
 use clap::{value_parser, Args, Parser};
 use reth_chainspec::{EthChainSpec, EthereumHardforks};
 use reth_cli::chainspec::ChainSpecParser;
-use reth_cli_runner::CliContext;
+use reth_cli_runner::{CliContext, DEFAULT_GRACEFUL_SHUTDOWN_TIMEOUT};
+use reth_cli_util::parse_duration_from_secs_or_ms;
 use reth_db::init_db;
 use reth_node_builder::NodeBuilder;
 use reth_node_core::{
@@ -16,7 +17,7 @@ use reth_node_core::{
     node_config::NodeConfig,
     version,
 };
-use std::{ffi::OsString, fmt, path::PathBuf, sync::Arc};
+use std::{ffi::OsString, fmt, path::PathBuf, sync::Arc, time::Duration};
 
 /// Start the node
 #[derive(Debug, Parser)]
@@ -67,6 +68,18 @@ pub struct NodeCommand<C: ChainSpecParser, Ext: clap::Args + fmt::Debug = NoArgs
     #[arg(long, conflicts_with = "instance", global = true)]
     pub with_unused_ports: bool,
 
+    /// Maximum time to wait for tasks to finish during graceful shutdown before forcing exit.
+    ///
+    /// Tasks that are still running once the timeout elapses are logged. Accepts seconds or a
+    /// duration with a unit, e.g. `10` or `500ms`.
+    #[arg(
+        long = "shutdown-timeout",
+        value_name = "DURATION",
+        value_parser = parse_duration_from_secs_or_ms,
+        default_value = DEFAULT_GRACEFUL_SHUTDOWN_TIMEOUT.as_secs().to_string()
+    )]
+    pub shutdown_timeout: Duration,
+
     /// All datadir related arguments
     #[command(flatten)]
     pub datadir: DatadirArgs,
@@ -163,6 +176,7 @@ where
             metrics,
             instance,
             with_unused_ports,
+            shutdown_timeout: _,
             network,
             rpc,
             txpool,
@@ -179,6 +193,7 @@ where
         } = self;
 
         engine.validate()?;
+        network.validate()?;
 
         // set up node config
         let mut node_config = NodeConfig {
@@ -120,9 +120,12 @@ impl<C: ChainSpecParser<ChainSpec: EthChainSpec + Hardforks + EthereumHardforks>
         // Does not do anything on windows.
         let _ = fdlimit::raise_fd_limit();
 
+        self.network.validate()?;
+
         let runtime = ctx.task_executor.clone();
-        let Environment { provider_factory, config, data_dir } =
+        let Environment { provider_factory, mut config, data_dir } =
             self.env.init::<N>(AccessRights::RW, ctx.task_executor.clone())?;
+        self.network.apply_downloader_timeouts(&mut config);
 
         let mut provider_rw = provider_factory.database_provider_rw()?;
         let components = components(provider_factory.chain_spec());
@@ -247,6 +250,7 @@ impl<C: ChainSpecParser<ChainSpec: EthChainSpec + Hardforks + EthereumHardforks>
                                 config.stages.bodies.downloader_min_concurrent_requests..=
                                     config.stages.bodies.downloader_max_concurrent_requests,
                             )
+                            .with_request_timeout(config.stages.bodies.downloader_request_timeout)
                             .build(fetch_client, consensus.clone(), provider_factory.clone()),
                     );
                     (Box::new(stage), None)
@@ -194,7 +194,7 @@ pub struct CliContext {
 }
 
 /// Default timeout for graceful shutdown of tasks.
-const DEFAULT_GRACEFUL_SHUTDOWN_TIMEOUT: Duration = Duration::from_secs(5);
+pub const DEFAULT_GRACEFUL_SHUTDOWN_TIMEOUT: Duration = Duration::from_secs(5);
 
 /// Configuration for [`CliRunner`].
 #[derive(Debug, Clone)]
@@ -1,5 +1,5 @@
 //! Configuration files.
-use reth_network_types::{PeersConfig, SessionsConfig};
+use reth_network_types::{session::config::INITIAL_REQUEST_TIMEOUT, PeersConfig, SessionsConfig};
 use reth_prune_types::{PruneModes, MINIMUM_UNWIND_SAFE_DISTANCE};
 use reth_stages_types::ExecutionStageThresholds;
 use reth_static_file_types::{StaticFileMap, StaticFileSegment};
@@ -192,6 +192,11 @@ pub struct HeadersConfig {
     pub downloader_request_limit: u64,
     /// The maximum number of headers to download before committing progress to the database.
     pub commit_threshold: u64,
+    /// Timeout for a single headers request before it is retried with another peer.
+    ///
+    /// Default: 20s
+    #[cfg_attr(feature = "serde", serde(with = "humantime_serde"))]
+    pub downloader_request_timeout: Duration,
 }
 
 impl Default for HeadersConfig {
@@ -202,6 +207,7 @@ impl Default for HeadersConfig {
             downloader_max_concurrent_requests: 100,
             downloader_min_concurrent_requests: 5,
             downloader_max_buffered_responses: 100,
+            downloader_request_timeout: INITIAL_REQUEST_TIMEOUT,
         }
     }
 }
@@ -232,6 +238,11 @@ pub struct BodiesConfig {
     ///
     /// Default: 100
     pub downloader_max_concurrent_requests: usize,
+    /// Timeout for a single bodies request before it is retried with another peer.
+    ///
+    /// Default: 20s
+    #[cfg_attr(feature = "serde", serde(with = "humantime_serde"))]
+    pub downloader_request_timeout: Duration,
 }
 
 impl Default for BodiesConfig {
@@ -242,6 +253,7 @@ impl Default for BodiesConfig {
             downloader_max_buffered_blocks_size_bytes: 2 * 1024 * 1024 * 1024, // ~2GB
             downloader_min_concurrent_requests: 5,
             downloader_max_concurrent_requests: 100,
+            downloader_request_timeout: INITIAL_REQUEST_TIMEOUT,
         }
     }
 }
@@ -347,6 +347,31 @@ pub fn validate_against_parent_eip1559_base_fee<ChainSpec: EthChainSpec + Ethere
     Ok(())
 }
 
+/// Extension trait for checking the EIP-1559 base fee of a [`SealedHeader`].
+pub trait SealedHeaderBaseFeeExt {
+    /// The type of the sealed header.
+    type Header;
+
+    /// Returns `true` if the header's base fee matches the one computed from the `parent`.
+    ///
+    /// Before London there is no base fee, so this always returns `true`. See also
+    /// [`validate_against_parent_eip1559_base_fee`].
+    fn is_base_fee_valid<ChainSpec>(&self, parent: &Self, chain_spec: &ChainSpec) -> bool
+    where
+        ChainSpec: EthChainSpec<Header = Self::Header> + EthereumHardforks;
+}
+
+impl<H: BlockHeader> SealedHeaderBaseFeeExt for SealedHeader<H> {
+    type Header = H;
+
+    fn is_base_fee_valid<ChainSpec>(&self, parent: &Self, chain_spec: &ChainSpec) -> bool
+    where
+        ChainSpec: EthChainSpec<Header = H> + EthereumHardforks,
+    {
+        validate_against_parent_eip1559_base_fee(self.header(), parent.header(), chain_spec).is_ok()
+    }
+}
+
 /// Validates that the block timestamp is greater than the parent block timestamp.
 #[inline]
 pub fn validate_against_parent_timestamp<H: BlockHeader>(
@@ -597,4 +622,34 @@ mod tests {
                 if diff.0.got == wrong_root && diff.0.expected == tx_root
         ));
     }
+
+    #[test]
+    fn base_fee_valid_around_london() {
+        let chain_spec = ChainSpecBuilder::mainnet()
+            .with_fork(EthereumHardfork::London, reth_chainspec::ForkCondition::Block(10))
+            .build();
+        let header = |number, base_fee_per_gas| {
+            SealedHeader::seal_slow(Header {
+                number,
+                gas_limit: 30_000_000,
+                gas_used: 15_000_000,
+                base_fee_per_gas,
+                ..Default::default()
+            })
+        };
+
+        // pre-London headers have no base fee to check
+        assert!(header(9, None).is_base_fee_valid(&header(8, None), &chain_spec));
+
+        // the London block must use the initial base fee
+        let london = header(10, Some(alloy_eips::eip1559::INITIAL_BASE_FEE));
+        assert!(london.is_base_fee_valid(&header(9, None), &chain_spec));
+        assert!(!header(10, Some(1)).is_base_fee_valid(&header(9, None), &chain_spec));
+        assert!(!header(10, None).is_base_fee_valid(&header(9, None), &chain_spec));
+
+        // the parent used exactly its gas target, so the base fee stays the same
+        let next = header(11, Some(alloy_eips::eip1559::INITIAL_BASE_FEE));
+        assert!(next.is_base_fee_valid(&london, &chain_spec));
+        assert!(!header(11, Some(1)).is_base_fee_valid(&london, &chain_spec));
+    }
 }
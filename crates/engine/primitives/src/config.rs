@@ -1,7 +1,11 @@
 //! Engine tree configuration.
 
+use alloc::sync::Arc;
 use alloy_eips::merge::EPOCH_SLOTS;
-use core::time::Duration;
+use core::{
+    sync::atomic::{AtomicU64, Ordering},
+    time::Duration,
+};
 
 /// Triggers persistence when the number of canonical blocks in memory exceeds this threshold.
 pub const DEFAULT_PERSISTENCE_THRESHOLD: u64 = 2;
@@ -39,6 +43,12 @@ pub const DEFAULT_SPARSE_TRIE_MAX_HOT_SLOTS: usize = 1500;
 /// Limits the number of account addresses retained across prune cycles.
 pub const DEFAULT_SPARSE_TRIE_MAX_HOT_ACCOUNTS: usize = 1000;
 
+/// Default maximum depth of a reorg the engine tree applies.
+///
+/// This is far beyond the finalization window, so only reorgs that can't happen on a finalizing
+/// chain are rejected.
+pub const DEFAULT_MAX_REORG_DEPTH: u64 = EPOCH_SLOTS * 256;
+
 /// Default timeout for the state root task before spawning a sequential fallback.
 pub const DEFAULT_STATE_ROOT_TASK_TIMEOUT: Duration = Duration::from_secs(1);
 
@@ -57,6 +67,69 @@ const fn assert_backpressure_threshold_invariant(
     );
 }
 
+/// Error returned when a runtime persistence threshold update is out of bounds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+pub enum PersistenceThresholdError {
+    /// The threshold is below the minimum of one block.
+    #[error("persistence threshold must be at least 1, got {0}")]
+    TooLow(u64),
+    /// The threshold is not below the persistence backpressure threshold.
+    #[error(
+        "persistence threshold ({threshold}) must be less than the persistence backpressure threshold ({backpressure_threshold})"
+    )]
+    TooHigh {
+        /// The requested threshold.
+        threshold: u64,
+        /// The configured persistence backpressure threshold.
+        backpressure_threshold: u64,
+    },
+}
+
+/// Handle to update the persistence threshold of a running engine tree.
+///
+/// The handle is created from the [`TreeConfig`] and passed to the engine tree on launch. Updates
+/// are picked up by the engine tree the next time it checks whether blocks should be persisted.
+#[derive(Debug, Clone)]
+pub struct PersistenceThresholdHandle {
+    /// The threshold shared with the engine tree.
+    threshold: Arc<AtomicU64>,
+    /// Upper bound (exclusive) for the threshold.
+    backpressure_threshold: u64,
+}
+
+impl PersistenceThresholdHandle {
+    /// Creates a new handle, starting at the persistence threshold of the given config.
+    pub fn new(config: &TreeConfig) -> Self {
+        Self {
+            threshold: Arc::new(AtomicU64::new(config.persistence_threshold())),
+            backpressure_threshold: config.persistence_backpressure_threshold(),
+        }
+    }
+
+    /// Returns the current persistence threshold.
+    pub fn get(&self) -> u64 {
+        self.threshold.load(Ordering::Relaxed)
+    }
+
+    /// Sets the persistence threshold.
+    ///
+    /// The threshold must be at least 1 and less than the configured persistence backpressure
+    /// threshold.
+    pub fn set(&self, blocks: u64) -> Result<(), PersistenceThresholdError> {
+        if blocks < 1 {
+            return Err(PersistenceThresholdError::TooLow(blocks))
+        }
+        if blocks >= self.backpressure_threshold {
+            return Err(PersistenceThresholdError::TooHigh {
+                threshold: blocks,
+                backpressure_threshold: self.backpressure_threshold,
+            })
+        }
+        self.threshold.store(blocks, Ordering::Relaxed);
+        Ok(())
+    }
+}
+
 const fn default_cross_block_cache_size() -> usize {
     if cfg!(test) {
         1024 * 1024 // 1 MB in tests
@@ -89,6 +162,9 @@ pub fn has_enough_parallelism() -> bool {
 pub struct TreeConfig {
     /// Maximum number of blocks to be kept only in memory without triggering
     /// persistence.
+    ///
+    /// This is the initial value, the engine tree can be adjusted at runtime via a
+    /// [`PersistenceThresholdHandle`].
     persistence_threshold: u64,
     /// How close to the canonical head we persist blocks. Represents the ideal
     /// number of most recent blocks to keep in memory for quick access and reorgs.
@@ -170,6 +246,8 @@ pub struct TreeConfig {
     share_execution_cache_with_payload_builder: bool,
     /// Whether to share sparse trie with the payload builder.
     share_sparse_trie_with_payload_builder: bool,
+    /// Maximum number of canonical blocks a reorg may unwind.
+    max_reorg_depth: u64,
     /// Maximum random jitter applied before each proof computation (trie-debug only).
     /// When set, each proof worker sleeps for a random duration up to this value
     /// before starting a proof calculation.
@@ -212,6 +290,7 @@ impl Default for TreeConfig {
             state_root_task_timeout: Some(DEFAULT_STATE_ROOT_TASK_TIMEOUT),
             share_execution_cache_with_payload_builder: false,
             share_sparse_trie_with_payload_builder: false,
+            max_reorg_depth: DEFAULT_MAX_REORG_DEPTH,
             #[cfg(feature = "trie-debug")]
             proof_jitter: None,
         }
@@ -283,6 +362,7 @@ impl TreeConfig {
             state_root_task_timeout,
             share_execution_cache_with_payload_builder,
             share_sparse_trie_with_payload_builder,
+            max_reorg_depth: DEFAULT_MAX_REORG_DEPTH,
             #[cfg(feature = "trie-debug")]
             proof_jitter: None,
         }
@@ -646,6 +726,21 @@ impl TreeConfig {
         self
     }
 
+    /// Returns the maximum number of canonical blocks a reorg may unwind.
+    pub const fn max_reorg_depth(&self) -> u64 {
+        self.max_reorg_depth
+    }
+
+    /// Setter for the maximum number of canonical blocks a reorg may unwind.
+    ///
+    /// Reorgs that unwind more canonical blocks are rejected by the engine tree: forkchoice updates
+    /// fail with an error and making a downloaded sync target canonical fails with a fatal error.
+    /// The limit applies to reorgs below the finalized block as well, which are otherwise applied.
+    pub const fn with_max_reorg_depth(mut self, max_reorg_depth: u64) -> Self {
+        self.max_reorg_depth = max_reorg_depth;
+        self
+    }
+
     /// Returns the proof jitter duration, if configured (trie-debug only).
     #[cfg(feature = "trie-debug")]
     pub const fn proof_jitter(&self) -> Option<Duration> {
@@ -662,7 +757,7 @@ impl TreeConfig {
 
 #[cfg(test)]
 mod tests {
-    use super::TreeConfig;
+    use super::{PersistenceThresholdError, PersistenceThresholdHandle, TreeConfig};
 
     #[test]
     #[should_panic(
@@ -673,4 +768,26 @@ mod tests {
             .with_persistence_threshold(4)
             .with_persistence_backpressure_threshold(4);
     }
+
+    #[test]
+    fn persistence_threshold_handle() {
+        let config = TreeConfig::default()
+            .with_persistence_threshold(2)
+            .with_persistence_backpressure_threshold(8);
+        let handle = PersistenceThresholdHandle::new(&config);
+        let cloned = handle.clone();
+        assert_eq!(handle.get(), 2);
+
+        assert_eq!(handle.set(0), Err(PersistenceThresholdError::TooLow(0)));
+        assert_eq!(
+            handle.set(8),
+            Err(PersistenceThresholdError::TooHigh { threshold: 8, backpressure_threshold: 8 })
+        );
+        assert_eq!(handle.get(), 2);
+
+        handle.set(5).unwrap();
+        assert_eq!(handle.get(), 5);
+        assert_eq!(cloned.get(), 5);
+        assert_eq!(config.persistence_threshold(), 2);
+    }
 }
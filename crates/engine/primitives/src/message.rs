@@ -1,6 +1,8 @@
 use crate::{
     error::BeaconForkChoiceUpdateError, BeaconOnNewPayloadError, ExecutionPayload, ForkchoiceStatus,
 };
+use alloy_eips::BlockNumHash;
+use alloy_primitives::B256;
 use alloy_rpc_types_engine::{
     ForkChoiceUpdateResult, ForkchoiceState, ForkchoiceUpdateError, ForkchoiceUpdated, PayloadId,
     PayloadStatus, PayloadStatusEnum,
@@ -186,6 +188,19 @@ impl<T> Default for BigBlockData<T> {
     }
 }
 
+/// Information about a fork chain tracked by the engine tree.
+///
+/// See also [`ConsensusEngineHandle::tracked_forks`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ForkChainInfo {
+    /// The tip of the fork.
+    pub tip: BlockNumHash,
+    /// The hash of the canonical block the fork branches off from.
+    pub common_ancestor: B256,
+    /// The number of blocks of the fork since the common ancestor.
+    pub block_count: u64,
+}
+
 /// A message for the beacon engine from other components of the node (engine RPC API invoked by the
 /// consensus layer).
 #[derive(Debug)]
@@ -226,6 +241,18 @@ pub enum BeaconEngineMessage<Payload: PayloadTypes> {
         /// The sender for returning forkchoice updated result.
         tx: oneshot::Sender<RethResult<OnForkChoiceUpdated>>,
     },
+    /// Message requesting the fork chains currently tracked by the engine tree.
+    TrackedForks {
+        /// The sender for returning the tracked forks.
+        tx: oneshot::Sender<Vec<ForkChainInfo>>,
+    },
+    /// Message requesting to remove a block from the engine's invalid block cache.
+    ClearInvalidBlock {
+        /// The hash of the block to remove.
+        block_hash: B256,
+        /// The sender for returning whether the block was cached as invalid.
+        tx: oneshot::Sender<bool>,
+    },
 }
 
 impl<Payload: PayloadTypes> Display for BeaconEngineMessage<Payload> {
@@ -258,6 +285,10 @@ impl<Payload: PayloadTypes> Display for BeaconEngineMessage<Payload> {
                     payload_attrs.is_some()
                 )
             }
+            Self::TrackedForks { .. } => write!(f, "TrackedForks"),
+            Self::ClearInvalidBlock { block_hash, .. } => {
+                write!(f, "ClearInvalidBlock({block_hash})")
+            }
         }
     }
 }
@@ -337,6 +368,33 @@ where
             .await?)
     }
 
+    /// Returns the fork chains currently tracked by the engine tree, that branch off the canonical
+    /// chain.
+    ///
+    /// This is intended for diagnostics only. Returns `None` if the engine is unavailable.
+    pub async fn tracked_forks(&self) -> Option<Vec<ForkChainInfo>> {
+        let (tx, rx) = oneshot::channel();
+        let _ = self.to_engine.send(BeaconEngineMessage::TrackedForks { tx });
+        rx.await.ok()
+    }
+
+    /// Removes the block with the given hash, and all blocks that were rejected because they
+    /// descend from it, from the engine's invalid block cache.
+    ///
+    /// This is intended to recover from blocks that were incorrectly marked invalid, e.g. due to
+    /// a since fixed validation bug, without restarting the node. The engine re-executes the block
+    /// the next time it is received, clearing an actually invalid block therefore only costs
+    /// another execution. However, this must not be used to force acceptance of a block: it will
+    /// be rejected again if it is invalid.
+    ///
+    /// Returns `true` if the block was cached as invalid, and `false` otherwise or if the engine
+    /// is unavailable.
+    pub async fn clear_invalid_block(&self, block_hash: B256) -> bool {
+        let (tx, rx) = oneshot::channel();
+        let _ = self.to_engine.send(BeaconEngineMessage::ClearInvalidBlock { block_hash, tx });
+        rx.await.unwrap_or_default()
+    }
+
     /// Sends a forkchoice update message to the beacon consensus engine and returns the receiver to
     /// wait for a response.
     fn send_fork_choice_updated(
@@ -14,7 +14,7 @@ use crate::{
 };
 use futures::Stream;
 use reth_consensus::FullConsensus;
-use reth_engine_primitives::BeaconEngineMessage;
+use reth_engine_primitives::{BeaconEngineMessage, PersistenceThresholdHandle};
 use reth_evm::ConfigureEvm;
 use reth_network_p2p::BlockClient;
 use reth_payload_builder::PayloadBuilderHandle;
@@ -61,6 +61,7 @@ pub fn build_engine_orchestrator<N, Client, S, V, C>(
     payload_builder: PayloadBuilderHandle<N::Payload>,
     payload_validator: V,
     tree_config: TreeConfig,
+    persistence_threshold: PersistenceThresholdHandle,
     sync_metrics_tx: MetricEventsSender,
     evm_config: C,
     changeset_cache: ChangesetCache,
@@ -95,6 +96,7 @@ where
         payload_builder,
         canonical_in_memory_state,
         tree_config,
+        persistence_threshold,
         engine_kind,
         evm_config,
         changeset_cache,
@@ -1,12 +1,26 @@
 //! Internal errors for the tree module.
 
 use alloy_consensus::BlockHeader;
+use alloy_primitives::B256;
 use reth_consensus::ConsensusError;
 use reth_errors::{BlockExecutionError, BlockValidationError, ProviderError};
 use reth_evm::execute::InternalBlockExecutionError;
 use reth_payload_primitives::NewPayloadError;
 use reth_primitives_traits::{Block, BlockBody, SealedBlock};
 
+/// Error returned when making a block canonical would unwind more canonical blocks than allowed by
+/// [`TreeConfig::max_reorg_depth`](reth_engine_primitives::TreeConfig::max_reorg_depth).
+///
+/// This is not caused by the new head being invalid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+#[error("reorg to {new_head} exceeds the maximum reorg depth of {max_depth} blocks")]
+pub struct ReorgTooDeepError {
+    /// The hash of the block that was supposed to become the new head.
+    pub new_head: B256,
+    /// The configured maximum reorg depth.
+    pub max_depth: u64,
+}
+
 /// This is an error that can come from advancing persistence.
 #[derive(Debug, thiserror::Error)]
 pub enum AdvancePersistenceError {
@@ -150,6 +164,17 @@ impl InsertBlockErrorKind {
             Self::Other(err) => Err(InternalBlockExecutionError::Other(err).into()),
         }
     }
+
+    /// Returns a reference to the error wrapped in [`InsertBlockErrorKind::Other`] if it is of
+    /// type `E`.
+    ///
+    /// Returns `None` for all other variants or if the wrapped error is of a different type.
+    pub fn downcast_other_ref<E: core::error::Error + 'static>(&self) -> Option<&E> {
+        match self {
+            Self::Other(err) => err.downcast_ref::<E>(),
+            _ => None,
+        }
+    }
 }
 
 /// Error variants that are not caused by invalid blocks
@@ -174,6 +199,20 @@ pub enum InsertBlockValidationError {
     Validation(#[from] BlockValidationError),
 }
 
+impl InsertBlockValidationError {
+    /// Returns a concise reason for the error, suitable for the `validationError` field of an
+    /// `INVALID` payload status.
+    ///
+    /// The reason is the error detail, prefixed with the kind of the error: `consensus` for
+    /// violated consensus rules and `execution` for errors caught during block execution.
+    pub fn reason(&self) -> String {
+        match self {
+            Self::Consensus(err) => format!("consensus: {err}"),
+            Self::Validation(err) => format!("execution: {err}"),
+        }
+    }
+}
+
 /// Errors that may occur when inserting a payload.
 #[derive(Debug, thiserror::Error)]
 pub enum InsertPayloadError<B: Block> {
@@ -184,3 +223,34 @@ pub enum InsertPayloadError<B: Block> {
     #[error(transparent)]
     Payload(#[from] NewPayloadError),
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, PartialEq, Eq, thiserror::Error)]
+    #[error("custom validation error {0}")]
+    struct CustomError(u64);
+
+    #[test]
+    fn downcast_other_ref() {
+        let kind = InsertBlockErrorKind::Other(Box::new(CustomError(42)));
+        assert_eq!(kind.downcast_other_ref::<CustomError>(), Some(&CustomError(42)));
+        assert!(kind.downcast_other_ref::<ProviderError>().is_none());
+
+        let kind = InsertBlockErrorKind::Provider(ProviderError::UnsupportedProvider);
+        assert!(kind.downcast_other_ref::<CustomError>().is_none());
+    }
+
+    #[test]
+    fn validation_error_reason() {
+        let err = InsertBlockValidationError::Consensus(ConsensusError::TimestampIsInPast {
+            parent_timestamp: 2,
+            timestamp: 1,
+        });
+        assert_eq!(
+            err.reason(),
+            "consensus: block timestamp 1 is in the past compared to the parent timestamp 2"
+        );
+    }
+}
@@ -79,6 +79,27 @@ impl InvalidHeaderCache {
             self.metrics.count.set(self.headers.len() as f64);
         }
     }
+
+    /// Removes the block with the given hash from the cache, together with all descendants that
+    /// were marked invalid because of it.
+    ///
+    /// Descendants are removed even if the block itself is no longer cached, e.g. because it was
+    /// evicted. Returns `true` only if the block itself was cached as invalid.
+    pub fn remove(&mut self, hash: &B256) -> bool {
+        let descendants = self
+            .headers
+            .iter()
+            .filter(|(_, entry)| entry.header.block.hash == *hash)
+            .map(|(descendant, _)| *descendant)
+            .collect::<Vec<_>>();
+
+        let removed = self.headers.remove(hash).is_some();
+        for descendant in descendants {
+            self.headers.remove(&descendant);
+        }
+        self.metrics.count.set(self.headers.len() as f64);
+        removed
+    }
 }
 
 struct HeaderEntry {
@@ -123,4 +144,38 @@ mod tests {
 
         assert!(cache.get(&header.hash()).is_none());
     }
+
+    #[test]
+    fn test_remove() {
+        let mut cache = InvalidHeaderCache::new(10);
+        let header = SealedHeader::seal_slow(Header::default());
+        let child = SealedHeader::seal_slow(Header {
+            parent_hash: header.hash(),
+            number: 1,
+            ..Default::default()
+        });
+        cache.insert(header.block_with_parent());
+        cache.insert_with_invalid_ancestor(child.hash(), header.block_with_parent());
+
+        assert!(cache.remove(&header.hash()));
+        assert!(cache.get(&header.hash()).is_none());
+        assert!(cache.get(&child.hash()).is_none());
+        assert!(!cache.remove(&header.hash()));
+    }
+
+    #[test]
+    fn test_remove_evicted_ancestor() {
+        let mut cache = InvalidHeaderCache::new(10);
+        let header = SealedHeader::seal_slow(Header::default());
+        let child = SealedHeader::seal_slow(Header {
+            parent_hash: header.hash(),
+            number: 1,
+            ..Default::default()
+        });
+        // only the descendant is cached, the invalid ancestor itself is gone
+        cache.insert_with_invalid_ancestor(child.hash(), header.block_with_parent());
+
+        assert!(!cache.remove(&header.hash()));
+        assert!(cache.get(&child.hash()).is_none());
+    }
 }
@@ -11,7 +11,7 @@ use alloy_primitives::B256;
 use alloy_rpc_types_engine::{
     ForkchoiceState, PayloadStatus, PayloadStatusEnum, PayloadValidationError,
 };
-use error::{InsertBlockError, InsertBlockFatalError};
+use error::{InsertBlockError, InsertBlockFatalError, ReorgTooDeepError};
 use reth_chain_state::{
     CanonicalInMemoryState, ComputedTrieData, ExecutedBlock, ExecutionTimingStats,
     MemoryOverlayStateProvider, NewCanonicalChain,
@@ -19,7 +19,8 @@ use reth_chain_state::{
 use reth_consensus::{Consensus, FullConsensus};
 use reth_engine_primitives::{
     BeaconEngineMessage, BeaconOnNewPayloadError, ConsensusEngineEvent, ExecutionPayload,
-    ForkchoiceStateTracker, NewPayloadTimings, OnForkChoiceUpdated, SlowBlockInfo,
+    ForkchoiceStateTracker, NewPayloadTimings, OnForkChoiceUpdated, PersistenceThresholdHandle,
+    SlowBlockInfo,
 };
 use reth_errors::{ConsensusError, ProviderResult};
 use reth_evm::ConfigureEvm;
@@ -293,6 +294,8 @@ where
     payload_builder: PayloadBuilderHandle<T>,
     /// Configuration settings.
     config: TreeConfig,
+    /// The persistence threshold, which can be adjusted at runtime.
+    persistence_threshold: PersistenceThresholdHandle,
     /// Metrics for the engine api.
     metrics: EngineApiMetrics,
     /// The engine API variant of this handler
@@ -370,6 +373,7 @@ where
         persistence_state: PersistenceState,
         payload_builder: PayloadBuilderHandle<T>,
         config: TreeConfig,
+        persistence_threshold: PersistenceThresholdHandle,
         engine_kind: EngineApiKind,
         evm_config: C,
         changeset_cache: ChangesetCache,
@@ -390,6 +394,7 @@ where
             canonical_in_memory_state,
             payload_builder,
             config,
+            persistence_threshold,
             metrics: Default::default(),
             incoming_tx,
             engine_kind,
@@ -414,6 +419,7 @@ where
         payload_builder: PayloadBuilderHandle<T>,
         canonical_in_memory_state: CanonicalInMemoryState<N>,
         config: TreeConfig,
+        persistence_threshold: PersistenceThresholdHandle,
         kind: EngineApiKind,
         evm_config: C,
         changeset_cache: ChangesetCache,
@@ -447,14 +453,16 @@ where
             persistence_state,
             payload_builder,
             config,
+            persistence_threshold,
             kind,
             evm_config,
             changeset_cache,
-            runtime,
+            runtime.clone(),
         );
         let incoming = task.incoming_tx.clone();
-        spawn_os_thread("engine", || {
+        spawn_os_thread("engine", move || {
             increase_thread_priority();
+            runtime.pin_to_critical_cores();
             task.run()
         });
         (incoming, outgoing)
@@ -903,6 +911,7 @@ where
             old_hash = block.recovered_block().parent_hash();
             old_chain.push(block);
             current_canonical_number -= 1;
+            self.ensure_reorg_depth(new_head, old_chain.len())?;
         }
 
         // Both new and old chain pointers are now at the same height.
@@ -914,6 +923,7 @@ where
             let block = self.canonical_block_by_hash(old_hash)?;
             old_hash = block.recovered_block().parent_hash();
             old_chain.push(block);
+            self.ensure_reorg_depth(new_head, old_chain.len())?;
 
             if let Some(block) = self.state.tree_state.executed_block_by_hash(current_hash).cloned()
             {
@@ -931,6 +941,17 @@ where
         Ok(Some(NewCanonicalChain::Reorg { new: new_chain, old: old_chain }))
     }
 
+    /// Returns an error if a reorg to `new_head` that unwinds `depth` canonical blocks exceeds the
+    /// configured [`TreeConfig::max_reorg_depth`].
+    fn ensure_reorg_depth(&self, new_head: B256, depth: usize) -> ProviderResult<()> {
+        let max_depth = self.config.max_reorg_depth();
+        if depth as u64 > max_depth {
+            warn!(target: "engine::tree", ?new_head, max_depth, "Rejecting reorg deeper than the maximum reorg depth");
+            return Err(ProviderError::other(ReorgTooDeepError { new_head, max_depth }))
+        }
+        Ok(())
+    }
+
     /// Updates the latest block state to the specified canonical ancestor.
     ///
     /// This method ensures that the latest block tracks the given canonical header by resetting
@@ -1735,6 +1756,16 @@ where
 
                                 self.on_maybe_tree_event(maybe_event)?;
                             }
+                            BeaconEngineMessage::TrackedForks { tx } => {
+                                let _ = tx.send(self.state.tree_state.tracked_forks());
+                            }
+                            BeaconEngineMessage::ClearInvalidBlock { block_hash, tx } => {
+                                let removed = self.state.invalid_headers.remove(&block_hash);
+                                if removed {
+                                    warn!(target: "engine::tree", %block_hash, "Cleared block from invalid block cache");
+                                }
+                                let _ = tx.send(removed);
+                            }
                         }
                     }
                 }
@@ -1911,6 +1942,9 @@ where
     /// Attempts to make the given target canonical.
     ///
     /// This will update the tracked canonical in memory state and do the necessary housekeeping.
+    ///
+    /// Returns a [`ReorgTooDeepError`] if the target requires a reorg deeper than
+    /// [`TreeConfig::max_reorg_depth`], which the callers treat as fatal.
     fn make_canonical(&mut self, target: B256) -> ProviderResult<()> {
         if let Some(chain_update) = self.on_new_head(target)? {
             self.on_canonical_chain_update(chain_update);
@@ -2015,9 +2049,9 @@ where
     }
 
     /// Returns true if the canonical chain length minus the last persisted
-    /// block is greater than or equal to the persistence threshold and
+    /// block is greater than or equal to the current persistence threshold and
     /// backfill is not running.
-    pub const fn should_persist(&self) -> bool {
+    pub fn should_persist(&self) -> bool {
         if !self.backfill_sync_state.is_idle() {
             // can't persist if backfill is running
             return false
@@ -2025,7 +2059,7 @@ where
 
         let min_block = self.persistence_state.last_persisted_block.number;
         self.state.tree_state.canonical_block_number().saturating_sub(min_block) >
-            self.config.persistence_threshold()
+            self.persistence_threshold.get()
     }
 
     /// Returns a batch of consecutive canonical blocks to persist in the range
@@ -3026,7 +3060,7 @@ where
         )));
 
         Ok(PayloadStatus::new(
-            PayloadStatusEnum::Invalid { validation_error: validation_err.to_string() },
+            PayloadStatusEnum::Invalid { validation_error: validation_err.reason() },
             latest_valid_hash,
         ))
     }
@@ -560,6 +560,9 @@ where
         let parent_span = Span::current();
         self.executor.spawn_blocking_named("sparse-trie", move || {
             reth_tasks::once!(increase_thread_priority);
+            // not guarded by `once!`, which is process-global, while the cores are configured per
+            // runtime
+            executor.pin_to_critical_cores();
 
             let _enter = debug_span!(target: "engine::tree::payload_processor", parent: parent_span, "sparse_trie_task")
                 .entered();
@@ -7,6 +7,7 @@ use alloy_primitives::{
     BlockNumber, B256,
 };
 use reth_chain_state::{DeferredTrieData, EthPrimitives, ExecutedBlock, LazyOverlay};
+use reth_engine_primitives::ForkChainInfo;
 use reth_primitives_traits::{AlloyBlockHeader, NodePrimitives, SealedHeader};
 use std::{
     collections::{btree_map, hash_map, BTreeMap, VecDeque},
@@ -234,6 +235,47 @@ impl<N: NodePrimitives> TreeState<N> {
         false
     }
 
+    /// Returns all fork chains that branch off the canonical chain, ordered by tip number
+    /// descending.
+    ///
+    /// A fork is identified by a block without children that is not part of the canonical chain.
+    pub fn tracked_forks(&self) -> Vec<ForkChainInfo> {
+        let mut canonical = B256Set::default();
+        let mut current_block = self.current_canonical_head.hash;
+        while let Some(executed) = self.blocks_by_hash.get(&current_block) {
+            canonical.insert(current_block);
+            current_block = executed.recovered_block().parent_hash();
+        }
+
+        let mut forks = self
+            .blocks_by_hash
+            .iter()
+            .filter(|(hash, _)| {
+                !canonical.contains(*hash) &&
+                    self.parent_to_child.get(*hash).is_none_or(|children| children.is_empty())
+            })
+            .map(|(_, tip)| {
+                let mut block_count = 1;
+                let mut parent_hash = tip.recovered_block().parent_hash();
+                while !canonical.contains(&parent_hash) &&
+                    let Some(parent) = self.blocks_by_hash.get(&parent_hash)
+                {
+                    block_count += 1;
+                    parent_hash = parent.recovered_block().parent_hash();
+                }
+
+                ForkChainInfo {
+                    tip: tip.recovered_block().num_hash(),
+                    common_ancestor: parent_hash,
+                    block_count,
+                }
+            })
+            .collect::<Vec<_>>();
+
+        forks.sort_unstable_by_key(|fork| core::cmp::Reverse(fork.tip.number));
+        forks
+    }
+
     /// Removes canonical blocks below the upper bound, only if the last persisted hash is
     /// part of the canonical chain.
     pub fn remove_canonical_until(&mut self, upper_bound: BlockNumber, last_persisted_hash: B256) {
@@ -510,6 +552,35 @@ mod tests {
         assert!(!tree_state.parent_to_child.contains_key(&blocks[2].recovered_block().hash()));
     }
 
+    #[test]
+    fn test_tree_state_tracked_forks() {
+        let mut tree_state = TreeState::new(BlockNumHash::default(), EngineApiKind::Ethereum);
+        let mut test_block_builder = TestBlockBuilder::eth();
+        let blocks: Vec<_> = test_block_builder.get_executed_blocks(1..5).collect();
+
+        for block in &blocks {
+            tree_state.insert_executed(block.clone());
+        }
+        tree_state.set_canonical_head(blocks[3].recovered_block().num_hash());
+        assert!(tree_state.tracked_forks().is_empty());
+
+        let fork_block_3 = test_block_builder
+            .get_executed_block_with_number(3, blocks[1].recovered_block().hash());
+        let fork_block_4 = test_block_builder
+            .get_executed_block_with_number(4, fork_block_3.recovered_block().hash());
+        tree_state.insert_executed(fork_block_3);
+        tree_state.insert_executed(fork_block_4.clone());
+
+        assert_eq!(
+            tree_state.tracked_forks(),
+            vec![ForkChainInfo {
+                tip: fork_block_4.recovered_block().num_hash(),
+                common_ancestor: blocks[1].recovered_block().hash(),
+                block_count: 2,
+            }]
+        );
+    }
+
     #[tokio::test]
     async fn test_tree_state_insert_executed_with_reorg() {
         let mut tree_state = TreeState::new(BlockNumHash::default(), EngineApiKind::Ethereum);
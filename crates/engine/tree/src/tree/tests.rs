@@ -21,7 +21,9 @@ use alloy_rpc_types_engine::{
 use assert_matches::assert_matches;
 use reth_chain_state::{test_utils::TestBlockBuilder, BlockState, ComputedTrieData};
 use reth_chainspec::{ChainSpec, HOLESKY, MAINNET};
-use reth_engine_primitives::{EngineApiValidator, ForkchoiceStatus, NoopInvalidBlockHook};
+use reth_engine_primitives::{
+    EngineApiValidator, ForkchoiceStatus, NoopInvalidBlockHook, PersistenceThresholdHandle,
+};
 use reth_ethereum_consensus::EthBeaconConsensus;
 use reth_ethereum_engine_primitives::EthEngineTypes;
 use reth_ethereum_primitives::{Block, EthPrimitives};
@@ -207,6 +209,10 @@ impl TestHarness {
             reth_tasks::Runtime::test(),
         );
 
+        // always assume enough parallelism for tests
+        let tree_config =
+            TreeConfig::default().with_legacy_state_root(false).with_has_enough_parallelism(true);
+        let persistence_threshold = PersistenceThresholdHandle::new(&tree_config);
         let tree = EngineApiTreeHandler::new(
             provider.clone(),
             consensus,
@@ -217,8 +223,8 @@ impl TestHarness {
             persistence_handle,
             PersistenceState { last_persisted_block: BlockNumHash::default(), rx: None },
             payload_builder,
-            // always assume enough parallelism for tests
-            TreeConfig::default().with_legacy_state_root(false).with_has_enough_parallelism(true),
+            tree_config,
+            persistence_threshold,
             EngineApiKind::Ethereum,
             evm_config,
             changeset_cache,
@@ -281,6 +287,13 @@ impl TestHarness {
         self
     }
 
+    /// Replaces the tree config, resetting the runtime persistence threshold to the one of the
+    /// config.
+    fn set_tree_config(&mut self, config: TreeConfig) {
+        self.tree.persistence_threshold = PersistenceThresholdHandle::new(&config);
+        self.tree.config = config;
+    }
+
     const fn with_backfill_state(mut self, state: BackfillSyncState) -> Self {
         self.tree.backfill_sync_state = state;
         self
@@ -529,6 +542,23 @@ fn test_tree_persist_block_batch() {
     }
 }
 
+#[test]
+fn test_should_persist_runtime_persistence_threshold() {
+    let blocks: Vec<_> = TestBlockBuilder::eth().get_executed_blocks(1..4).collect();
+    let test_harness = TestHarness::new(MAINNET.clone()).with_blocks(blocks);
+    let persistence_threshold = test_harness.tree.persistence_threshold.clone();
+
+    // three canonical blocks are not persisted yet
+    assert_eq!(persistence_threshold.get(), 2);
+    assert!(test_harness.tree.should_persist());
+
+    persistence_threshold.set(3).unwrap();
+    assert!(!test_harness.tree.should_persist());
+
+    persistence_threshold.set(2).unwrap();
+    assert!(test_harness.tree.should_persist());
+}
+
 #[tokio::test]
 async fn test_tree_persist_blocks() {
     let tree_config = TreeConfig::default();
@@ -696,11 +726,13 @@ async fn test_holesky_payload() {
 fn test_backpressure_waits_for_persistence_before_reading_incoming() {
     let blocks: Vec<_> = TestBlockBuilder::eth().get_executed_blocks(1..4).collect();
     let mut test_harness = TestHarness::new(MAINNET.clone()).with_blocks(blocks.clone());
-    test_harness.tree.config = test_harness
-        .tree
-        .config
-        .with_persistence_threshold(0)
-        .with_persistence_backpressure_threshold(1);
+    test_harness.set_tree_config(
+        test_harness
+            .tree
+            .config
+            .with_persistence_threshold(0)
+            .with_persistence_backpressure_threshold(1),
+    );
 
     let (persist_tx, persist_rx) = crossbeam_channel::bounded(1);
     let persisted = blocks.last().unwrap().recovered_block().num_hash();
@@ -767,8 +799,9 @@ async fn test_tree_state_on_new_head_reorg() {
 
     // Set persistence_threshold to 1
     let mut test_harness = TestHarness::new(chain_spec);
-    test_harness.tree.config =
-        test_harness.tree.config.with_persistence_threshold(1).with_memory_block_buffer_target(1);
+    test_harness.set_tree_config(
+        test_harness.tree.config.with_persistence_threshold(1).with_memory_block_buffer_target(1),
+    );
     let mut test_block_builder = TestBlockBuilder::eth();
     let blocks: Vec<_> = test_block_builder.get_executed_blocks(1..6).collect();
 
@@ -951,6 +984,61 @@ fn test_tree_state_on_new_head_deep_fork() {
     }
 }
 
+#[test]
+fn test_tree_state_on_new_head_max_reorg_depth() {
+    reth_tracing::init_test_tracing();
+
+    let chain_spec = MAINNET.clone();
+    let mut test_harness = TestHarness::new(chain_spec);
+    let mut test_block_builder = TestBlockBuilder::eth();
+
+    let blocks: Vec<_> = test_block_builder.get_executed_blocks(0..5).collect();
+    for block in &blocks {
+        test_harness.tree.state.tree_state.insert_executed(block.clone());
+    }
+    let last_block = blocks.last().unwrap().recovered_block().clone();
+
+    // canonical chain of 4 blocks on top of the last block and a competing fork of 2 blocks
+    let chain_a = test_block_builder.create_fork(&last_block, 4);
+    let chain_b = test_block_builder.create_fork(&last_block, 2);
+    for block in chain_a.iter().chain(&chain_b) {
+        test_harness.tree.state.tree_state.insert_executed(ExecutedBlock::new(
+            Arc::new(block.clone()),
+            Arc::new(BlockExecutionOutput::default()),
+            ComputedTrieData::default(),
+        ));
+    }
+    test_harness.tree.state.tree_state.set_canonical_head(chain_a.last().unwrap().num_hash());
+
+    // reorging to the fork unwinds all 4 canonical blocks
+    test_harness.tree.config = test_harness.tree.config.clone().with_max_reorg_depth(3);
+    let err = test_harness.tree.on_new_head(chain_b.last().unwrap().hash()).unwrap_err();
+    assert_matches!(
+        err,
+        ProviderError::Other(err) if err.as_error().downcast_ref::<ReorgTooDeepError>().is_some()
+    );
+
+    // making a downloaded sync target that requires the reorg canonical fails as well
+    let err = test_harness
+        .tree
+        .on_tree_event(TreeEvent::TreeAction(TreeAction::MakeCanonical {
+            sync_target_head: chain_b.last().unwrap().hash(),
+        }))
+        .unwrap_err();
+    assert_matches!(
+        err,
+        ProviderError::Other(err) if err.as_error().downcast_ref::<ReorgTooDeepError>().is_some()
+    );
+    assert_eq!(
+        test_harness.tree.state.tree_state.canonical_block_hash(),
+        chain_a.last().unwrap().hash()
+    );
+
+    test_harness.tree.config = test_harness.tree.config.clone().with_max_reorg_depth(4);
+    let result = test_harness.tree.on_new_head(chain_b.last().unwrap().hash()).unwrap();
+    assert_matches!(result, Some(NewCanonicalChain::Reorg { old, .. }) if old.len() == 4);
+}
+
 #[tokio::test]
 async fn test_get_canonical_blocks_to_persist() {
     let chain_spec = MAINNET.clone();
@@ -968,9 +1056,11 @@ async fn test_get_canonical_blocks_to_persist() {
 
     let persistence_threshold = 4;
     let memory_block_buffer_target = 3;
-    test_harness.tree.config = TreeConfig::default()
-        .with_persistence_threshold(persistence_threshold)
-        .with_memory_block_buffer_target(memory_block_buffer_target);
+    test_harness.set_tree_config(
+        TreeConfig::default()
+            .with_persistence_threshold(persistence_threshold)
+            .with_memory_block_buffer_target(memory_block_buffer_target),
+    );
 
     let blocks_to_persist =
         test_harness.tree.get_canonical_blocks_to_persist(PersistTarget::Threshold).unwrap();
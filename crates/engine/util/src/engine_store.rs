@@ -1,20 +1,27 @@
 //! Stores engine API messages to disk for later inspection and replay.
 
-use alloy_rpc_types_engine::ForkchoiceState;
-use futures::{Stream, StreamExt};
-use reth_engine_primitives::{BeaconEngineMessage, ExecutionPayload};
+use alloy_rpc_types_engine::{ForkchoiceState, PayloadStatus, PayloadStatusEnum};
+use futures::{stream::FuturesUnordered, Stream, StreamExt};
+use reth_engine_primitives::{BeaconEngineMessage, ExecutionPayload, ForkchoiceStatus};
 use reth_fs_util as fs;
 use reth_payload_primitives::PayloadTypes;
 use serde::{Deserialize, Serialize};
 use std::{
     collections::BTreeMap,
-    path::PathBuf,
+    future::Future,
+    path::{Path, PathBuf},
     pin::Pin,
     task::{ready, Context, Poll},
     time::SystemTime,
 };
+use tokio::sync::oneshot;
 use tracing::*;
 
+/// Suffix of the files that hold the responses to stored engine API messages.
+const RESPONSE_SUFFIX: &str = ".response.json";
+
+type StoreResponseFut = Pin<Box<dyn Future<Output = ()> + Send + Sync>>;
+
 /// A message from the engine API that has been stored to disk.
 #[derive(Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -35,6 +42,12 @@ pub enum StoredEngineApiMessage<T: PayloadTypes> {
 }
 
 /// This can read and write engine API messages in a specific directory.
+///
+/// Every message is stored in its own JSON file, named `<timestamp>-<method>-<hash>.json` and
+/// containing a [`StoredEngineApiMessage`]. Once the engine responded to the message, the
+/// [`PayloadStatus`] of the response is stored next to it, in a file with the same name and the
+/// `.response.json` extension. For `engine_forkchoiceUpdated` only the status is recorded, the
+/// latest valid hash is not available to the store.
 #[derive(Debug)]
 pub struct EngineMessageStore {
     /// The path to the directory that stores the engine API messages.
@@ -51,47 +64,88 @@ impl EngineMessageStore {
 
     /// Stores the received [`BeaconEngineMessage`] to disk, appending the `received_at` time to the
     /// path.
+    ///
+    /// Returns the path of the stored message, or `None` if the message is not stored.
     pub fn on_message<T>(
         &self,
         msg: &BeaconEngineMessage<T>,
         received_at: SystemTime,
-    ) -> eyre::Result<()>
+    ) -> eyre::Result<Option<PathBuf>>
     where
         T: PayloadTypes,
     {
         fs::create_dir_all(&self.path)?; // ensure that store path had been created
         let timestamp = received_at.duration_since(SystemTime::UNIX_EPOCH).unwrap().as_millis();
-        match msg {
+        let path = match msg {
             BeaconEngineMessage::ForkchoiceUpdated { state, payload_attrs, tx: _tx } => {
                 let filename = format!("{}-fcu-{}.json", timestamp, state.head_block_hash);
+                let path = self.path.join(filename);
                 fs::write(
-                    self.path.join(filename),
+                    &path,
                     serde_json::to_vec(&StoredEngineApiMessage::<T>::ForkchoiceUpdated {
                         state: *state,
                         payload_attrs: payload_attrs.clone(),
                     })?,
                 )?;
+                path
             }
             BeaconEngineMessage::NewPayload { payload, .. } |
             BeaconEngineMessage::RethNewPayload { payload, .. } => {
                 let filename = format!("{}-new_payload-{}.json", timestamp, payload.block_hash());
+                let path = self.path.join(filename);
                 fs::write(
-                    self.path.join(filename),
+                    &path,
                     serde_json::to_vec(&StoredEngineApiMessage::<T>::NewPayload {
                         payload: payload.clone(),
                     })?,
                 )?;
+                path
             }
+            BeaconEngineMessage::TrackedForks { .. } |
+            BeaconEngineMessage::ClearInvalidBlock { .. } => return Ok(None),
         };
+        Ok(Some(path))
+    }
+
+    /// Stores the payload status the engine responded with to the message stored at `message`.
+    pub fn on_response(message: &Path, status: &PayloadStatus) -> eyre::Result<()> {
+        fs::write(Self::response_path(message), serde_json::to_vec(status)?)?;
         Ok(())
     }
 
+    /// Reads the message stored at `path`, together with the recorded response, if any.
+    pub fn read_message<T: PayloadTypes>(
+        path: &Path,
+    ) -> eyre::Result<(StoredEngineApiMessage<T>, Option<PayloadStatus>)> {
+        let message = serde_json::from_slice(&fs::read(path)?)
+            .map_err(|err| eyre::eyre!("invalid engine API message {}: {err}", path.display()))?;
+
+        let response_path = Self::response_path(path);
+        let response = if response_path.exists() {
+            Some(serde_json::from_slice(&fs::read(&response_path)?).map_err(|err| {
+                eyre::eyre!("invalid engine API response {}: {err}", response_path.display())
+            })?)
+        } else {
+            None
+        };
+
+        Ok((message, response))
+    }
+
+    /// Returns the path of the response to the message stored at `message`.
+    fn response_path(message: &Path) -> PathBuf {
+        message.with_extension(&RESPONSE_SUFFIX[1..])
+    }
+
     /// Finds and iterates through any stored engine API message files, ordered by timestamp.
     pub fn engine_messages_iter(&self) -> eyre::Result<impl Iterator<Item = PathBuf>> {
         let mut filenames_by_ts = BTreeMap::<u64, Vec<PathBuf>>::default();
         for entry in fs::read_dir(&self.path)? {
             let entry = entry?;
             let filename = entry.file_name();
+            if filename.to_str().is_some_and(|n| n.ends_with(RESPONSE_SUFFIX)) {
+                continue
+            }
             if let Some(filename) = filename.to_str().filter(|n| n.ends_with(".json")) {
                 if let Some(Ok(timestamp)) = filename.split('-').next().map(|n| n.parse::<u64>()) {
                     filenames_by_ts.entry(timestamp).or_default().push(entry.path());
@@ -109,6 +163,9 @@ impl EngineMessageStore {
 
 /// A wrapper stream that stores Engine API messages in
 /// the specified directory.
+///
+/// The responses of the engine are intercepted and stored as well, see [`EngineMessageStore`].
+/// They are forwarded to the original sender when the stream is polled next.
 #[derive(Debug)]
 #[pin_project::pin_project]
 pub struct EngineStoreStream<S> {
@@ -117,12 +174,14 @@ pub struct EngineStoreStream<S> {
     stream: S,
     /// Engine message store.
     store: EngineMessageStore,
+    /// Pending engine responses to stored messages.
+    responses: FuturesUnordered<StoreResponseFut>,
 }
 
 impl<S> EngineStoreStream<S> {
     /// Create new engine store stream wrapper.
-    pub const fn new(stream: S, path: PathBuf) -> Self {
-        Self { stream, store: EngineMessageStore::new(path) }
+    pub fn new(stream: S, path: PathBuf) -> Self {
+        Self { stream, store: EngineMessageStore::new(path), responses: FuturesUnordered::new() }
     }
 }
 
@@ -135,12 +194,136 @@ where
 
     fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
         let mut this = self.project();
-        let next = ready!(this.stream.poll_next_unpin(cx));
-        if let Some(msg) = &next &&
-            let Err(error) = this.store.on_message(msg, SystemTime::now())
+
+        while let Poll::Ready(Some(())) = this.responses.poll_next_unpin(cx) {}
+
+        let Some(msg) = ready!(this.stream.poll_next_unpin(cx)) else { return Poll::Ready(None) };
+        let path = match this.store.on_message(&msg, SystemTime::now()) {
+            Ok(Some(path)) => path,
+            Ok(None) => return Poll::Ready(Some(msg)),
+            Err(error) => {
+                error!(target: "engine::stream::store", ?msg, %error, "Error handling Engine API message");
+                return Poll::Ready(Some(msg))
+            }
+        };
+
+        let msg = match msg {
+            BeaconEngineMessage::NewPayload { payload, tx, enqueued_at } => {
+                let (response_tx, response_rx) = oneshot::channel();
+                this.responses.push(store_response(path, response_rx, tx, |response| {
+                    response.as_ref().ok().cloned()
+                }));
+                BeaconEngineMessage::NewPayload { payload, tx: response_tx, enqueued_at }
+            }
+            BeaconEngineMessage::RethNewPayload {
+                payload,
+                wait_for_persistence,
+                wait_for_caches,
+                tx,
+                enqueued_at,
+            } => {
+                let (response_tx, response_rx) = oneshot::channel();
+                this.responses.push(store_response(path, response_rx, tx, |response| {
+                    response.as_ref().ok().map(|(status, _)| status.clone())
+                }));
+                BeaconEngineMessage::RethNewPayload {
+                    payload,
+                    wait_for_persistence,
+                    wait_for_caches,
+                    tx: response_tx,
+                    enqueued_at,
+                }
+            }
+            BeaconEngineMessage::ForkchoiceUpdated { state, payload_attrs, tx } => {
+                let (response_tx, response_rx) = oneshot::channel();
+                this.responses.push(store_response(path, response_rx, tx, |response| {
+                    let status = match response.as_ref().ok()?.forkchoice_status() {
+                        ForkchoiceStatus::Valid => PayloadStatusEnum::Valid,
+                        ForkchoiceStatus::Invalid => {
+                            PayloadStatusEnum::Invalid { validation_error: String::new() }
+                        }
+                        ForkchoiceStatus::Syncing => PayloadStatusEnum::Syncing,
+                    };
+                    Some(PayloadStatus::from_status(status))
+                }));
+                BeaconEngineMessage::ForkchoiceUpdated { state, payload_attrs, tx: response_tx }
+            }
+            msg => msg,
+        };
+        Poll::Ready(Some(msg))
+    }
+}
+
+/// Returns a future that waits for the engine's response to the message stored at `message`,
+/// stores its payload status and forwards the response to `tx`.
+fn store_response<R: Send + 'static>(
+    message: PathBuf,
+    rx: oneshot::Receiver<R>,
+    tx: oneshot::Sender<R>,
+    status: fn(&R) -> Option<PayloadStatus>,
+) -> StoreResponseFut {
+    Box::pin(async move {
+        let Ok(response) = rx.await else { return };
+        if let Some(status) = status(&response) &&
+            let Err(error) = EngineMessageStore::on_response(&message, &status)
         {
-            error!(target: "engine::stream::store", ?msg, %error, "Error handling Engine API message");
+            error!(target: "engine::stream::store", ?message, %error, "Error storing Engine API response");
         }
-        Poll::Ready(next)
+        let _ = tx.send(response);
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::B256;
+    use futures::task::noop_waker_ref;
+    use reth_engine_primitives::OnForkChoiceUpdated;
+    use reth_ethereum_engine_primitives::EthEngineTypes;
+
+    #[test]
+    fn store_forkchoice_updated_response() {
+        let dir = tempfile::tempdir().unwrap();
+        let (to_stream, messages) = tokio::sync::mpsc::unbounded_channel();
+        let mut stream = EngineStoreStream::new(
+            tokio_stream::wrappers::UnboundedReceiverStream::new(messages),
+            dir.path().to_path_buf(),
+        );
+        let mut cx = Context::from_waker(noop_waker_ref());
+
+        let state =
+            ForkchoiceState { head_block_hash: B256::with_last_byte(1), ..Default::default() };
+        let (tx, mut rx) = oneshot::channel();
+        to_stream
+            .send(BeaconEngineMessage::<EthEngineTypes>::ForkchoiceUpdated {
+                state,
+                payload_attrs: None,
+                tx,
+            })
+            .unwrap();
+
+        let Poll::Ready(Some(BeaconEngineMessage::ForkchoiceUpdated { tx: engine_tx, .. })) =
+            stream.poll_next_unpin(&mut cx)
+        else {
+            panic!("expected forkchoice updated message")
+        };
+        let status = PayloadStatus::new(PayloadStatusEnum::Valid, Some(state.head_block_hash));
+        engine_tx.send(Ok(OnForkChoiceUpdated::valid(status))).unwrap();
+
+        // the response is forwarded once the stream is polled again
+        assert!(stream.poll_next_unpin(&mut cx).is_pending());
+        let response = rx.try_recv().unwrap().unwrap();
+        assert_eq!(response.forkchoice_status(), ForkchoiceStatus::Valid);
+
+        let paths = stream.store.engine_messages_iter().unwrap().collect::<Vec<_>>();
+        assert_eq!(paths.len(), 1);
+        let (message, response) =
+            EngineMessageStore::read_message::<EthEngineTypes>(&paths[0]).unwrap();
+        assert!(matches!(
+            message,
+            StoredEngineApiMessage::ForkchoiceUpdated { state: stored, payload_attrs: None }
+                if stored == state
+        ));
+        assert_eq!(response, Some(PayloadStatus::from_status(PayloadStatusEnum::Valid)));
     }
 }
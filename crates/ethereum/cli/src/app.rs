@@ -10,7 +10,7 @@ use reth_cli_commands::{
     common::{CliComponentsBuilder, CliNodeTypes, HeaderMut},
     launcher::{FnLauncher, Launcher},
 };
-use reth_cli_runner::CliRunner;
+use reth_cli_runner::{CliRunner, CliRunnerConfig};
 use reth_db::DatabaseEnv;
 use reth_node_api::NodePrimitives;
 use reth_node_builder::{NodeBuilder, WithLaunchContext};
@@ -100,13 +100,17 @@ where
             None => {
                 let runtime_config = match &self.cli.command {
                     Commands::Node(command) => {
-                        reth_tasks::RuntimeConfig::default().with_rayon(RayonConfig {
+                        let config = reth_tasks::RuntimeConfig::default().with_rayon(RayonConfig {
                             reserved_cpu_cores: command.engine.reserved_cpu_cores,
                             proof_storage_worker_threads: command.engine.storage_worker_count,
                             proof_account_worker_threads: command.engine.account_worker_count,
                             prewarming_threads: command.engine.prewarming_threads,
                             ..Default::default()
-                        })
+                        });
+                        match command.engine.critical_cpu_cores.clone() {
+                            Some(cores) => config.with_critical_cpu_cores(cores),
+                            None => config,
+                        }
                     }
                     _ => reth_tasks::RuntimeConfig::default(),
                 };
@@ -178,6 +182,9 @@ where
                 Rpc::validate_selection(ws_api, "ws.api").map_err(|e| eyre!("{e}"))?;
             }
 
+            let runner = runner.with_config(
+                CliRunnerConfig::new().with_graceful_shutdown_timeout(command.shutdown_timeout),
+            );
             runner.run_command_until_exit(|ctx| {
                 command.execute(ctx, FnLauncher::new::<C, Ext>(launcher))
             })
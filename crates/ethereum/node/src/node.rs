@@ -52,8 +52,10 @@ use reth_rpc_eth_types::{error::FromEvmError, EthApiError};
 use reth_rpc_server_types::RethRpcModule;
 use reth_tracing::tracing::{debug, info};
 use reth_transaction_pool::{
-    blobstore::DiskFileBlobStore, EthTransactionPool, PoolPooledTx, PoolTransaction,
-    TransactionPool, TransactionValidationTaskExecutor,
+    blobstore::DiskFileBlobStore,
+    error::{InvalidPoolTransactionError, RejectReason},
+    EthPooledTransaction, EthTransactionPool, PoolPooledTx, PoolTransaction, TransactionPool,
+    TransactionValidationTaskExecutor,
 };
 use revm::context::TxEnv;
 use std::{marker::PhantomData, sync::Arc, time::SystemTime};
@@ -483,6 +485,10 @@ where
     }
 }
 
+/// A custom transaction admission policy, see [`EthereumPoolBuilder::with_transaction_filter`].
+pub type TransactionFilter =
+    Arc<dyn Fn(&EthPooledTransaction) -> Result<(), RejectReason> + Send + Sync>;
+
 /// A basic ethereum transaction pool.
 ///
 /// This contains various settings that can be configured and take precedence over the node's
@@ -493,22 +499,34 @@ pub struct EthereumPoolBuilder {
     // TODO add options for txpool args
 }
 
-impl<Types, Node, Evm> PoolBuilder<Node, Evm> for EthereumPoolBuilder
-where
-    Types: NodeTypes<
-        ChainSpec: EthereumHardforks,
-        Primitives: NodePrimitives<SignedTx = TransactionSigned>,
-    >,
-    Node: FullNodeTypes<Types = Types>,
-    Evm: ConfigureEvm<Primitives = PrimitivesTy<Types>> + Clone + 'static,
-{
-    type Pool = EthTransactionPool<Node::Provider, DiskFileBlobStore, Evm>;
+impl EthereumPoolBuilder {
+    /// Installs a custom admission policy for transactions, e.g. to block interactions with
+    /// certain contracts.
+    ///
+    /// The filter runs during pool validation _after_ all standard checks passed, it does not
+    /// replace them. Transactions it rejects are bounced with the returned [`RejectReason`].
+    pub fn with_transaction_filter<F>(self, filter: F) -> FilteredEthereumPoolBuilder
+    where
+        F: Fn(&EthPooledTransaction) -> Result<(), RejectReason> + Send + Sync + 'static,
+    {
+        FilteredEthereumPoolBuilder { inner: self, transaction_filter: Arc::new(filter) }
+    }
 
-    async fn build_pool(
+    /// Builds the pool and installs the given admission policy on its validator.
+    async fn build_eth_pool<Types, Node, Evm>(
         self,
         ctx: &BuilderContext<Node>,
         evm_config: Evm,
-    ) -> eyre::Result<Self::Pool> {
+        transaction_filter: Option<TransactionFilter>,
+    ) -> eyre::Result<EthTransactionPool<Node::Provider, DiskFileBlobStore, Evm>>
+    where
+        Types: NodeTypes<
+            ChainSpec: EthereumHardforks,
+            Primitives: NodePrimitives<SignedTx = TransactionSigned>,
+        >,
+        Node: FullNodeTypes<Types = Types>,
+        Evm: ConfigureEvm<Primitives = PrimitivesTy<Types>> + Clone + 'static,
+    {
         let pool_config = ctx.pool_config();
 
         let blobs_disabled = ctx.config().txpool.disable_blobs_support ||
@@ -534,7 +552,7 @@ where
         let blob_store =
             reth_node_builder::components::create_blob_store_with_cache(ctx, blob_cache_size)?;
 
-        let validator =
+        let mut validator =
             TransactionValidationTaskExecutor::eth_builder(ctx.provider().clone(), evm_config)
                 .set_eip4844(!blobs_disabled)
                 .kzg_settings(ctx.kzg_settings()?)
@@ -543,8 +561,18 @@ where
                 .set_tx_fee_cap(ctx.config().rpc.rpc_tx_fee_cap)
                 .with_max_tx_gas_limit(ctx.config().txpool.max_tx_gas_limit)
                 .with_minimum_priority_fee(ctx.config().txpool.minimum_priority_fee)
-                .with_additional_tasks(ctx.config().txpool.additional_validation_tasks)
-                .build_with_tasks(ctx.task_executor().clone(), blob_store.clone());
+                .with_require_chain_id(pool_config.require_chain_id)
+                .build::<EthPooledTransaction, _>(blob_store.clone());
+        if let Some(filter) = transaction_filter {
+            validator.set_additional_stateful_validation(move |_, tx, _| {
+                filter(tx).map_err(|reason| InvalidPoolTransactionError::Other(Box::new(reason)))
+            });
+        }
+        let validator = TransactionValidationTaskExecutor::spawn(
+            validator,
+            ctx.task_executor(),
+            ctx.config().txpool.additional_validation_tasks,
+        );
 
         if validator.validator().eip4844() {
             // initializing the KZG settings can be expensive, this should be done upfront so that
@@ -568,6 +596,64 @@ where
     }
 }
 
+impl<Types, Node, Evm> PoolBuilder<Node, Evm> for EthereumPoolBuilder
+where
+    Types: NodeTypes<
+        ChainSpec: EthereumHardforks,
+        Primitives: NodePrimitives<SignedTx = TransactionSigned>,
+    >,
+    Node: FullNodeTypes<Types = Types>,
+    Evm: ConfigureEvm<Primitives = PrimitivesTy<Types>> + Clone + 'static,
+{
+    type Pool = EthTransactionPool<Node::Provider, DiskFileBlobStore, Evm>;
+
+    async fn build_pool(
+        self,
+        ctx: &BuilderContext<Node>,
+        evm_config: Evm,
+    ) -> eyre::Result<Self::Pool> {
+        self.build_eth_pool(ctx, evm_config, None).await
+    }
+}
+
+/// An [`EthereumPoolBuilder`] with a custom transaction admission policy.
+///
+/// See [`EthereumPoolBuilder::with_transaction_filter`].
+#[derive(Clone)]
+pub struct FilteredEthereumPoolBuilder {
+    inner: EthereumPoolBuilder,
+    /// Custom admission policy consulted after the standard validation.
+    transaction_filter: TransactionFilter,
+}
+
+impl std::fmt::Debug for FilteredEthereumPoolBuilder {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FilteredEthereumPoolBuilder")
+            .field("inner", &self.inner)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<Types, Node, Evm> PoolBuilder<Node, Evm> for FilteredEthereumPoolBuilder
+where
+    Types: NodeTypes<
+        ChainSpec: EthereumHardforks,
+        Primitives: NodePrimitives<SignedTx = TransactionSigned>,
+    >,
+    Node: FullNodeTypes<Types = Types>,
+    Evm: ConfigureEvm<Primitives = PrimitivesTy<Types>> + Clone + 'static,
+{
+    type Pool = EthTransactionPool<Node::Provider, DiskFileBlobStore, Evm>;
+
+    async fn build_pool(
+        self,
+        ctx: &BuilderContext<Node>,
+        evm_config: Evm,
+    ) -> eyre::Result<Self::Pool> {
+        self.inner.build_eth_pool(ctx, evm_config, Some(self.transaction_filter)).await
+    }
+}
+
 /// A basic ethereum payload service.
 #[derive(Debug, Default, Clone, Copy)]
 pub struct EthereumNetworkBuilder {
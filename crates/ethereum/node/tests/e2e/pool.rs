@@ -1,5 +1,5 @@
 use crate::utils::eth_payload_attributes;
-use alloy_consensus::{EthereumTxEnvelope, TxEip4844};
+use alloy_consensus::{EthereumTxEnvelope, Transaction as _, TxEip4844};
 use alloy_eips::{eip1559::ETHEREUM_BLOCK_GAS_LIMIT_30M, Encodable2718};
 use alloy_genesis::Genesis;
 use alloy_primitives::B256;
@@ -9,14 +9,19 @@ use reth_e2e_test_utils::{
 };
 use reth_node_builder::{NodeBuilder, NodeHandle};
 use reth_node_core::{args::RpcServerArgs, node_config::NodeConfig};
-use reth_node_ethereum::EthereumNode;
+use reth_node_ethereum::{
+    node::{EthereumAddOns, EthereumPoolBuilder},
+    EthereumNode,
+};
 use reth_primitives_traits::Recovered;
-use reth_provider::CanonStateSubscriptions;
+use reth_provider::{providers::BlockchainProvider, CanonStateSubscriptions};
 use reth_tasks::Runtime;
 use reth_transaction_pool::{
-    blobstore::InMemoryBlobStore, test_utils::OkValidator, BlockInfo, CoinbaseTipOrdering,
-    EthPooledTransaction, Pool, PoolTransaction, TransactionOrigin, TransactionPool,
-    TransactionPoolExt,
+    blobstore::InMemoryBlobStore,
+    error::{PoolErrorKind, RejectReason},
+    test_utils::OkValidator,
+    BlockInfo, CoinbaseTipOrdering, EthPooledTransaction, Pool, PoolTransaction, TransactionOrigin,
+    TransactionPool, TransactionPoolExt,
 };
 use std::{sync::Arc, time::Duration};
 
@@ -309,3 +314,51 @@ async fn maintain_txpool_commit() -> eyre::Result<()> {
 
     Ok(())
 }
+
+// Test that the admission filter of the pool builder rejects transactions after validation.
+#[tokio::test]
+async fn pool_transaction_filter_rejects_transaction() -> eyre::Result<()> {
+    reth_tracing::init_test_tracing();
+    let runtime = Runtime::test();
+
+    let genesis: Genesis = serde_json::from_str(include_str!("../assets/genesis.json")).unwrap();
+    let chain_spec = Arc::new(
+        ChainSpecBuilder::default()
+            .chain(MAINNET.chain)
+            .genesis(genesis)
+            .cancun_activated()
+            .build(),
+    );
+    let node_config = NodeConfig::test().with_chain(chain_spec).with_unused_ports();
+    let pool_builder = EthereumPoolBuilder::default().with_transaction_filter(|tx| {
+        if !tx.value().is_zero() {
+            return Err(RejectReason::new("value transfers are disabled"))
+        }
+        Ok(())
+    });
+    let NodeHandle { node, node_exit_future: _ } = NodeBuilder::new(node_config)
+        .testing_node(runtime)
+        .with_types_and_provider::<EthereumNode, BlockchainProvider<_>>()
+        .with_components(EthereumNode::components().pool(pool_builder))
+        .with_add_ons(EthereumAddOns::default())
+        .launch()
+        .await?;
+
+    let wallet = Wallet::default().inner;
+    let sender = wallet.address();
+    let envelope = TransactionTestContext::transfer_tx(1, wallet).await;
+    let tx = Recovered::new_unchecked(EthereumTxEnvelope::<TxEip4844>::from(envelope), sender);
+    let pooled_tx = EthPooledTransaction::new(tx, 200);
+
+    let err = node.pool.add_transaction(TransactionOrigin::External, pooled_tx).await.unwrap_err();
+    let PoolErrorKind::InvalidTransaction(err) = err.kind else {
+        panic!("unexpected pool error: {err:?}")
+    };
+    assert_eq!(
+        err.downcast_other_ref::<RejectReason>(),
+        Some(&RejectReason::new("value transfers are disabled"))
+    );
+    assert!(node.pool.is_empty());
+
+    Ok(())
+}
@@ -1,17 +1,33 @@
 //! Node builder setup tests.
 
-use std::sync::Arc;
+use alloy_consensus::Transaction;
+use alloy_primitives::{Address, Log, TxNumber, B256};
+use futures::future::BoxFuture;
+use std::{
+    io,
+    str::FromStr,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
 
 use reth_db::{
+    mdbx::DatabaseArguments,
     test_utils::{create_test_rw_db, TempDatabase},
     DatabaseEnv,
 };
+use reth_network::DiscoverySource;
+use reth_network_peers::NodeRecord;
 use reth_node_api::NodeTypesWithDBAdapter;
 use reth_node_builder::{EngineNodeLauncher, FullNodeComponents, NodeBuilder, NodeConfig};
-use reth_node_ethereum::node::{EthereumAddOns, EthereumNode};
+use reth_node_core::{
+    args::DatadirArgs,
+    dirs::{DataDirPath, MaybePlatformPath},
+};
+use reth_node_ethereum::node::{EthereumAddOns, EthereumNode, EthereumPoolBuilder};
 use reth_provider::providers::BlockchainProvider;
 use reth_rpc_builder::Identity;
 use reth_tasks::Runtime;
+use reth_transaction_pool::{error::RejectReason, PoolConfig};
 
 #[test]
 fn test_basic_setup() {
@@ -30,10 +46,19 @@ fn test_basic_setup() {
             Ok(())
         })
         .on_node_started(|_full_node| Ok(()))
+        .on_node_started_async(|_full_node| async { Ok(()) })
+        .on_shutdown(|node| async move {
+            let _provider = node.provider();
+            Ok(())
+        })
         .on_rpc_started(|_ctx, handles| {
             let _client = handles.rpc.http_client();
             Ok(())
         })
+        .on_rpc_stopped(|handles| async move {
+            let _addr = handles.rpc.http_local_addr();
+            Ok(())
+        })
         .map_add_ons(|addons| addons.with_rpc_middleware(Identity::default()))
         .extend_rpc_modules(|ctx| {
             let _ = ctx.config();
@@ -44,6 +69,104 @@ fn test_basic_setup() {
         .check_launch();
 }
 
+#[test]
+fn test_transaction_filter_setup() {
+    let config = NodeConfig::test();
+    let db = create_test_rw_db();
+    let _builder = NodeBuilder::new(config)
+        .with_database(db)
+        .with_types::<EthereumNode>()
+        .with_components(EthereumNode::components().pool(
+            EthereumPoolBuilder::default().with_transaction_filter(|tx| {
+                if tx.to().is_some_and(|to| to.is_zero()) {
+                    return Err(RejectReason::new("calls to the zero address are not allowed"))
+                }
+                Ok(())
+            }),
+        ))
+        .with_add_ons(EthereumAddOns::default())
+        .check_launch();
+}
+
+#[test]
+fn test_genesis_state_root_setup() {
+    let config = NodeConfig::test();
+    let genesis_hash = config.chain.genesis_hash();
+    let state_root = B256::with_last_byte(1);
+    let db = create_test_rw_db();
+    let builder = NodeBuilder::new(config)
+        .with_database(db)
+        .with_types::<EthereumNode>()
+        .with_components(EthereumNode::components())
+        .with_add_ons(EthereumAddOns::default())
+        .with_genesis_state_root(state_root);
+
+    // the chain spec, and with it the fork ID, is derived from the overridden genesis header
+    let chain = &builder.config.chain;
+    assert_eq!(chain.genesis_header().state_root, state_root);
+    assert_eq!(chain.genesis_hash(), chain.genesis_header().hash_slow());
+    assert_ne!(chain.genesis_hash(), genesis_hash);
+}
+
+#[test]
+fn test_map_pool_config_setup() {
+    let config = NodeConfig::test();
+    let db = create_test_rw_db();
+    let _builder = NodeBuilder::new(config)
+        .with_database(db)
+        .with_types::<EthereumNode>()
+        .with_components(EthereumNode::components())
+        .with_add_ons(EthereumAddOns::default())
+        .map_pool_config(|mut pool_config| {
+            pool_config.max_account_slots *= 2;
+            pool_config
+        })
+        .map_pool_config(|pool_config| PoolConfig { minimal_protocol_basefee: 0, ..pool_config })
+        .check_launch();
+}
+
+#[test]
+fn test_prune_filter_setup() {
+    let config = NodeConfig::test();
+    let db = create_test_rw_db();
+    let contract = Address::with_last_byte(1);
+    let _builder = NodeBuilder::new(config)
+        .with_database(db)
+        .with_types::<EthereumNode>()
+        .with_components(EthereumNode::components())
+        .with_add_ons(EthereumAddOns::default())
+        .with_prune_filter(move |_tx_num: TxNumber, logs: &[Log]| {
+            logs.iter().any(|log| log.address == contract)
+        })
+        .check_launch();
+}
+
+#[derive(Debug)]
+struct ZeroIntervalSource;
+
+impl DiscoverySource for ZeroIntervalSource {
+    fn discover(&self) -> BoxFuture<'_, io::Result<Vec<NodeRecord>>> {
+        Box::pin(async { Ok(Vec::new()) })
+    }
+
+    fn interval(&self) -> Duration {
+        Duration::ZERO
+    }
+}
+
+#[test]
+#[should_panic(expected = "discovery source interval must be non-zero")]
+fn test_zero_interval_discovery_source() {
+    let config = NodeConfig::test();
+    let db = create_test_rw_db();
+    let _builder = NodeBuilder::new(config)
+        .with_database(db)
+        .with_types::<EthereumNode>()
+        .with_components(EthereumNode::components())
+        .with_add_ons(EthereumAddOns::default())
+        .with_discovery_source(ZeroIntervalSource);
+}
+
 #[tokio::test]
 async fn test_eth_launcher() {
     let runtime = Runtime::test();
@@ -110,6 +233,49 @@ fn test_eth_launcher_with_tokio_runtime() {
     });
 }
 
+#[test]
+fn test_launch_with_deterministic_scheduling() {
+    let tempdir = tempfile::tempdir().unwrap();
+    let datadir_args = DatadirArgs {
+        datadir: MaybePlatformPath::<DataDirPath>::from_str(tempdir.path().to_str().unwrap())
+            .unwrap(),
+        static_files_path: Some(tempdir.path().join("static")),
+        rocksdb_path: Some(tempdir.path().join("rocksdb")),
+        pprof_dumps_path: Some(tempdir.path().join("pprof")),
+    };
+    let config = NodeConfig::test().with_datadir_args(datadir_args);
+
+    let panicked = Arc::new(Mutex::new(None));
+    let panicked2 = Arc::clone(&panicked);
+    let (builder, scheduler) = NodeBuilder::new(config)
+        .with_database(create_test_rw_db())
+        .with_launch_context(Runtime::test())
+        .on_task_panic(move |name, _| {
+            *panicked2.lock().unwrap() = Some(name.to_string());
+        })
+        .with_deterministic_scheduling();
+    let executor = builder.task_executor().clone();
+
+    let _node = scheduler.block_on(builder.node(EthereumNode::default()).launch()).unwrap();
+
+    // the hook registered before switching executors must still fire
+    executor.spawn_critical_task("deterministic task", async { panic!("intentionally panic") });
+    scheduler.step();
+    assert_eq!(panicked.lock().unwrap().as_deref(), Some("deterministic task"));
+}
+
+#[test]
+fn test_database_from_path_setup() {
+    let datadir = tempfile::tempdir().unwrap();
+    let db_path = datadir.path().join("db");
+    let _builder = NodeBuilder::new(NodeConfig::test())
+        .with_database_from_path(&db_path, DatabaseArguments::test())
+        .unwrap()
+        .node(EthereumNode::default())
+        .check_launch();
+    assert!(db_path.join("database.version").is_file());
+}
+
 #[test]
 fn test_node_setup() {
     let config = NodeConfig::test();
@@ -37,3 +37,22 @@ fn basic_exex() {
         .install_exex("dummy", move |ctx| future::ok(DummyExEx { _ctx: ctx }))
         .check_launch();
 }
+
+#[test]
+fn duplicate_exex_ids() {
+    let config = NodeConfig::test();
+    let db = create_test_rw_db();
+    let builder = NodeBuilder::new(config)
+        .with_database(db)
+        .with_types::<EthereumNode>()
+        .with_components(EthereumNode::components())
+        .with_add_ons(EthereumAddOns::default())
+        .install_exex("dummy", move |ctx| future::ok(DummyExEx { _ctx: ctx }))
+        .install_exex("other", move |ctx| future::ok(DummyExEx { _ctx: ctx }));
+    assert!(builder.validate_exex_ids().is_ok());
+
+    let builder = builder.install_exex("dummy", move |ctx| future::ok(DummyExEx { _ctx: ctx }));
+    assert_eq!(builder.installed_exex_ids().collect::<Vec<_>>(), ["dummy", "other", "dummy"]);
+    let err = builder.validate_exex_ids().unwrap_err();
+    assert!(err.to_string().contains("dummy"));
+}
@@ -219,6 +219,8 @@ pub struct ExExManager<P, N: NodePrimitives> {
     provider: P,
 
     /// Handles to communicate with the `ExEx`'s.
+    ///
+    /// Notifications are sent to the `ExEx`'s in the order of the handles.
     exex_handles: Vec<ExExHandle<N>>,
 
     /// [`ExExNotification`] channel from the [`ExExManagerHandle`]s.
@@ -266,7 +268,8 @@ where
     /// Create a new [`ExExManager`].
     ///
     /// You must provide an [`ExExHandle`] for each `ExEx` and the maximum capacity of the
-    /// notification buffer in the manager.
+    /// notification buffer in the manager. Each notification is sent to the `ExEx`'s in the order
+    /// of `handles`.
     ///
     /// When the capacity is exceeded (which can happen if an `ExEx` is slow) no one can send
     /// notifications over [`ExExManagerHandle`]s until there is capacity again.
@@ -451,7 +454,7 @@ where
     /// 3. Drain [`ExExManagerHandle`] notifications, push them to the internal buffer and update
     ///    the internal buffer capacity.
     /// 5. Send notifications from the internal buffer to those ExExes that are ready to receive new
-    ///    notifications.
+    ///    notifications, in the order of the ExEx handles.
     /// 5. Remove notifications from the internal buffer that have been sent to **all** ExExes and
     ///    update the internal buffer capacity.
     /// 6. Update the channel with the lowest [`FinishedExExHeight`] among all ExExes.
@@ -507,9 +510,7 @@ where
 
         // Advance all poll senders
         let mut min_id = usize::MAX;
-        for idx in (0..this.exex_handles.len()).rev() {
-            let mut exex = this.exex_handles.swap_remove(idx);
-
+        for exex in &mut this.exex_handles {
             // It is a logic error for this to ever underflow since the manager manages the
             // notification IDs
             let notification_index = exex
@@ -523,7 +524,6 @@ where
                 return Poll::Ready(Err(err.into()))
             }
             min_id = min_id.min(exex.next_notification_id);
-            this.exex_handles.push(exex);
         }
 
         // Remove processed buffered notifications
@@ -14,6 +14,7 @@ use reth_network_p2p::{
     },
     error::{DownloadError, DownloadResult},
 };
+use reth_network_types::session::config::INITIAL_REQUEST_TIMEOUT;
 use reth_primitives_traits::{Block, InMemorySize, SealedHeader};
 use reth_storage_api::HeaderProvider;
 use reth_tasks::Runtime;
@@ -25,6 +26,7 @@ use std::{
     pin::Pin,
     sync::Arc,
     task::{Context, Poll},
+    time::Duration,
 };
 use tracing::info;
 
@@ -508,6 +510,8 @@ pub struct BodiesDownloaderBuilder {
     pub max_buffered_blocks_size_bytes: usize,
     /// The maximum number of requests to send concurrently.
     pub concurrent_requests_range: RangeInclusive<usize>,
+    /// Timeout for a single bodies request.
+    pub request_timeout: Duration,
 }
 
 impl BodiesDownloaderBuilder {
@@ -522,6 +526,7 @@ impl BodiesDownloaderBuilder {
                 config.downloader_min_concurrent_requests..=
                     config.downloader_max_concurrent_requests,
             )
+            .with_request_timeout(config.downloader_request_timeout)
     }
 }
 
@@ -532,6 +537,7 @@ impl Default for BodiesDownloaderBuilder {
             stream_batch_size: 1_000,
             max_buffered_blocks_size_bytes: 2 * 1024 * 1024 * 1024, // ~2GB
             concurrent_requests_range: 5..=100,
+            request_timeout: INITIAL_REQUEST_TIMEOUT,
         }
     }
 }
@@ -567,6 +573,12 @@ impl BodiesDownloaderBuilder {
         self
     }
 
+    /// Set the timeout for a single bodies request on the downloader.
+    pub const fn with_request_timeout(mut self, request_timeout: Duration) -> Self {
+        self.request_timeout = request_timeout;
+        self
+    }
+
     /// Consume self and return the concurrent downloader.
     pub fn build<B, C, Provider>(
         self,
@@ -584,9 +596,10 @@ impl BodiesDownloaderBuilder {
             stream_batch_size,
             concurrent_requests_range,
             max_buffered_blocks_size_bytes,
+            request_timeout,
         } = self;
         let metrics = BodyDownloaderMetrics::default();
-        let in_progress_queue = BodiesRequestQueue::new(metrics.clone());
+        let in_progress_queue = BodiesRequestQueue::new(metrics.clone(), request_timeout);
         BodiesDownloader {
             client: Arc::new(client),
             consensus,
@@ -14,6 +14,7 @@ use std::{
     pin::Pin,
     sync::Arc,
     task::{Context, Poll},
+    time::Duration,
 };
 
 /// The wrapper around [`FuturesUnordered`] that keeps information
@@ -24,6 +25,8 @@ pub(crate) struct BodiesRequestQueue<B: Block, C: BodiesClient<Body = B::Body>>
     inner: FuturesUnordered<BodiesRequestFuture<B, C>>,
     /// The downloader metrics.
     metrics: BodyDownloaderMetrics,
+    /// Timeout for a single bodies request.
+    request_timeout: Duration,
     /// Last requested block number.
     pub(crate) last_requested_block_number: Option<BlockNumber>,
 }
@@ -34,8 +37,13 @@ where
     C: BodiesClient<Body = B::Body> + 'static,
 {
     /// Create new instance of request queue.
-    pub(crate) fn new(metrics: BodyDownloaderMetrics) -> Self {
-        Self { metrics, inner: Default::default(), last_requested_block_number: None }
+    pub(crate) fn new(metrics: BodyDownloaderMetrics, request_timeout: Duration) -> Self {
+        Self {
+            metrics,
+            request_timeout,
+            inner: Default::default(),
+            last_requested_block_number: None,
+        }
     }
 
     /// Returns `true` if the queue is empty.
@@ -73,7 +81,8 @@ where
 
         // Create request and push into the queue.
         self.inner.push(
-            BodiesRequestFuture::new(client, consensus, self.metrics.clone()).with_headers(request),
+            BodiesRequestFuture::new(client, consensus, self.metrics.clone(), self.request_timeout)
+                .with_headers(request),
         )
     }
 }
@@ -5,7 +5,7 @@ use futures::{Future, FutureExt};
 use reth_consensus::Consensus;
 use reth_network_p2p::{
     bodies::{client::BodiesClient, response::BlockResponse},
-    error::{DownloadError, DownloadResult},
+    error::{DownloadError, DownloadResult, RequestError},
     priority::Priority,
 };
 use reth_network_peers::{PeerId, WithPeerId};
@@ -15,7 +15,9 @@ use std::{
     pin::Pin,
     sync::Arc,
     task::{ready, Context, Poll},
+    time::Duration,
 };
+use tokio::time::Sleep;
 
 /// Body request implemented as a [Future].
 ///
@@ -48,6 +50,10 @@ pub(crate) struct BodiesRequestFuture<B: Block, C: BodiesClient<Body = B::Body>>
     /// Internal buffer for all blocks
     buffer: Vec<BlockResponse<B>>,
     fut: Option<C::Output>,
+    /// How long to wait for a single request before it is retried.
+    request_timeout: Duration,
+    /// Timeout of the in-flight request, started on first poll.
+    timeout: Option<Pin<Box<Sleep>>>,
     /// Tracks how many bodies we requested in the last request.
     last_request_len: Option<usize>,
 }
@@ -62,6 +68,7 @@ where
         client: Arc<C>,
         consensus: Arc<dyn Consensus<B>>,
         metrics: BodyDownloaderMetrics,
+        request_timeout: Duration,
     ) -> Self {
         Self {
             client,
@@ -72,6 +79,8 @@ where
             buffer: Default::default(),
             last_request_len: None,
             fut: None,
+            request_timeout,
+            timeout: None,
         }
     }
 
@@ -111,6 +120,7 @@ where
         let client = Arc::clone(&self.client);
         self.last_request_len = Some(req.len());
         self.fut = Some(client.get_block_bodies_with_priority(req, priority));
+        self.timeout = None;
     }
 
     /// Process block response.
@@ -227,7 +237,18 @@ where
             // Check if there is a pending requests. It might not exist if all
             // headers are empty and there is nothing to download.
             if let Some(fut) = this.fut.as_mut() {
-                match ready!(fut.poll_unpin(cx)) {
+                let outcome = match fut.poll_unpin(cx) {
+                    Poll::Ready(outcome) => outcome,
+                    Poll::Pending => {
+                        let request_timeout = this.request_timeout;
+                        let timeout = this
+                            .timeout
+                            .get_or_insert_with(|| Box::pin(tokio::time::sleep(request_timeout)));
+                        ready!(timeout.as_mut().poll(cx));
+                        Err(RequestError::Timeout)
+                    }
+                };
+                match outcome {
                     Ok(response) => {
                         let peer_id = response.peer_id();
                         if let Err(error) = this.on_block_response(response) {
@@ -261,6 +282,7 @@ mod tests {
     };
     use reth_consensus::test_utils::TestConsensus;
     use reth_ethereum_primitives::Block;
+    use reth_network_types::session::config::INITIAL_REQUEST_TIMEOUT;
     use reth_testing_utils::{generators, generators::random_header_range};
 
     /// Check if future returns empty bodies without dispatching any requests.
@@ -274,6 +296,7 @@ mod tests {
             client.clone(),
             Arc::new(TestConsensus::default()),
             BodyDownloaderMetrics::default(),
+            INITIAL_REQUEST_TIMEOUT,
         )
         .with_headers(headers.clone());
 
@@ -298,6 +321,7 @@ mod tests {
             client.clone(),
             Arc::new(TestConsensus::default()),
             BodyDownloaderMetrics::default(),
+            INITIAL_REQUEST_TIMEOUT,
         )
         .with_headers(headers.clone());
 
@@ -211,6 +211,21 @@ impl<B: FullBlock> FileClient<B> {
     pub fn total_transactions(&self) -> usize {
         self.bodies.iter().fold(0, |acc, (_, body)| acc + body.transactions().len())
     }
+
+    /// Consumes the client and returns its blocks in ascending order of block number.
+    ///
+    /// Headers without a matching body are skipped.
+    pub fn into_blocks(mut self) -> Vec<SealedBlock<B>> {
+        self.hash_to_number
+            .into_iter()
+            .sorted_unstable_by_key(|(_, number)| *number)
+            .filter_map(|(hash, number)| {
+                let header = self.headers.remove(&number)?;
+                let body = self.bodies.remove(&hash)?;
+                Some(SealedBlock::from_sealed_parts(SealedHeader::new(header, hash), body))
+            })
+            .collect()
+    }
 }
 
 struct FileClientBuilder<B: Block> {
@@ -771,6 +786,20 @@ mod tests {
         assert_eq!(downloaded_headers, headers[1..]);
     }
 
+    #[tokio::test]
+    async fn test_into_blocks() {
+        let (file, headers, _) = generate_bodies_file(0..=19).await;
+        let client: FileClient<Block> =
+            FileClient::from_file(file, NoopConsensus::arc()).await.unwrap();
+
+        let blocks = client.into_blocks();
+        assert_eq!(blocks.len(), headers.len());
+        for (block, header) in blocks.iter().zip(&headers) {
+            assert_eq!(block.number, header.number);
+            assert_eq!(block.hash(), header.hash());
+        }
+    }
+
     #[tokio::test]
     async fn test_download_bodies_from_file() {
         // Generate some random blocks
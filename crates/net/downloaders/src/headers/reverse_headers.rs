@@ -11,7 +11,7 @@ use rayon::prelude::*;
 use reth_config::config::HeadersConfig;
 use reth_consensus::HeaderValidator;
 use reth_network_p2p::{
-    error::{DownloadError, DownloadResult, PeerRequestResult},
+    error::{DownloadError, DownloadResult, PeerRequestResult, RequestError},
     headers::{
         client::{HeadersClient, HeadersRequest},
         downloader::{validate_header_download, HeaderDownloader, SyncTarget},
@@ -20,6 +20,7 @@ use reth_network_p2p::{
     priority::Priority,
 };
 use reth_network_peers::PeerId;
+use reth_network_types::session::config::INITIAL_REQUEST_TIMEOUT;
 use reth_primitives_traits::{GotExpected, SealedHeader};
 use reth_tasks::Runtime;
 use std::{
@@ -29,8 +30,10 @@ use std::{
     pin::Pin,
     sync::Arc,
     task::{ready, Context, Poll},
+    time::Duration,
 };
 use thiserror::Error;
+use tokio::time::Sleep;
 use tracing::{debug, error, trace};
 
 /// A heuristic that is used to determine the number of requests that should be prepared for a peer.
@@ -91,6 +94,8 @@ pub struct ReverseHeadersDownloader<H: HeadersClient> {
     stream_batch_size: usize,
     /// Maximum amount of received headers to buffer internally.
     max_buffered_responses: usize,
+    /// How long to wait for a single request before it is retried.
+    request_timeout: Duration,
     /// Contains the request to retrieve the headers for the sync target
     ///
     /// This will give us the block number of the `sync_target`, after which we can send multiple
@@ -607,6 +612,8 @@ where
         HeadersRequestFuture {
             request: Some(request.clone()),
             fut: client.get_headers_with_priority(request, priority),
+            request_timeout: self.request_timeout,
+            timeout: None,
         }
     }
 
@@ -913,10 +920,15 @@ where
 }
 
 /// A future that returns a list of headers on success.
+///
+/// Resolves with [`RequestError::Timeout`] if no response arrives before the timeout elapses.
 #[derive(Debug)]
 struct HeadersRequestFuture<F> {
     request: Option<HeadersRequest>,
     fut: F,
+    request_timeout: Duration,
+    /// Started on first poll.
+    timeout: Option<Pin<Box<Sleep>>>,
 }
 
 impl<F, H> Future for HeadersRequestFuture<F>
@@ -927,7 +939,17 @@ where
 
     fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
         let this = self.get_mut();
-        let outcome = ready!(this.fut.poll_unpin(cx));
+        let outcome = match this.fut.poll_unpin(cx) {
+            Poll::Ready(outcome) => outcome,
+            Poll::Pending => {
+                let request_timeout = this.request_timeout;
+                let timeout = this
+                    .timeout
+                    .get_or_insert_with(|| Box::pin(tokio::time::sleep(request_timeout)));
+                ready!(timeout.as_mut().poll(cx));
+                Err(RequestError::Timeout)
+            }
+        };
         let request = this.request.take().unwrap();
 
         Poll::Ready(HeadersRequestOutcome { request, outcome })
@@ -1106,6 +1128,8 @@ pub struct ReverseHeadersDownloaderBuilder {
     max_concurrent_requests: usize,
     /// How many responses to buffer
     max_buffered_responses: usize,
+    /// Timeout for a single request
+    request_timeout: Duration,
 }
 
 impl ReverseHeadersDownloaderBuilder {
@@ -1117,6 +1141,7 @@ impl ReverseHeadersDownloaderBuilder {
             .min_concurrent_requests(config.downloader_min_concurrent_requests)
             .max_concurrent_requests(config.downloader_max_concurrent_requests)
             .max_buffered_responses(config.downloader_max_buffered_responses)
+            .request_timeout(config.downloader_request_timeout)
             .stream_batch_size(config.commit_threshold as usize)
     }
 }
@@ -1131,6 +1156,7 @@ impl Default for ReverseHeadersDownloaderBuilder {
             max_concurrent_requests: 100,
             min_concurrent_requests: 5,
             max_buffered_responses: 100,
+            request_timeout: INITIAL_REQUEST_TIMEOUT,
         }
     }
 }
@@ -1183,6 +1209,14 @@ impl ReverseHeadersDownloaderBuilder {
         self
     }
 
+    /// Set the timeout for a single headers request.
+    ///
+    /// Requests that don't receive a response within this duration are retried.
+    pub const fn request_timeout(mut self, request_timeout: Duration) -> Self {
+        self.request_timeout = request_timeout;
+        self
+    }
+
     /// Build [`ReverseHeadersDownloader`] with provided consensus
     /// and header client implementations
     pub fn build<H>(
@@ -1199,6 +1233,7 @@ impl ReverseHeadersDownloaderBuilder {
             min_concurrent_requests,
             max_concurrent_requests,
             max_buffered_responses,
+            request_timeout,
         } = self;
         ReverseHeadersDownloader {
             consensus,
@@ -1215,6 +1250,7 @@ impl ReverseHeadersDownloaderBuilder {
             max_concurrent_requests,
             stream_batch_size,
             max_buffered_responses,
+            request_timeout,
             sync_target_request: None,
             in_progress_queue: Default::default(),
             buffered_responses: Default::default(),
@@ -1,7 +1,11 @@
 //! Implements the `GetReceipts` and `Receipts` message types.
 
 use alloc::vec::Vec;
-use alloy_consensus::{ReceiptWithBloom, RlpDecodableReceipt, RlpEncodableReceipt, TxReceipt};
+use alloy_consensus::{
+    proofs::calculate_receipt_root, ReceiptWithBloom, RlpDecodableReceipt, RlpEncodableReceipt,
+    TxReceipt,
+};
+use alloy_eips::eip2718::Encodable2718;
 use alloy_primitives::B256;
 use alloy_rlp::{RlpDecodableWrapper, RlpEncodableWrapper};
 use derive_more::{Deref, IntoIterator};
@@ -28,6 +32,43 @@ pub struct GetReceipts(
     pub Vec<B256>,
 );
 
+impl GetReceipts {
+    /// Checks that the request asks for the receipts of at most `max` blocks.
+    pub const fn validate_len(&self, max: usize) -> Result<(), RequestTooLarge> {
+        let len = self.0.len();
+        if len > max {
+            return Err(RequestTooLarge { len, max })
+        }
+        Ok(())
+    }
+
+    /// Splits the request into consecutive requests for the receipts of at most `max` blocks each.
+    ///
+    /// An empty request yields no chunks.
+    ///
+    /// # Panics
+    ///
+    /// If `max` is zero.
+    pub fn chunked(self, max: usize) -> impl Iterator<Item = Self> {
+        assert!(max > 0, "chunk size must be non-zero");
+        let mut hashes = self.0.into_iter().peekable();
+        core::iter::from_fn(move || {
+            hashes.peek()?;
+            Some(Self(hashes.by_ref().take(max).collect()))
+        })
+    }
+}
+
+/// Error returned if a request asks for more items than allowed.
+#[derive(thiserror::Error, Debug, Clone, Copy, PartialEq, Eq)]
+#[error("request for {len} items exceeds the limit of {max}")]
+pub struct RequestTooLarge {
+    /// Number of items in the request.
+    pub len: usize,
+    /// Maximum number of items allowed.
+    pub max: usize,
+}
+
 /// Eth/70 `GetReceipts` request payload that supports partial receipt queries.
 ///
 /// When used with eth/70, the request id is carried by the surrounding
@@ -75,6 +116,47 @@ pub struct Receipts<T = Receipt>(
     pub Vec<Vec<ReceiptWithBloom<T>>>,
 );
 
+impl<T> Receipts<T> {
+    /// Returns an iterator over the receipts of all blocks, ignoring block boundaries.
+    pub fn iter_all(&self) -> impl Iterator<Item = &ReceiptWithBloom<T>> {
+        self.0.iter().flatten()
+    }
+
+    /// Returns an iterator over the receipts of all blocks, paired with the index of their block.
+    pub fn iter_all_with_block(&self) -> impl Iterator<Item = (usize, &ReceiptWithBloom<T>)> {
+        self.0.iter().enumerate().flat_map(|(idx, receipts)| receipts.iter().map(move |r| (idx, r)))
+    }
+}
+
+impl<T: TxReceipt> Receipts<T> {
+    /// Checks that the `cumulative_gas_used` of the receipts within each block is non-decreasing.
+    ///
+    /// Returns the position of the first receipt that violates this.
+    pub fn validate_cumulative_gas(&self) -> Result<(), CumulativeGasError> {
+        validate_cumulative_gas(
+            self.iter().map(|receipts| receipts.iter().map(|r| r.receipt.cumulative_gas_used())),
+        )
+    }
+}
+
+impl<T> Receipts<T>
+where
+    ReceiptWithBloom<T>: Encodable2718,
+{
+    /// Computes the receipts root of the block at `block_index`, to be matched against the
+    /// header's `receipts_root`.
+    ///
+    /// Returns `None` if `block_index` is out of range.
+    pub fn receipts_root(&self, block_index: usize) -> Option<B256> {
+        self.0.get(block_index).map(|receipts| calculate_receipt_root(receipts))
+    }
+
+    /// Computes the receipts roots of all blocks in the response, in order.
+    pub fn receipts_roots(&self) -> Vec<B256> {
+        self.0.iter().map(|receipts| calculate_receipt_root(receipts)).collect()
+    }
+}
+
 impl<T: RlpEncodableReceipt> alloy_rlp::Encodable for Receipts<T> {
     #[inline]
     fn encode(&self, out: &mut dyn alloy_rlp::BufMut) {
@@ -86,6 +168,41 @@ impl<T: RlpEncodableReceipt> alloy_rlp::Encodable for Receipts<T> {
     }
 }
 
+impl<T: RlpEncodableReceipt> Receipts<T> {
+    /// Returns the exact length of the RLP encoding of the receipts, without encoding them.
+    pub fn rlp_size(&self) -> usize {
+        alloy_rlp::Encodable::length(self)
+    }
+
+    /// Splits the receipts into consecutive chunks whose RLP encoding is at most `max_bytes`.
+    ///
+    /// Receipts of a block are never split across chunks. A block whose receipts alone exceed
+    /// `max_bytes` forms its own chunk.
+    pub fn split_by_size(self, max_bytes: usize) -> Vec<Self> {
+        let list_len = |payload_len: usize| alloy_rlp::length_of_length(payload_len) + payload_len;
+
+        let mut chunks = Vec::new();
+        let mut chunk = Vec::new();
+        let mut payload_len = 0;
+        for block in self.0 {
+            let block_len = alloy_rlp::Encodable::length(&block);
+            if !chunk.is_empty() && list_len(payload_len + block_len) > max_bytes {
+                chunks.push(Self(core::mem::take(&mut chunk)));
+                payload_len = 0;
+            }
+            if list_len(block_len) > max_bytes {
+                tracing::warn!(target: "net::eth", block_len, max_bytes, "Block receipts exceed the maximum chunk size");
+            }
+            payload_len += block_len;
+            chunk.push(block);
+        }
+        if !chunk.is_empty() {
+            chunks.push(Self(chunk));
+        }
+        chunks
+    }
+}
+
 impl<T: RlpDecodableReceipt> alloy_rlp::Decodable for Receipts<T> {
     #[inline]
     fn decode(buf: &mut &[u8]) -> alloy_rlp::Result<Self> {
@@ -104,6 +221,18 @@ impl<T: RlpDecodableReceipt> alloy_rlp::Decodable for Receipts<T> {
 #[add_arbitrary_tests(rlp)]
 pub struct Receipts69<T = Receipt>(pub Vec<Vec<T>>);
 
+impl<T> Receipts69<T> {
+    /// Returns an iterator over the receipts of all blocks, ignoring block boundaries.
+    pub fn iter_all(&self) -> impl Iterator<Item = &T> {
+        self.0.iter().flatten()
+    }
+
+    /// Returns an iterator over the receipts of all blocks, paired with the index of their block.
+    pub fn iter_all_with_block(&self) -> impl Iterator<Item = (usize, &T)> {
+        self.0.iter().enumerate().flat_map(|(idx, receipts)| receipts.iter().map(move |r| (idx, r)))
+    }
+}
+
 impl<T: TxReceipt> Receipts69<T> {
     /// Encodes all receipts with the bloom filter.
     ///
@@ -123,6 +252,24 @@ impl<T: TxReceipt> Receipts69<T> {
     }
 }
 
+impl<T: TxReceipt> Receipts69<T> {
+    /// Checks that the `cumulative_gas_used` of the receipts within each block is non-decreasing.
+    ///
+    /// Returns the position of the first receipt that violates this.
+    pub fn validate_cumulative_gas(&self) -> Result<(), CumulativeGasError> {
+        validate_cumulative_gas(
+            self.iter().map(|receipts| receipts.iter().map(|r| r.cumulative_gas_used())),
+        )
+    }
+}
+
+impl<T: alloy_rlp::Encodable> Receipts69<T> {
+    /// Returns the exact length of the RLP encoding of the receipts, without encoding them.
+    pub fn rlp_size(&self) -> usize {
+        alloy_rlp::Encodable::length(self)
+    }
+}
+
 impl<T: TxReceipt> From<Receipts69<T>> for Receipts<T> {
     fn from(receipts: Receipts69<T>) -> Self {
         receipts.into_with_bloom()
@@ -191,12 +338,130 @@ impl<T: TxReceipt> From<Receipts70<T>> for Receipts<T> {
     }
 }
 
+/// Incrementally assembles a receipts response whose RLP encoding stays within a byte budget.
+///
+/// Blocks are added with [`Self::push_block`] until the next block would exceed `max_bytes`. The
+/// RLP size is tracked while adding blocks, so it never needs to be measured again.
+///
+/// `R` is the receipt type of a block, [`ReceiptWithBloom`] for [`Receipts`] and the bare receipt
+/// for [`Receipts69`].
+#[derive(Clone, Debug)]
+pub struct ReceiptsResponseBuilder<R> {
+    /// The receipts of the blocks added so far.
+    blocks: Vec<Vec<R>>,
+    /// The combined RLP length of the blocks added so far.
+    payload_len: usize,
+    /// The maximum RLP length of the response.
+    max_bytes: usize,
+}
+
+impl<R: alloy_rlp::Encodable> ReceiptsResponseBuilder<R> {
+    /// Creates a new builder for a response of at most `max_bytes`.
+    pub const fn new(max_bytes: usize) -> Self {
+        Self { blocks: Vec::new(), payload_len: 0, max_bytes }
+    }
+
+    /// Returns the number of blocks added so far.
+    pub const fn len(&self) -> usize {
+        self.blocks.len()
+    }
+
+    /// Returns `true` if no block was added yet.
+    pub const fn is_empty(&self) -> bool {
+        self.blocks.is_empty()
+    }
+
+    /// Returns the RLP length of the response built so far.
+    pub const fn rlp_size(&self) -> usize {
+        alloy_rlp::length_of_length(self.payload_len) + self.payload_len
+    }
+
+    /// Returns `true` if adding a block with the given receipts would exceed the byte budget.
+    ///
+    /// The first block never exceeds it, so that a response can always make progress.
+    pub fn would_exceed(&self, receipts: &[R]) -> bool {
+        self.would_exceed_with_len(alloy_rlp::list_length(receipts))
+    }
+
+    /// Adds the receipts of the next block if they fit into the byte budget.
+    ///
+    /// Returns the receipts back if adding them would exceed it, see [`Self::would_exceed`].
+    pub fn push_block(&mut self, receipts: Vec<R>) -> Result<(), Vec<R>> {
+        let block_len = alloy_rlp::Encodable::length(&receipts);
+        if self.would_exceed_with_len(block_len) {
+            return Err(receipts)
+        }
+        self.payload_len += block_len;
+        self.blocks.push(receipts);
+        Ok(())
+    }
+
+    fn would_exceed_with_len(&self, block_len: usize) -> bool {
+        if self.blocks.is_empty() {
+            return false
+        }
+        let payload_len = self.payload_len + block_len;
+        alloy_rlp::length_of_length(payload_len) + payload_len > self.max_bytes
+    }
+}
+
+impl<T: RlpEncodableReceipt> ReceiptsResponseBuilder<ReceiptWithBloom<T>> {
+    /// Returns the assembled [`Receipts`] response.
+    pub fn finish(self) -> Receipts<T> {
+        Receipts(self.blocks)
+    }
+}
+
+impl<T: RlpEncodableReceipt + alloy_rlp::Encodable> ReceiptsResponseBuilder<T> {
+    /// Returns the assembled [`Receipts69`] response.
+    ///
+    /// Only available for bare receipts: [`ReceiptWithBloom`] is not a [`RlpEncodableReceipt`], so
+    /// a builder for a bloom-bearing [`Receipts`] response can't be finished as eth/69.
+    pub fn finish_69(self) -> Receipts69<T> {
+        Receipts69(self.blocks)
+    }
+}
+
+/// Error returned if the cumulative gas of a block's receipts decreases.
+#[derive(thiserror::Error, Debug, Clone, Copy, PartialEq, Eq)]
+#[error(
+    "cumulative gas decreased from {previous} to {current} at receipt {receipt_index} of block {block_index}"
+)]
+pub struct CumulativeGasError {
+    /// Index of the block within the response.
+    pub block_index: usize,
+    /// Index of the offending receipt within the block.
+    pub receipt_index: usize,
+    /// Cumulative gas used of the preceding receipt.
+    pub previous: u64,
+    /// Cumulative gas used of the offending receipt.
+    pub current: u64,
+}
+
+/// Checks that the cumulative gas values within each block are non-decreasing.
+fn validate_cumulative_gas<B, R>(blocks: B) -> Result<(), CumulativeGasError>
+where
+    B: IntoIterator<Item = R>,
+    R: IntoIterator<Item = u64>,
+{
+    for (block_index, receipts) in blocks.into_iter().enumerate() {
+        let mut previous = 0;
+        for (receipt_index, current) in receipts.into_iter().enumerate() {
+            if current < previous {
+                return Err(CumulativeGasError { block_index, receipt_index, previous, current })
+            }
+            previous = current;
+        }
+    }
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::{message::RequestPair, GetReceipts, Receipts};
     use alloy_consensus::TxType;
-    use alloy_primitives::{hex, Log};
+    use alloy_primitives::{bytes, hex, Address, Log};
     use alloy_rlp::{Decodable, Encodable};
 
     #[test]
@@ -342,6 +607,52 @@ mod tests {
         assert_eq!(encoded, data);
     }
 
+    #[test]
+    fn validate_cumulative_gas_69() {
+        let receipt = |cumulative_gas_used| -> Receipt {
+            Receipt { cumulative_gas_used, ..Default::default() }
+        };
+
+        let receipts =
+            Receipts69(vec![vec![receipt(21_000), receipt(42_000)], vec![receipt(21_000)]]);
+        assert_eq!(receipts.validate_cumulative_gas(), Ok(()));
+        assert_eq!(receipts.into_with_bloom().validate_cumulative_gas(), Ok(()));
+
+        let receipts =
+            Receipts69(vec![vec![receipt(21_000)], vec![receipt(42_000), receipt(21_000)]]);
+        let expected = CumulativeGasError {
+            block_index: 1,
+            receipt_index: 1,
+            previous: 42_000,
+            current: 21_000,
+        };
+        assert_eq!(receipts.validate_cumulative_gas(), Err(expected));
+        assert_eq!(receipts.into_with_bloom().validate_cumulative_gas(), Err(expected));
+    }
+
+    #[test]
+    fn iter_all_receipts() {
+        let receipt = |cumulative_gas_used| -> Receipt {
+            Receipt { cumulative_gas_used, ..Default::default() }
+        };
+
+        let receipts =
+            Receipts69(vec![vec![receipt(21_000), receipt(42_000)], vec![], vec![receipt(21_000)]]);
+        let gas = |r: &Receipt| r.cumulative_gas_used;
+        assert_eq!(receipts.iter_all().map(gas).collect::<Vec<_>>(), [21_000, 42_000, 21_000]);
+        assert_eq!(
+            receipts.iter_all_with_block().map(|(idx, r)| (idx, gas(r))).collect::<Vec<_>>(),
+            [(0, 21_000), (0, 42_000), (2, 21_000)]
+        );
+
+        let receipts = receipts.into_with_bloom();
+        assert_eq!(receipts.iter_all().count(), 3);
+        assert_eq!(
+            receipts.iter_all_with_block().map(|(idx, _)| idx).collect::<Vec<_>>(),
+            [0, 0, 2]
+        );
+    }
+
     #[test]
     fn encode_get_receipts70_inline_shape() {
         let req = RequestPair {
@@ -378,6 +689,135 @@ mod tests {
         assert_eq!(decoded, req);
     }
 
+    #[test]
+    fn rlp_size_matches_encoding() {
+        let receipt = Receipt {
+            tx_type: TxType::Eip1559,
+            success: true,
+            cumulative_gas_used: 21_000,
+            logs: vec![Log::new_unchecked(
+                Address::with_last_byte(1),
+                vec![B256::with_last_byte(2)],
+                bytes!("0100ff"),
+            )],
+        };
+
+        let receipts69 = Receipts69(vec![vec![receipt.clone(), receipt], vec![]]);
+        assert_eq!(receipts69.rlp_size(), alloy_rlp::encode(&receipts69).len());
+
+        let receipts = receipts69.into_with_bloom();
+        assert_eq!(receipts.rlp_size(), alloy_rlp::encode(&receipts).len());
+    }
+
+    #[test]
+    fn receipts_roots() {
+        let receipt = Receipt {
+            tx_type: TxType::Eip1559,
+            success: true,
+            cumulative_gas_used: 21_000,
+            logs: vec![Log::new_unchecked(Address::ZERO, vec![B256::ZERO], bytes!("00"))],
+        };
+        let receipts = Receipts(vec![vec![], vec![receipt.clone().into_with_bloom()]]);
+
+        assert_eq!(receipts.receipts_root(0), Some(alloy_consensus::EMPTY_ROOT_HASH));
+        assert_eq!(
+            receipts.receipts_root(1),
+            Some(reth_ethereum_primitives::calculate_receipt_root_no_memo(&[receipt]))
+        );
+        assert_eq!(receipts.receipts_root(2), None);
+        assert_eq!(
+            receipts.receipts_roots(),
+            vec![receipts.receipts_root(0).unwrap(), receipts.receipts_root(1).unwrap()]
+        );
+    }
+
+    #[test]
+    fn receipts_split_by_size() {
+        let receipt = |logs: usize| ReceiptWithBloom {
+            receipt: Receipt {
+                tx_type: TxType::Eip1559,
+                success: true,
+                cumulative_gas_used: 21_000,
+                logs: vec![Log::new_unchecked(Address::ZERO, vec![B256::ZERO], bytes!("00")); logs],
+            },
+            logs_bloom: Default::default(),
+        };
+        let small = vec![receipt(0)];
+        let large = vec![receipt(10), receipt(10)];
+        let max_bytes = Receipts(vec![large.clone()]).rlp_size();
+        assert!(Receipts(vec![small.clone(); 3]).rlp_size() < max_bytes);
+
+        let receipts = Receipts(vec![
+            small.clone(),
+            small.clone(),
+            large.clone(),
+            small.clone(),
+            vec![receipt(20)],
+            small.clone(),
+        ]);
+        let chunks = receipts.clone().split_by_size(max_bytes);
+
+        assert_eq!(
+            chunks,
+            vec![
+                Receipts(vec![small.clone(), small.clone()]),
+                Receipts(vec![large]),
+                Receipts(vec![small.clone()]),
+                Receipts(vec![vec![receipt(20)]]),
+                Receipts(vec![small]),
+            ]
+        );
+        // all chunks except the oversized block fit
+        for (idx, chunk) in chunks.iter().enumerate() {
+            if idx != 3 {
+                assert!(chunk.rlp_size() <= max_bytes);
+            }
+        }
+        assert_eq!(chunks.into_iter().flatten().collect::<Vec<_>>(), receipts.0);
+
+        assert!(Receipts::<Receipt>::default().split_by_size(max_bytes).is_empty());
+    }
+
+    #[test]
+    fn receipts_response_builder() {
+        let receipt = |logs: usize| Receipt {
+            tx_type: TxType::Eip1559,
+            success: true,
+            cumulative_gas_used: 21_000,
+            logs: vec![Log::new_unchecked(Address::ZERO, vec![B256::ZERO], bytes!("00")); logs],
+        };
+        let with_bloom = |logs: usize| ReceiptWithBloom {
+            receipt: receipt(logs),
+            logs_bloom: Default::default(),
+        };
+
+        let block = vec![with_bloom(1), with_bloom(2)];
+        let max_bytes = Receipts(vec![block.clone(); 2]).rlp_size();
+
+        let mut builder = ReceiptsResponseBuilder::new(max_bytes);
+        assert!(builder.is_empty());
+        builder.push_block(block.clone()).unwrap();
+        builder.push_block(block.clone()).unwrap();
+        // the response is exactly at the budget, a block without receipts doesn't fit anymore
+        assert_eq!(builder.rlp_size(), max_bytes);
+        assert!(builder.would_exceed(&[]));
+        assert_eq!(builder.push_block(block.clone()), Err(block.clone()));
+        assert_eq!(builder.len(), 2);
+
+        let receipts = builder.finish();
+        assert_eq!(receipts, Receipts(vec![block.clone(); 2]));
+        assert_eq!(receipts.rlp_size(), max_bytes);
+
+        // the first block is always accepted
+        let mut builder = ReceiptsResponseBuilder::new(1);
+        assert!(!builder.would_exceed(&[receipt(3)]));
+        builder.push_block(vec![receipt(3)]).unwrap();
+        assert!(builder.push_block(vec![receipt(0)]).is_err());
+        let receipts = builder.finish_69();
+        assert_eq!(receipts, Receipts69(vec![vec![receipt(3)]]));
+        assert_eq!(receipts.rlp_size(), Receipts69(vec![vec![receipt(3)]]).rlp_size());
+    }
+
     #[test]
     fn encode_receipts70_inline_shape() {
         let payload: Receipts70<Receipt> =
@@ -407,4 +847,32 @@ mod tests {
         assert!(buf.is_empty(), "buffer not fully consumed on decode");
         assert_eq!(decoded, resp);
     }
+
+    #[test]
+    fn get_receipts_validate_len() {
+        let request = GetReceipts(vec![B256::ZERO; 4]);
+        assert_eq!(request.validate_len(4), Ok(()));
+        assert_eq!(request.validate_len(3), Err(RequestTooLarge { len: 4, max: 3 }));
+    }
+
+    #[test]
+    fn get_receipts_chunked() {
+        let hashes = (0..5u8).map(B256::with_last_byte).collect::<Vec<_>>();
+
+        let chunks = GetReceipts(hashes.clone()).chunked(5).collect::<Vec<_>>();
+        assert_eq!(chunks, vec![GetReceipts(hashes.clone())]);
+
+        let chunks = GetReceipts(hashes.clone()).chunked(2).collect::<Vec<_>>();
+        assert_eq!(
+            chunks,
+            vec![
+                GetReceipts(hashes[..2].to_vec()),
+                GetReceipts(hashes[2..4].to_vec()),
+                GetReceipts(hashes[4..].to_vec()),
+            ]
+        );
+        assert!(chunks.iter().all(|chunk| chunk.validate_len(2).is_ok()));
+
+        assert_eq!(GetReceipts::default().chunked(2).count(), 0);
+    }
 }
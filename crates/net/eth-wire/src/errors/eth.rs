@@ -63,6 +63,14 @@ impl EthStreamError {
         }
     }
 
+    /// Returns whether this error is caused by a message that exceeds the maximum message size.
+    pub const fn is_message_too_big(&self) -> bool {
+        matches!(
+            self,
+            Self::MessageTooBig(_) | Self::P2PStreamError(P2PStreamError::MessageTooBig { .. })
+        )
+    }
+
     /// Returns whether this error indicates a protocol breach on the receive side.
     ///
     /// These are errors caused by the remote peer sending invalid or malformed data
@@ -0,0 +1,69 @@
+//! Rate limiting of inbound block announcements.
+
+use alloy_primitives::map::HashMap;
+use reth_network_peers::PeerId;
+use std::time::{Duration, Instant};
+
+/// Tracks inbound `NewBlock` and `NewBlockHashes` announcements per peer and limits them to a
+/// fixed number per second.
+#[derive(Debug)]
+pub(crate) struct BlockAnnouncementLimiter {
+    /// Max number of announcements accepted per peer per second.
+    max_per_sec: u32,
+    /// The start of the current window and the announcements accepted within it, per peer.
+    peers: HashMap<PeerId, (Instant, u32)>,
+}
+
+impl BlockAnnouncementLimiter {
+    /// Creates a new limiter that accepts at most `max_per_sec` announcements per peer per second.
+    pub(crate) fn new(max_per_sec: u32) -> Self {
+        Self { max_per_sec, peers: Default::default() }
+    }
+
+    /// Records an announcement from the peer and returns `false` if it exceeds the limit and
+    /// should be dropped.
+    pub(crate) fn try_acquire(&mut self, peer_id: PeerId) -> bool {
+        self.try_acquire_at(peer_id, Instant::now())
+    }
+
+    fn try_acquire_at(&mut self, peer_id: PeerId, now: Instant) -> bool {
+        let (window_start, count) = self.peers.entry(peer_id).or_insert((now, 0));
+        if now.duration_since(*window_start) >= Duration::from_secs(1) {
+            *window_start = now;
+            *count = 0;
+        }
+        if *count >= self.max_per_sec {
+            return false
+        }
+        *count += 1;
+        true
+    }
+
+    /// Removes the tracked state of the peer, e.g. once its session is closed.
+    pub(crate) fn remove_peer(&mut self, peer_id: &PeerId) {
+        self.peers.remove(peer_id);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn limits_announcements_per_peer() {
+        let mut limiter = BlockAnnouncementLimiter::new(2);
+        let (peer, other) = (PeerId::random(), PeerId::random());
+        let now = Instant::now();
+
+        assert!(limiter.try_acquire_at(peer, now));
+        assert!(limiter.try_acquire_at(peer, now));
+        assert!(!limiter.try_acquire_at(peer, now + Duration::from_millis(500)));
+        assert!(limiter.try_acquire_at(other, now));
+
+        // next window
+        assert!(limiter.try_acquire_at(peer, now + Duration::from_secs(1)));
+
+        limiter.remove_peer(&peer);
+        assert!(!limiter.peers.contains_key(&peer));
+    }
+}
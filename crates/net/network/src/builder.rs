@@ -68,7 +68,8 @@ impl<Tx, Eth, N: NetworkPrimitives> NetworkBuilder<Tx, Eth, N> {
         let (tx, rx) = mpsc::channel(ETH_REQUEST_CHANNEL_CAPACITY);
         network.set_eth_request_handler(tx);
         let peers = network.handle().peers_handle().clone();
-        let request_handler = EthRequestHandler::new(client, peers, rx);
+        let executor = network.executor().clone();
+        let request_handler = EthRequestHandler::new(client, peers, rx, executor);
         NetworkBuilder { network, request_handler, transactions }
     }
 
@@ -32,6 +32,11 @@ use crate::{
 };
 pub use secp256k1::SecretKey;
 
+/// Default max number of inbound block announcements accepted per peer per second.
+///
+/// This is well above the rate of any honest peer and only affects announcement floods.
+pub const DEFAULT_MAX_BLOCK_ANNOUNCEMENTS_PER_SEC: u32 = 100;
+
 /// Convenience function to create a new random [`SecretKey`]
 pub fn rng_secret_key() -> SecretKey {
     SecretKey::new(&mut rand_08::thread_rng())
@@ -100,6 +105,11 @@ pub struct NetworkConfig<C, N: NetworkPrimitives = EthNetworkPrimitives> {
     /// List of block number-hash pairs to check for required blocks.
     /// If non-empty, peers that don't have these blocks will be filtered out.
     pub required_block_hashes: Vec<BlockNumHash>,
+    /// Max number of inbound block announcements accepted per peer per second.
+    pub max_block_announcements_per_sec: u32,
+    /// Whether to recompute the bloom of each receipt received from a peer and compare it to
+    /// the bloom supplied by the peer.
+    pub verify_receipt_blooms: bool,
 }
 
 // === impl NetworkConfig ===
@@ -223,6 +233,10 @@ pub struct NetworkConfigBuilder<N: NetworkPrimitives = EthNetworkPrimitives> {
     eth_max_message_size: usize,
     /// List of block hashes to check for required blocks.
     required_block_hashes: Vec<BlockNumHash>,
+    /// Max number of inbound block announcements accepted per peer per second.
+    max_block_announcements_per_sec: u32,
+    /// Whether to verify the receipt blooms supplied by peers.
+    verify_receipt_blooms: bool,
     /// Optional network id
     network_id: Option<u64>,
 }
@@ -267,6 +281,8 @@ impl<N: NetworkPrimitives> NetworkConfigBuilder<N> {
             handshake: Arc::new(EthHandshake::default()),
             eth_max_message_size: MAX_MESSAGE_SIZE,
             required_block_hashes: Vec::new(),
+            max_block_announcements_per_sec: DEFAULT_MAX_BLOCK_ANNOUNCEMENTS_PER_SEC,
+            verify_receipt_blooms: false,
             network_id: None,
         }
     }
@@ -556,6 +572,24 @@ impl<N: NetworkPrimitives> NetworkConfigBuilder<N> {
         self
     }
 
+    /// Sets the max number of inbound block announcements accepted per peer per second.
+    ///
+    /// Excess `NewBlock` and `NewBlockHashes` announcements are dropped.
+    pub const fn max_block_announcements_per_sec(mut self, max: u32) -> Self {
+        self.max_block_announcements_per_sec = max;
+        self
+    }
+
+    /// Sets whether the bloom of each receipt received from a peer is recomputed and compared to
+    /// the bloom supplied by the peer.
+    ///
+    /// Responses with a mismatching bloom are dropped and requested from another peer. This only
+    /// applies to eth/66-68 responses, later versions don't include blooms.
+    pub const fn verify_receipt_blooms(mut self, verify: bool) -> Self {
+        self.verify_receipt_blooms = verify;
+        self
+    }
+
     /// Sets the block import type.
     pub fn block_import(mut self, block_import: Box<dyn BlockImport<N::NewBlockPayload>>) -> Self {
         self.block_import = Some(block_import);
@@ -643,6 +677,8 @@ impl<N: NetworkPrimitives> NetworkConfigBuilder<N> {
             handshake,
             eth_max_message_size,
             required_block_hashes,
+            max_block_announcements_per_sec,
+            verify_receipt_blooms,
             network_id,
         } = self;
 
@@ -716,6 +752,8 @@ impl<N: NetworkPrimitives> NetworkConfigBuilder<N> {
             handshake,
             eth_max_message_size,
             required_block_hashes,
+            max_block_announcements_per_sec,
+            verify_receipt_blooms,
         }
     }
 }
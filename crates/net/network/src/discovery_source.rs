@@ -0,0 +1,67 @@
+//! Custom sources of peers, e.g. a DNS tree or an HTTP endpoint, that feed into the network.
+
+use futures::future::BoxFuture;
+use reth_network_api::{PeerKind, Peers};
+use reth_network_peers::NodeRecord;
+use std::{fmt, io, time::Duration};
+use tracing::{debug, warn};
+
+/// The default interval at which a [`DiscoverySource`] is queried for peers.
+pub const DEFAULT_DISCOVERY_SOURCE_INTERVAL: Duration = Duration::from_secs(30 * 60);
+
+/// A custom source of peers.
+///
+/// This can be used by private networks to bootstrap from their own infrastructure, for example an
+/// HTTP endpoint that serves enodes, instead of the public discovery mechanisms.
+pub trait DiscoverySource: fmt::Debug + Send + Sync {
+    /// Returns the peers currently advertised by this source.
+    fn discover(&self) -> BoxFuture<'_, io::Result<Vec<NodeRecord>>>;
+
+    /// The interval at which this source is queried again after the initial query at startup.
+    ///
+    /// Defaults to [`DEFAULT_DISCOVERY_SOURCE_INTERVAL`].
+    fn interval(&self) -> Duration {
+        DEFAULT_DISCOVERY_SOURCE_INTERVAL
+    }
+}
+
+impl<T: DiscoverySource + ?Sized> DiscoverySource for Box<T> {
+    fn discover(&self) -> BoxFuture<'_, io::Result<Vec<NodeRecord>>> {
+        (**self).discover()
+    }
+
+    fn interval(&self) -> Duration {
+        (**self).interval()
+    }
+}
+
+/// Queries the given source immediately and then on every [`DiscoverySource::interval`], adding all
+/// discovered peers to the network.
+///
+/// This future never resolves.
+pub async fn feed_discovery_source<S, N>(source: S, network: N)
+where
+    S: DiscoverySource,
+    N: Peers,
+{
+    let mut interval = tokio::time::interval(source.interval());
+    loop {
+        interval.tick().await;
+        match source.discover().await {
+            Ok(records) => {
+                debug!(target: "net::discovery_source", ?source, peers = records.len(), "Discovered peers");
+                for record in records {
+                    network.add_peer_kind(
+                        record.id,
+                        Some(PeerKind::Basic),
+                        record.tcp_addr(),
+                        Some(record.udp_addr()),
+                    );
+                }
+            }
+            Err(err) => {
+                warn!(target: "net::discovery_source", ?source, %err, "Failed to discover peers");
+            }
+        }
+    }
+}
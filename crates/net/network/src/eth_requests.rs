@@ -8,7 +8,7 @@ use alloy_consensus::{BlockHeader, ReceiptWithBloom};
 use alloy_eips::BlockHashOrNumber;
 use alloy_primitives::Bytes;
 use alloy_rlp::Encodable;
-use futures::StreamExt;
+use futures::{stream::FuturesUnordered, StreamExt};
 use reth_eth_wire::{
     BlockAccessLists, BlockBodies, BlockHeaders, EthNetworkPrimitives, GetBlockAccessLists,
     GetBlockBodies, GetBlockHeaders, GetNodeData, GetReceipts, GetReceipts70, HeadersDirection,
@@ -19,13 +19,18 @@ use reth_network_p2p::error::RequestResult;
 use reth_network_peers::PeerId;
 use reth_primitives_traits::Block;
 use reth_storage_api::{BlockReader, HeaderProvider};
+use reth_tasks::Runtime;
 use std::{
+    collections::VecDeque,
     future::Future,
     pin::Pin,
     task::{Context, Poll},
     time::Duration,
 };
-use tokio::sync::{mpsc::Receiver, oneshot};
+use tokio::{
+    sync::{mpsc::Receiver, oneshot},
+    task::JoinHandle,
+};
 use tokio_stream::wrappers::ReceiverStream;
 
 // Limits: <https://github.com/ethereum/go-ethereum/blob/b0d44338bbcefee044f1f635a84487cbbd8f0538/eth/protocols/eth/handler.go#L34-L56>
@@ -49,6 +54,12 @@ pub const MAX_BODIES_SERVE: usize = 1024;
 /// Maximum size of replies to data retrievals: 2MB
 pub const SOFT_RESPONSE_LIMIT: usize = 2 * 1024 * 1024;
 
+/// Default maximum number of receipt requests that are served concurrently.
+pub const DEFAULT_MAX_CONCURRENT_RECEIPT_REQUESTS: usize = 64;
+
+/// Default maximum number of receipt requests that are queued while all serving slots are busy.
+pub const DEFAULT_MAX_QUEUED_RECEIPT_REQUESTS: usize = 256;
+
 /// Manages eth related requests on top of the p2p network.
 ///
 /// This can be spawned to another task and is supposed to be run as background service.
@@ -63,6 +74,16 @@ pub struct EthRequestHandler<C, N: NetworkPrimitives = EthNetworkPrimitives> {
     peers: PeersHandle,
     /// Incoming request from the [`NetworkManager`](crate::NetworkManager).
     incoming_requests: ReceiverStream<IncomingEthRequest<N>>,
+    /// Receipt requests that are waiting for a free slot to be served.
+    pending_receipt_requests: VecDeque<IncomingEthRequest<N>>,
+    /// Receipt requests that are currently being served on blocking tasks.
+    active_receipt_requests: FuturesUnordered<JoinHandle<()>>,
+    /// Maximum number of receipt requests that are served concurrently.
+    max_concurrent_receipt_requests: usize,
+    /// Maximum number of receipt requests that are queued while all serving slots are busy.
+    max_queued_receipt_requests: usize,
+    /// The executor receipt requests are served on.
+    executor: Runtime,
     /// Metrics for the eth request handler.
     metrics: EthRequestHandlerMetrics,
 }
@@ -70,14 +91,61 @@ pub struct EthRequestHandler<C, N: NetworkPrimitives = EthNetworkPrimitives> {
 // === impl EthRequestHandler ===
 impl<C, N: NetworkPrimitives> EthRequestHandler<C, N> {
     /// Create a new instance
-    pub fn new(client: C, peers: PeersHandle, incoming: Receiver<IncomingEthRequest<N>>) -> Self {
+    pub fn new(
+        client: C,
+        peers: PeersHandle,
+        incoming: Receiver<IncomingEthRequest<N>>,
+        executor: Runtime,
+    ) -> Self {
         Self {
             client,
             peers,
             incoming_requests: ReceiverStream::new(incoming),
+            pending_receipt_requests: VecDeque::new(),
+            active_receipt_requests: FuturesUnordered::new(),
+            max_concurrent_receipt_requests: DEFAULT_MAX_CONCURRENT_RECEIPT_REQUESTS,
+            max_queued_receipt_requests: DEFAULT_MAX_QUEUED_RECEIPT_REQUESTS,
+            executor,
             metrics: Default::default(),
         }
     }
+
+    /// Sets the maximum number of receipt requests that are served concurrently.
+    ///
+    /// Receipt requests are expensive to serve, so each one is served on a blocking task and at
+    /// most `max` of them run at the same time.
+    pub const fn with_max_concurrent_receipt_requests(mut self, max: usize) -> Self {
+        self.max_concurrent_receipt_requests = max;
+        self
+    }
+
+    /// Sets the maximum number of receipt requests that are queued while all serving slots are
+    /// busy.
+    ///
+    /// Any receipt request that arrives while the queue is full is answered with an empty
+    /// response.
+    pub const fn with_max_queued_receipt_requests(mut self, max: usize) -> Self {
+        self.max_queued_receipt_requests = max;
+        self
+    }
+
+    /// Answers a receipts request with an empty response, which tells the peer that none of the
+    /// requested receipts are served right now.
+    fn reject_receipts_request(request: IncomingEthRequest<N>) {
+        match request {
+            IncomingEthRequest::GetReceipts { response, .. } => {
+                let _ = response.send(Ok(Receipts(Vec::new())));
+            }
+            IncomingEthRequest::GetReceipts69 { response, .. } => {
+                let _ = response.send(Ok(Receipts69(Vec::new())));
+            }
+            IncomingEthRequest::GetReceipts70 { response, .. } => {
+                let _ = response
+                    .send(Ok(Receipts70 { last_block_incomplete: false, receipts: Vec::new() }));
+            }
+            _ => {}
+        }
+    }
 }
 
 impl<C, N> EthRequestHandler<C, N>
@@ -189,14 +257,12 @@ where
     }
 
     fn on_receipts_request(
-        &self,
+        client: &C,
         _peer_id: PeerId,
         request: GetReceipts,
         response: oneshot::Sender<RequestResult<Receipts<C::Receipt>>>,
     ) {
-        self.metrics.eth_receipts_requests_received_total.increment(1);
-
-        let receipts = self.get_receipts_response(request, |receipts_by_block| {
+        let receipts = Self::get_receipts_response(client, request, |receipts_by_block| {
             receipts_by_block.into_iter().map(ReceiptWithBloom::from).collect::<Vec<_>>()
         });
 
@@ -204,14 +270,12 @@ where
     }
 
     fn on_receipts69_request(
-        &self,
+        client: &C,
         _peer_id: PeerId,
         request: GetReceipts,
         response: oneshot::Sender<RequestResult<Receipts69<C::Receipt>>>,
     ) {
-        self.metrics.eth_receipts_requests_received_total.increment(1);
-
-        let receipts = self.get_receipts_response(request, |receipts_by_block| {
+        let receipts = Self::get_receipts_response(client, request, |receipts_by_block| {
             // skip bloom filter for eth69
             receipts_by_block
         });
@@ -223,13 +287,11 @@ where
     ///
     /// This will adhere to the soft limit but allow filling the last vec partially.
     fn on_receipts70_request(
-        &self,
+        client: &C,
         _peer_id: PeerId,
         request: GetReceipts70,
         response: oneshot::Sender<RequestResult<Receipts70<C::Receipt>>>,
     ) {
-        self.metrics.eth_receipts_requests_received_total.increment(1);
-
         let GetReceipts70 { first_block_receipt_index, block_hashes } = request;
 
         let mut receipts = Vec::new();
@@ -242,7 +304,7 @@ where
             }
 
             let Some(mut block_receipts) =
-                self.client.receipts_by_block(BlockHashOrNumber::Hash(hash)).unwrap_or_default()
+                client.receipts_by_block(BlockHashOrNumber::Hash(hash)).unwrap_or_default()
             else {
                 break
             };
@@ -304,7 +366,7 @@ where
     }
 
     #[inline]
-    fn get_receipts_response<T, F>(&self, request: GetReceipts, transform_fn: F) -> Vec<Vec<T>>
+    fn get_receipts_response<T, F>(client: &C, request: GetReceipts, transform_fn: F) -> Vec<Vec<T>>
     where
         F: Fn(Vec<C::Receipt>) -> Vec<T>,
         T: Encodable,
@@ -314,7 +376,7 @@ where
 
         for hash in request {
             if let Some(receipts_by_block) =
-                self.client.receipts_by_block(BlockHashOrNumber::Hash(hash)).unwrap_or_default()
+                client.receipts_by_block(BlockHashOrNumber::Hash(hash)).unwrap_or_default()
             {
                 let transformed_receipts = transform_fn(receipts_by_block);
                 total_bytes += transformed_receipts.length();
@@ -332,6 +394,50 @@ where
     }
 }
 
+impl<C, N> EthRequestHandler<C, N>
+where
+    N: NetworkPrimitives,
+    C: BlockReader<Receipt = N::Receipt> + Clone + 'static,
+{
+    /// Queues an incoming receipts request, or rejects it if too many receipt requests are
+    /// already waiting to be served.
+    fn queue_receipts_request(&mut self, request: IncomingEthRequest<N>) {
+        self.metrics.eth_receipts_requests_received_total.increment(1);
+
+        if self.pending_receipt_requests.len() >= self.max_queued_receipt_requests {
+            self.metrics.eth_receipts_requests_rejected_total.increment(1);
+            Self::reject_receipts_request(request);
+            return
+        }
+
+        self.pending_receipt_requests.push_back(request);
+        self.spawn_receipt_requests();
+    }
+
+    /// Serves queued receipt requests on blocking tasks until the concurrency limit is reached.
+    fn spawn_receipt_requests(&mut self) {
+        while self.active_receipt_requests.len() < self.max_concurrent_receipt_requests {
+            let Some(request) = self.pending_receipt_requests.pop_front() else { break };
+            let client = self.client.clone();
+            let task = self.executor.spawn_blocking_task(async move {
+                match request {
+                    IncomingEthRequest::GetReceipts { peer_id, request, response } => {
+                        Self::on_receipts_request(&client, peer_id, request, response)
+                    }
+                    IncomingEthRequest::GetReceipts69 { peer_id, request, response } => {
+                        Self::on_receipts69_request(&client, peer_id, request, response)
+                    }
+                    IncomingEthRequest::GetReceipts70 { peer_id, request, response } => {
+                        Self::on_receipts70_request(&client, peer_id, request, response)
+                    }
+                    _ => {}
+                }
+            });
+            self.active_receipt_requests.push(task);
+        }
+    }
+}
+
 /// An endless future.
 ///
 /// This should be spawned or used as part of `tokio::select!`.
@@ -340,7 +446,9 @@ where
     N: NetworkPrimitives,
     C: BlockReader<Block = N::Block, Receipt = N::Receipt>
         + HeaderProvider<Header = N::BlockHeader>
-        + Unpin,
+        + Clone
+        + Unpin
+        + 'static,
 {
     type Output = ();
 
@@ -365,14 +473,10 @@ where
                     IncomingEthRequest::GetNodeData { .. } => {
                         this.metrics.eth_node_data_requests_received_total.increment(1);
                     }
-                    IncomingEthRequest::GetReceipts { peer_id, request, response } => {
-                        this.on_receipts_request(peer_id, request, response)
-                    }
-                    IncomingEthRequest::GetReceipts69 { peer_id, request, response } => {
-                        this.on_receipts69_request(peer_id, request, response)
-                    }
-                    IncomingEthRequest::GetReceipts70 { peer_id, request, response } => {
-                        this.on_receipts70_request(peer_id, request, response)
+                    request @ (IncomingEthRequest::GetReceipts { .. } |
+                    IncomingEthRequest::GetReceipts69 { .. } |
+                    IncomingEthRequest::GetReceipts70 { .. }) => {
+                        this.queue_receipts_request(request)
                     }
                     IncomingEthRequest::GetBlockAccessLists { peer_id, request, response } => {
                         this.on_block_access_lists_request(peer_id, request, response)
@@ -383,6 +487,18 @@ where
 
         this.metrics.acc_duration_poll_eth_req_handler.set(acc.as_secs_f64());
 
+        // serve receipt requests on blocking tasks so they can't starve cheaper requests, and
+        // free the slots of finished ones
+        loop {
+            this.spawn_receipt_requests();
+            match this.active_receipt_requests.poll_next_unpin(cx) {
+                Poll::Ready(Some(_)) => {}
+                Poll::Ready(None) | Poll::Pending => break,
+            }
+        }
+        this.metrics.eth_receipts_requests_pending.set(this.pending_receipt_requests.len() as f64);
+        this.metrics.eth_receipts_requests_active.set(this.active_receipt_requests.len() as f64);
+
         // stream is fully drained and import futures pending
         if maybe_more_incoming_requests {
             // make sure we're woken up again
@@ -474,3 +590,47 @@ pub enum IncomingEthRequest<N: NetworkPrimitives = EthNetworkPrimitives> {
         response: oneshot::Sender<RequestResult<BlockAccessLists>>,
     },
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::poll;
+    use reth_storage_api::noop::NoopProvider;
+    use tokio::sync::mpsc;
+
+    #[tokio::test]
+    async fn limits_concurrent_receipt_requests() {
+        let (peers_tx, _peers_rx) = mpsc::unbounded_channel();
+        let (tx, rx) = mpsc::channel(8);
+        let mut handler = EthRequestHandler::<_, EthNetworkPrimitives>::new(
+            NoopProvider::default(),
+            PeersHandle::new(peers_tx),
+            rx,
+            Runtime::test(),
+        )
+        .with_max_concurrent_receipt_requests(1)
+        .with_max_queued_receipt_requests(1);
+
+        let mut responses = Vec::new();
+        for _ in 0..3 {
+            let (response, rx) = oneshot::channel();
+            tx.send(IncomingEthRequest::GetReceipts {
+                peer_id: PeerId::ZERO,
+                request: GetReceipts::default(),
+                response,
+            })
+            .await
+            .unwrap();
+            responses.push(rx);
+        }
+
+        // one request is served, one is queued and the last one is rejected
+        let _ = poll!(&mut handler);
+        let [first, second, third] = responses.try_into().unwrap();
+        assert!(third.await.unwrap().unwrap().0.is_empty());
+
+        tokio::spawn(handler);
+        assert!(first.await.unwrap().is_ok());
+        assert!(second.await.unwrap().is_ok());
+    }
+}
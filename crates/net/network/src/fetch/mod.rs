@@ -34,7 +34,8 @@ use tokio_stream::wrappers::UnboundedReceiverStream;
 
 type InflightHeadersRequest<H> = Request<HeadersRequest, PeerRequestResult<Vec<H>>>;
 type InflightBodiesRequest<B> = Request<(), PeerRequestResult<Vec<B>>>;
-type InflightReceiptsRequest<R> = Request<(), PeerRequestResult<ReceiptsResponse<R>>>;
+type InflightReceiptsRequest<R> =
+    Request<(Vec<B256>, Priority), PeerRequestResult<ReceiptsResponse<R>>>;
 type InflightBlockAccessListsRequest = Request<(), PeerRequestResult<BlockAccessLists>>;
 
 /// Manages data fetching operations.
@@ -291,8 +292,8 @@ impl<N: NetworkPrimitives> StateFetcher<N> {
                 self.inflight_bals_requests.insert(peer_id, inflight);
                 BlockRequest::GetBlockAccessLists(GetBlockAccessLists(request))
             }
-            DownloadRequest::GetReceipts { request, response, .. } => {
-                let inflight = Request { request: (), response };
+            DownloadRequest::GetReceipts { request, response, priority } => {
+                let inflight = Request { request: (request.clone(), priority), response };
                 self.inflight_receipts_requests.insert(peer_id, inflight);
                 BlockRequest::GetReceipts(GetReceipts(request))
             }
@@ -415,6 +416,28 @@ impl<N: NetworkPrimitives> StateFetcher<N> {
         None
     }
 
+    /// Called when a `GetReceipts` response from a peer contains a receipt whose bloom doesn't
+    /// match the bloom supplied by the peer.
+    ///
+    /// The response is dropped and the request is queued again, so that it is sent to another
+    /// peer if one is available.
+    pub(crate) fn on_receipts_bloom_mismatch(&mut self, peer_id: PeerId) -> BlockResponseOutcome {
+        if let Some(Request { request: (request, priority), response }) =
+            self.inflight_receipts_requests.remove(&peer_id)
+        {
+            self.queued_requests.push_front(DownloadRequest::GetReceipts {
+                request,
+                response,
+                priority,
+            });
+        }
+        if let Some(peer) = self.peers.get_mut(&peer_id) {
+            peer.last_response_likely_bad = true;
+            peer.state.on_request_finished();
+        }
+        BlockResponseOutcome::BadResponse(peer_id, ReputationChangeKind::BadMessage)
+    }
+
     /// Returns a new [`FetchClient`] that can send requests to this type.
     pub(crate) fn client(&self) -> FetchClient<N> {
         FetchClient {
@@ -1285,7 +1308,10 @@ mod tests {
     ) -> oneshot::Receiver<PeerRequestResult<ReceiptsResponse<reth_ethereum_primitives::Receipt>>>
     {
         let (tx, rx) = oneshot::channel();
-        fetcher.inflight_receipts_requests.insert(peer_id, Request { request: (), response: tx });
+        fetcher.inflight_receipts_requests.insert(
+            peer_id,
+            Request { request: (vec![B256::ZERO], Priority::default()), response: tx },
+        );
         fetcher.peers.get_mut(&peer_id).unwrap().state = PeerState::GetReceipts;
         rx
     }
@@ -1345,6 +1371,29 @@ mod tests {
         assert_eq!(result.1.receipts.len(), 1);
     }
 
+    #[tokio::test]
+    async fn test_receipts_bloom_mismatch_requeues_request() {
+        let (mut fetcher, peer_id) = fetcher_with_peer();
+        let _rx = insert_inflight_receipts(&mut fetcher, peer_id);
+
+        let outcome = fetcher.on_receipts_bloom_mismatch(peer_id);
+
+        assert!(matches!(
+            outcome,
+            BlockResponseOutcome::BadResponse(id, ReputationChangeKind::BadMessage) if id == peer_id
+        ));
+        assert!(fetcher.peers[&peer_id].state.is_idle());
+        assert!(fetcher.peers[&peer_id].last_response_likely_bad);
+        assert!(!fetcher.inflight_receipts_requests.contains_key(&peer_id));
+
+        // The request is queued again with the original hashes
+        let Some(DownloadRequest::GetReceipts { request, .. }) = fetcher.queued_requests.front()
+        else {
+            panic!("expected queued GetReceipts request");
+        };
+        assert_eq!(request, &vec![B256::ZERO]);
+    }
+
     #[tokio::test]
     async fn test_receipts_empty_response_marks_peer_bad() {
         let (mut fetcher, peer_id) = fetcher_with_peer();
@@ -125,6 +125,7 @@ pub mod test_utils;
 
 pub mod cache;
 pub mod config;
+pub mod discovery_source;
 pub mod error;
 pub mod eth_requests;
 pub mod import;
@@ -133,6 +134,7 @@ pub mod peers;
 pub mod protocol;
 pub mod transactions;
 
+mod block_announcements;
 mod budget;
 mod builder;
 mod discovery;
@@ -165,6 +167,7 @@ pub use session::{
 pub use builder::NetworkBuilder;
 pub use config::{NetworkConfig, NetworkConfigBuilder};
 pub use discovery::Discovery;
+pub use discovery_source::DiscoverySource;
 pub use fetch::FetchClient;
 pub use flattened_response::FlattenedResponse;
 pub use manager::NetworkManager;
@@ -16,6 +16,7 @@
 //! to the local node. Once a (tcp) connection is established, both peers start to authenticate a [RLPx session](https://github.com/ethereum/devp2p/blob/master/rlpx.md) via a handshake. If the handshake was successful, both peers announce their capabilities and are now ready to exchange sub-protocol messages via the `RLPx` session.
 
 use crate::{
+    block_announcements::BlockAnnouncementLimiter,
     budget::{DEFAULT_BUDGET_TRY_DRAIN_NETWORK_HANDLE_CHANNEL, DEFAULT_BUDGET_TRY_DRAIN_SWARM},
     config::NetworkConfig,
     discovery::Discovery,
@@ -53,7 +54,7 @@ use reth_network_api::{
 use reth_network_peers::{NodeRecord, PeerId};
 use reth_network_types::ReputationChangeKind;
 use reth_storage_api::BlockNumReader;
-use reth_tasks::shutdown::GracefulShutdown;
+use reth_tasks::{shutdown::GracefulShutdown, Runtime};
 use reth_tokio_util::EventSender;
 use secp256k1::SecretKey;
 use std::{
@@ -138,6 +139,10 @@ pub struct NetworkManager<N: NetworkPrimitives = EthNetworkPrimitives> {
     /// This is updated via internal events and shared via `Arc` with the [`NetworkHandle`]
     /// Updated by the `NetworkWorker` and loaded by the `NetworkService`.
     num_active_peers: Arc<AtomicUsize>,
+    /// Tracks the number of established inbound sessions, shared with the [`NetworkHandle`].
+    num_inbound_peers: Arc<AtomicUsize>,
+    /// Tracks the number of established outbound sessions, shared with the [`NetworkHandle`].
+    num_outbound_peers: Arc<AtomicUsize>,
     /// Metrics for the Network
     metrics: NetworkMetrics,
     /// Disconnect metrics for the Network, split by connection direction.
@@ -148,6 +153,8 @@ pub struct NetworkManager<N: NetworkPrimitives = EthNetworkPrimitives> {
     pending_session_failure_metrics: PendingSessionFailureMetrics,
     /// Backed off peers metrics, split by reason.
     backed_off_peers_metrics: BackedOffPeersMetrics,
+    /// Limits inbound block announcements per peer.
+    block_announcements: BlockAnnouncementLimiter,
 }
 
 impl NetworkManager {
@@ -218,6 +225,11 @@ impl<N: NetworkPrimitives> NetworkManager<N> {
         self.swarm.sessions().secret_key()
     }
 
+    /// Returns the executor the network spawns its tasks on.
+    pub(crate) const fn executor(&self) -> &Runtime {
+        self.swarm.sessions().executor()
+    }
+
     #[inline]
     fn update_poll_metrics(&self, start: Instant, poll_durations: NetworkManagerPollDurations) {
         let metrics = &self.metrics;
@@ -263,6 +275,8 @@ impl<N: NetworkPrimitives> NetworkManager<N> {
             handshake,
             eth_max_message_size,
             required_block_hashes,
+            max_block_announcements_per_sec,
+            verify_receipt_blooms,
         } = config;
 
         let peers_manager = PeersManager::new(peers_config);
@@ -307,6 +321,8 @@ impl<N: NetworkPrimitives> NetworkManager<N> {
         let discv5 = discovery.discv5();
 
         let num_active_peers = Arc::new(AtomicUsize::new(0));
+        let num_inbound_peers = Arc::new(AtomicUsize::new(0));
+        let num_outbound_peers = Arc::new(AtomicUsize::new(0));
 
         let sessions = SessionManager::new(
             secret_key,
@@ -325,6 +341,7 @@ impl<N: NetworkPrimitives> NetworkManager<N> {
             discovery,
             peers_manager,
             Arc::clone(&num_active_peers),
+            verify_receipt_blooms,
         );
 
         let swarm = Swarm::new(incoming, sessions, state);
@@ -335,6 +352,8 @@ impl<N: NetworkPrimitives> NetworkManager<N> {
 
         let handle = NetworkHandle::new(
             Arc::clone(&num_active_peers),
+            Arc::clone(&num_inbound_peers),
+            Arc::clone(&num_outbound_peers),
             Arc::new(Mutex::new(listener_addr)),
             to_manager_tx,
             secret_key,
@@ -364,11 +383,14 @@ impl<N: NetworkPrimitives> NetworkManager<N> {
             to_transactions_manager: None,
             to_eth_request_handler: None,
             num_active_peers,
+            num_inbound_peers,
+            num_outbound_peers,
             metrics: Default::default(),
             disconnect_metrics: Default::default(),
             closed_sessions_metrics: Default::default(),
             pending_session_failure_metrics: Default::default(),
             backed_off_peers_metrics: Default::default(),
+            block_announcements: BlockAnnouncementLimiter::new(max_block_announcements_per_sec),
         })
     }
 
@@ -441,6 +463,16 @@ impl<N: NetworkPrimitives> NetworkManager<N> {
         self.swarm.peers().num_known_peers()
     }
 
+    /// Returns the number of currently connected inbound peers.
+    pub fn num_inbound_peers(&self) -> usize {
+        self.swarm.peers().num_inbound_connections()
+    }
+
+    /// Returns the number of currently connected outbound peers.
+    pub fn num_outbound_peers(&self) -> usize {
+        self.swarm.peers().num_outbound_connections()
+    }
+
     /// Returns a new [`PeersHandle`] that can be cloned and shared.
     ///
     /// The [`PeersHandle`] can be used to interact with the network's peer set.
@@ -638,6 +670,11 @@ impl<N: NetworkPrimitives> NetworkManager<N> {
         match msg {
             PeerMessage::NewBlockHashes(hashes) => {
                 self.within_pow_or_disconnect(peer_id, |this| {
+                    if !this.block_announcements.try_acquire(peer_id) {
+                        trace!(target: "net", ?peer_id, "Dropping block hashes announcement over rate limit");
+                        this.metrics.dropped_block_announcements.increment(1);
+                        return
+                    }
                     // update peer's state, to track what blocks this peer has seen
                     this.swarm.state_mut().on_new_block_hashes(peer_id, hashes.to_vec());
                     // start block import process for the hashes
@@ -646,6 +683,11 @@ impl<N: NetworkPrimitives> NetworkManager<N> {
             }
             PeerMessage::NewBlock(block) => {
                 self.within_pow_or_disconnect(peer_id, move |this| {
+                    if !this.block_announcements.try_acquire(peer_id) {
+                        trace!(target: "net", ?peer_id, "Dropping block announcement over rate limit");
+                        this.metrics.dropped_block_announcements.increment(1);
+                        return
+                    }
                     this.swarm.state_mut().on_new_block(peer_id, block.hash);
                     // start block import process
                     this.block_import.on_new_block(peer_id, NewBlockEvent::Block(block));
@@ -867,6 +909,7 @@ impl<N: NetworkPrimitives> NetworkManager<N> {
                 self.metrics.tracked_peers.set(self.swarm.peers().num_known_peers() as f64);
             }
             SwarmEvent::SessionClosed { peer_id, remote_addr, error } => {
+                self.block_announcements.remove_peer(&peer_id);
                 let total_active = self.num_active_peers.fetch_sub(1, Ordering::Relaxed) - 1;
                 self.metrics.connected_peers.set(total_active as f64);
                 trace!(
@@ -1044,8 +1087,12 @@ impl<N: NetworkPrimitives> NetworkManager<N> {
     /// Updates the metrics for active,established connections
     #[inline]
     fn update_active_connection_metrics(&self) {
-        self.metrics.incoming_connections.set(self.swarm.peers().num_inbound_connections() as f64);
-        self.metrics.outgoing_connections.set(self.swarm.peers().num_outbound_connections() as f64);
+        let num_inbound = self.swarm.peers().num_inbound_connections();
+        let num_outbound = self.swarm.peers().num_outbound_connections();
+        self.num_inbound_peers.store(num_inbound, Ordering::Relaxed);
+        self.num_outbound_peers.store(num_outbound, Ordering::Relaxed);
+        self.metrics.incoming_connections.set(num_inbound as f64);
+        self.metrics.outgoing_connections.set(num_outbound as f64);
     }
 
     /// Updates the metrics for pending connections
@@ -46,6 +46,9 @@ pub struct NetworkMetrics {
     /// Number of Eth Requests dropped due to channel being at full capacity
     pub(crate) total_dropped_eth_requests_at_full_capacity: Counter,
 
+    /// Number of inbound block announcements dropped due to the per peer rate limit
+    pub(crate) dropped_block_announcements: Counter,
+
     /* ================ POLL DURATION ================ */
 
     /* -- Total poll duration of `NetworksManager` future -- */
@@ -145,6 +148,8 @@ pub struct SessionManagerMetrics {
     pub(crate) queued_outgoing_messages: Gauge,
     /// Total number of broadcast messages sent via the unbounded overflow channel.
     pub(crate) total_unbounded_broadcast_msgs: Counter,
+    /// Total number of incoming messages rejected for exceeding the maximum message size.
+    pub(crate) total_oversized_messages_rejected: Counter,
 }
 
 /// Metrics for the [`TransactionsManager`](crate::transactions::TransactionsManager).
@@ -548,6 +553,15 @@ pub struct EthRequestHandlerMetrics {
     /// Number of `GetReceipts` requests received
     pub(crate) eth_receipts_requests_received_total: Counter,
 
+    /// Number of `GetReceipts` requests rejected because too many were already pending
+    pub(crate) eth_receipts_requests_rejected_total: Counter,
+
+    /// Number of `GetReceipts` requests waiting to be served
+    pub(crate) eth_receipts_requests_pending: Gauge,
+
+    /// Number of `GetReceipts` requests currently being served
+    pub(crate) eth_receipts_requests_active: Gauge,
+
     /// Number of `GetBlockBodies` requests received
     pub(crate) eth_bodies_requests_received_total: Counter,
 
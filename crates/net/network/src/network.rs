@@ -54,6 +54,8 @@ impl<N: NetworkPrimitives> NetworkHandle<N> {
     #[expect(clippy::too_many_arguments)]
     pub(crate) fn new(
         num_active_peers: Arc<AtomicUsize>,
+        num_inbound_peers: Arc<AtomicUsize>,
+        num_outbound_peers: Arc<AtomicUsize>,
         listener_address: Arc<Mutex<SocketAddr>>,
         to_manager_tx: UnboundedSender<NetworkHandleMessage<N>>,
         secret_key: SecretKey,
@@ -69,6 +71,8 @@ impl<N: NetworkPrimitives> NetworkHandle<N> {
     ) -> Self {
         let inner = NetworkInner {
             num_active_peers,
+            num_inbound_peers,
+            num_outbound_peers,
             to_manager_tx,
             listener_address,
             secret_key,
@@ -87,6 +91,20 @@ impl<N: NetworkPrimitives> NetworkHandle<N> {
         Self { inner: Arc::new(inner) }
     }
 
+    /// Returns the number of currently connected inbound peers.
+    ///
+    /// See also [`PeersInfo::num_connected_peers`].
+    pub fn num_inbound_peers(&self) -> usize {
+        self.inner.num_inbound_peers.load(Ordering::Relaxed)
+    }
+
+    /// Returns the number of currently connected outbound peers.
+    ///
+    /// See also [`PeersInfo::num_connected_peers`].
+    pub fn num_outbound_peers(&self) -> usize {
+        self.inner.num_outbound_peers.load(Ordering::Relaxed)
+    }
+
     /// Returns the [`PeerId`] used in the network.
     pub fn peer_id(&self) -> &PeerId {
         &self.inner.local_peer_id
@@ -484,6 +502,10 @@ impl<N: NetworkPrimitives> BlockDownloaderProvider for NetworkHandle<N> {
 struct NetworkInner<N: NetworkPrimitives = EthNetworkPrimitives> {
     /// Number of active peer sessions the node's currently handling.
     num_active_peers: Arc<AtomicUsize>,
+    /// Number of established inbound peer sessions.
+    num_inbound_peers: Arc<AtomicUsize>,
+    /// Number of established outbound peer sessions.
+    num_outbound_peers: Arc<AtomicUsize>,
     /// Sender half of the message channel to the [`crate::NetworkManager`].
     to_manager_tx: UnboundedSender<NetworkHandleMessage<N>>,
     /// The local address that accepts incoming connections.
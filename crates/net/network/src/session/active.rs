@@ -152,6 +152,8 @@ pub(crate) struct ActiveSession<N: NetworkPrimitives> {
     pub(crate) unbounded_rx: mpsc::UnboundedReceiver<SessionCommand<N>>,
     /// Counter for broadcast messages received via the unbounded overflow channel.
     pub(crate) unbounded_broadcast_msgs: Counter,
+    /// Counter for incoming messages rejected because they exceed the maximum message size.
+    pub(crate) oversized_messages_rejected: Counter,
     /// Sink to send messages to the [`SessionManager`](super::SessionManager).
     pub(crate) to_session_manager: MeteredPollSender<ActiveSessionMessage<N>>,
     /// A message that needs to be delivered to the session manager
@@ -822,6 +824,9 @@ impl<N: NetworkPrimitives> Future for ActiveSession<N> {
                             }
                             Err(err) => {
                                 debug!(target: "net::session", %err, remote_peer_id=?this.remote_peer_id, "failed to receive message");
+                                if err.is_message_too_big() {
+                                    this.oversized_messages_rejected.increment(1);
+                                }
                                 if err.is_protocol_breach() {
                                     this.on_bad_message();
                                     return this.try_disconnect(DisconnectReason::ProtocolBreach, cx)
@@ -1233,6 +1238,7 @@ mod tests {
                         commands_rx: ReceiverStream::new(commands_rx),
                         unbounded_rx,
                         unbounded_broadcast_msgs: Counter::noop(),
+                        oversized_messages_rejected: Counter::noop(),
                         to_session_manager: MeteredPollSender::new(
                             poll_sender,
                             "network_active_session",
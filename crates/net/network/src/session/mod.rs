@@ -207,6 +207,11 @@ impl<N: NetworkPrimitives> SessionManager<N> {
         self.secret_key
     }
 
+    /// Returns the executor used to spawn session tasks.
+    pub(crate) const fn executor(&self) -> &Runtime {
+        &self.executor
+    }
+
     /// Returns a borrowed reference to the active sessions.
     pub const fn active_sessions(&self) -> &HashMap<PeerId, ActiveSessionHandle<N>> {
         &self.active_sessions
@@ -572,6 +577,10 @@ impl<N: NetworkPrimitives> SessionManager<N> {
                     commands_rx: ReceiverStream::new(commands_rx),
                     unbounded_rx,
                     unbounded_broadcast_msgs: self.metrics.total_unbounded_broadcast_msgs.clone(),
+                    oversized_messages_rejected: self
+                        .metrics
+                        .total_oversized_messages_rejected
+                        .clone(),
                     to_session_manager: self.active_session_tx.clone(),
                     pending_message_to_session: None,
                     internal_request_rx: ReceiverStream::new(messages_rx).fuse(),
@@ -9,7 +9,7 @@ use crate::{
     session::BlockRangeInfo,
     FetchClient,
 };
-use alloy_consensus::BlockHeader;
+use alloy_consensus::{BlockHeader, TxReceipt};
 use alloy_primitives::B256;
 use rand::seq::SliceRandom;
 use reth_eth_wire::{
@@ -94,6 +94,9 @@ pub struct NetworkState<N: NetworkPrimitives = EthNetworkPrimitives> {
     /// will then queue in the request and notify the fetcher once the result has been
     /// received.
     state_fetcher: StateFetcher<N>,
+    /// Whether to recompute the blooms of legacy receipts responses and compare them to the
+    /// blooms supplied by the peer.
+    verify_receipt_blooms: bool,
 }
 
 impl<N: NetworkPrimitives> NetworkState<N> {
@@ -103,6 +106,7 @@ impl<N: NetworkPrimitives> NetworkState<N> {
         discovery: Discovery,
         peers_manager: PeersManager,
         num_active_peers: Arc<AtomicUsize>,
+        verify_receipt_blooms: bool,
     ) -> Self {
         let state_fetcher = StateFetcher::new(peers_manager.handle(), num_active_peers);
         Self {
@@ -112,6 +116,7 @@ impl<N: NetworkPrimitives> NetworkState<N> {
             client,
             discovery,
             state_fetcher,
+            verify_receipt_blooms,
         }
     }
 
@@ -467,6 +472,16 @@ impl<N: NetworkPrimitives> NetworkState<N> {
                 self.state_fetcher.on_block_bodies_response(peer, res)
             }
             PeerResponseResult::Receipts(res) => {
+                if self.verify_receipt_blooms &&
+                    res.as_ref().is_ok_and(|blocks| {
+                        blocks.iter().flatten().any(|rwb| rwb.receipt.bloom() != rwb.logs_bloom)
+                    })
+                {
+                    debug!(target: "net", ?peer, "Received receipts with mismatching bloom");
+                    let outcome = self.state_fetcher.on_receipts_bloom_mismatch(peer);
+                    return self.on_block_response_outcome(outcome)
+                }
+
                 // Legacy eth/66-68: strip bloom filters and wrap in ReceiptsResponse
                 let normalized = res.map(|blocks| {
                     let receipts = blocks
@@ -671,6 +686,7 @@ mod tests {
             client: BlockNumReader(Box::new(NoopProvider::default())),
             discovery: Discovery::noop(),
             state_fetcher: StateFetcher::new(handle, Default::default()),
+            verify_receipt_blooms: false,
         }
     }
 
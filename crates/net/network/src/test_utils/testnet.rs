@@ -319,6 +319,7 @@ where
             Receipt = reth_ethereum_primitives::Receipt,
             Header = alloy_consensus::Header,
         > + HeaderProvider
+        + Clone
         + Unpin
         + 'static,
     Pool: TransactionPool<
@@ -466,7 +467,8 @@ where
         let (tx, rx) = channel(ETH_REQUEST_CHANNEL_CAPACITY);
         self.network.set_eth_request_handler(tx);
         let peers = self.network.peers_handle();
-        let request_handler = EthRequestHandler::new(self.client.clone(), peers, rx);
+        let executor = self.network.executor().clone();
+        let request_handler = EthRequestHandler::new(self.client.clone(), peers, rx, executor);
         self.request_handler = Some(request_handler);
     }
 
@@ -573,6 +575,7 @@ where
             Receipt = reth_ethereum_primitives::Receipt,
             Header = alloy_consensus::Header,
         > + HeaderProvider
+        + Clone
         + Unpin
         + 'static,
     Pool: TransactionPool<
@@ -1245,6 +1245,10 @@ where
             trace!(target: "net::tx", ?peer_id, "Skipping transaction broadcast: node syncing or gossip disabled");
             return
         }
+        if !self.policies.propagation_policy().can_propagate(peer) {
+            trace!(target: "net::tx", ?peer_id, "Skipping transaction broadcast: propagation policy");
+            return
+        }
 
         // Get transactions to broadcast
         let pooled_txs = self.pool.pooled_transactions_max(
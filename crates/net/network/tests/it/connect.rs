@@ -76,6 +76,8 @@ async fn test_establish_connections() {
         }
         assert!(expected_connections.is_empty());
         assert!(expected_peers.is_empty());
+        assert_eq!(handle0.num_outbound_peers(), 2);
+        assert_eq!(handle0.num_inbound_peers(), 0);
 
         // also await the established session on both target
         futures::future::join(listener1.next(), listener2.next()).await;
@@ -1,8 +1,13 @@
 //! Node add-ons. Depend on core [`NodeComponents`](crate::NodeComponents).
 
+use reth_network::DiscoverySource;
 use reth_node_api::{FullNodeComponents, NodeAddOns};
+use reth_node_metrics::sink::BoxedMetricsSink;
+use reth_prune::SharedPruneFilter;
+use reth_stages::StageId;
+use std::{collections::HashMap, path::PathBuf};
 
-use crate::{exex::BoxedLaunchExEx, hooks::NodeHooks};
+use crate::{exex::BoxedLaunchExEx, hooks::NodeHooks, PoolConfigTransform};
 
 /// Additional node extensions.
 ///
@@ -12,6 +17,24 @@ pub struct AddOns<Node: FullNodeComponents, AddOns: NodeAddOns<Node>> {
     pub hooks: NodeHooks<Node, AddOns>,
     /// The `ExExs` (execution extensions) of the node.
     pub exexs: Vec<(String, Box<dyn BoxedLaunchExEx<Node>>)>,
+    /// The priorities of the `ExExs`, keyed by `ExEx` ID.
+    pub exex_priorities: HashMap<String, i32>,
+    /// External sinks that periodically receive the node's metrics.
+    pub metrics_sinks: Vec<BoxedMetricsSink>,
+    /// Custom sources of peers that are fed into the network.
+    pub discovery_sources: Vec<Box<dyn DiscoverySource>>,
+    /// Pipeline stages that are skipped during sync.
+    pub skipped_stages: Vec<StageId>,
+    /// Directory of a static file snapshot whose headers are imported before launch.
+    pub static_file_snapshot: Option<PathBuf>,
+    /// RLP file of blocks that are imported into the engine during launch.
+    pub block_import_file: Option<PathBuf>,
+    /// Engine API message store directory that is replayed against the engine after launch.
+    pub engine_replay: Option<PathBuf>,
+    /// Filter that exempts data from pruning.
+    pub prune_filter: Option<SharedPruneFilter>,
+    /// Transform applied to the pool config before the pool is built.
+    pub pool_config_transform: Option<PoolConfigTransform>,
     /// Additional captured addons.
     pub add_ons: AddOns,
 }
@@ -8,11 +8,15 @@ use crate::{
     components::NodeComponentsBuilder,
     node::FullNode,
     rpc::{RethRpcAddOns, RethRpcServerHandles, RpcContext},
-    BlockReaderFor, DebugNode, DebugNodeLauncher, EngineNodeLauncher, LaunchNode, Node,
+    BlockReaderFor, DebugNode, DebugNodeLauncher, EngineNodeLauncher, LaunchNode, LaunchPhase,
+    LaunchPhaseTracker, Node,
 };
 use alloy_eips::eip4844::env_settings::EnvKzgSettings;
+use alloy_primitives::B256;
+use eyre::WrapErr;
 use futures::Future;
-use reth_chainspec::{EthChainSpec, EthereumHardforks, Hardforks};
+use reth_chainspec::{EthChainSpec, EthereumHardforks, ForkCondition, GenesisHeaderMut, Hardforks};
+use reth_db::{mdbx::DatabaseArguments, DatabaseEnv};
 use reth_db_api::{database::Database, database_metrics::DatabaseMetrics};
 use reth_exex::ExExContext;
 use reth_network::{
@@ -20,8 +24,8 @@ use reth_network::{
         config::{AnnouncementFilteringPolicy, StrictEthAnnouncementFilter},
         TransactionPropagationPolicy, TransactionsManagerConfig,
     },
-    NetworkBuilder, NetworkConfig, NetworkConfigBuilder, NetworkHandle, NetworkManager,
-    NetworkPrimitives,
+    DiscoverySource, NetworkBuilder, NetworkConfig, NetworkConfigBuilder, NetworkHandle,
+    NetworkManager, NetworkPrimitives,
 };
 use reth_node_api::{
     FullNodeTypes, FullNodeTypesAdapter, NodeAddOns, NodeTypes, NodeTypesWithDBAdapter,
@@ -32,14 +36,25 @@ use reth_node_core::{
     node_config::NodeConfig,
     primitives::Head,
 };
+use reth_node_metrics::sink::MetricsSink;
+use reth_primitives_traits::header::HeaderMut;
 use reth_provider::{
     providers::{BlockchainProvider, NodeTypesForProvider, RocksDBProvider},
-    ChainSpecProvider, FullProvider,
+    ChainSpecProvider, FullProvider, StaticFileProviderFactory,
 };
+use reth_prune::PruneFilter;
+use reth_stages::StageId;
+#[cfg(feature = "test-utils")]
+use reth_tasks::DeterministicScheduler;
 use reth_tasks::TaskExecutor;
 use reth_transaction_pool::{PoolConfig, PoolTransaction, TransactionPool};
 use secp256k1::SecretKey;
-use std::sync::Arc;
+use std::{
+    any::Any,
+    path::{Path, PathBuf},
+    sync::Arc,
+    time::Duration,
+};
 use tracing::{info, trace, warn};
 
 pub mod add_ons;
@@ -234,12 +249,37 @@ impl<DB, ChainSpec: EthChainSpec> NodeBuilder<DB, ChainSpec> {
         NodeBuilder { config: self.config, database, rocksdb_provider: self.rocksdb_provider }
     }
 
+    /// Opens the database at `path`, or creates it if it doesn't exist yet, and configures it as
+    /// the database that the node will use.
+    ///
+    /// This is equivalent to passing the database returned by [`reth_db::init_db`] to
+    /// [`Self::with_database`]: the database version file is checked, or created for a new
+    /// database, missing tables are created and the client version of `args` is recorded.
+    pub fn with_database_from_path(
+        self,
+        path: impl AsRef<Path>,
+        args: DatabaseArguments,
+    ) -> eyre::Result<NodeBuilder<DatabaseEnv, ChainSpec>> {
+        let path = path.as_ref();
+        let database = reth_db::init_db(path, args)
+            .wrap_err_with(|| format!("failed to open database at {}", path.display()))?;
+        Ok(self.with_database(database))
+    }
+
     /// Sets the [`RocksDBProvider`] to use instead of creating one during launch.
     pub fn with_rocksdb_provider(mut self, rocksdb_provider: RocksDBProvider) -> Self {
         self.rocksdb_provider = Some(rocksdb_provider);
         self
     }
 
+    /// Retains receipts of the last `distance` blocks and prunes older ones.
+    ///
+    /// See [`NodeConfig::with_receipts_retention`].
+    pub fn with_receipts_retention(mut self, distance: u64) -> eyre::Result<Self> {
+        self.config = self.config.with_receipts_retention(distance)?;
+        Ok(self)
+    }
+
     /// Preconfigure the builder with the context to launch the node.
     ///
     /// This provides the task executor and the data directory for the node.
@@ -264,13 +304,36 @@ impl<DB, ChainSpec: EthChainSpec> NodeBuilder<DB, ChainSpec> {
     /// The entire `datadir` will be cleaned up when the node is dropped.
     #[cfg(feature = "test-utils")]
     pub fn testing_node_with_datadir(
-        mut self,
+        self,
         task_executor: TaskExecutor,
         datadir: impl Into<std::path::PathBuf>,
     ) -> WithLaunchContext<
         NodeBuilder<Arc<reth_db::test_utils::TempDatabase<reth_db::DatabaseEnv>>, ChainSpec>,
     > {
-        let path = reth_node_core::dirs::MaybePlatformPath::<DataDirPath>::from(datadir.into());
+        WithLaunchContext { builder: self.with_test_datadir(datadir.into()), task_executor }
+    }
+
+    /// Configures an _ephemeral_ in-memory database for the node.
+    ///
+    /// The entire datadir, including the database and static files, is placed on a tmpfs
+    /// (`/dev/shm`) if available, see [`reth_db::test_utils::in_memory_tempdir_path`], and is
+    /// cleaned up when the node is dropped.
+    ///
+    /// Nothing is durable, this is intended for testing and simulations only.
+    #[cfg(feature = "test-utils")]
+    pub fn with_in_memory_database(
+        self,
+    ) -> NodeBuilder<Arc<reth_db::test_utils::TempDatabase<reth_db::DatabaseEnv>>, ChainSpec> {
+        self.with_test_datadir(reth_db::test_utils::in_memory_tempdir_path())
+    }
+
+    /// Configures the given `datadir` and a temporary test database inside of it.
+    #[cfg(feature = "test-utils")]
+    fn with_test_datadir(
+        mut self,
+        datadir: std::path::PathBuf,
+    ) -> NodeBuilder<Arc<reth_db::test_utils::TempDatabase<reth_db::DatabaseEnv>>, ChainSpec> {
+        let path = reth_node_core::dirs::MaybePlatformPath::<DataDirPath>::from(datadir);
         self.config = self.config.with_datadir_args(reth_node_core::args::DatadirArgs {
             datadir: path.clone(),
             ..Default::default()
@@ -281,7 +344,7 @@ impl<DB, ChainSpec: EthChainSpec> NodeBuilder<DB, ChainSpec> {
 
         let db = reth_db::test_utils::create_test_rw_db_with_datadir(data_dir.data_dir());
 
-        WithLaunchContext { builder: self.with_database(db), task_executor }
+        self.with_database(db)
     }
 }
 
@@ -337,6 +400,39 @@ impl<Builder> WithLaunchContext<Builder> {
     pub const fn task_executor(&self) -> &TaskExecutor {
         &self.task_executor
     }
+
+    /// Registers a callback that is invoked with the task name and panic payload when a critical
+    /// task panics, e.g. to forward the event to external alerting.
+    ///
+    /// The panic is still logged as before. See [`TaskExecutor::set_task_panic_hook`].
+    pub fn on_task_panic<F>(self, f: F) -> Self
+    where
+        F: Fn(&str, &dyn Any) + Send + Sync + 'static,
+    {
+        self.task_executor.set_task_panic_hook(f);
+        self
+    }
+
+    /// Routes all tasks spawned by the node through a [`DeterministicScheduler`] that the test
+    /// drives manually, instead of the configured multi-threaded runtime.
+    ///
+    /// The node must be launched and awaited via [`DeterministicScheduler::block_on`]; spawned
+    /// tasks then only make progress while the scheduler is driven and timers only fire when
+    /// time is advanced, so tests can step the node instead of sleeping.
+    ///
+    /// This replaces the task executor; a hook registered via
+    /// [`on_task_panic`](Self::on_task_panic) is carried over to the new executor.
+    ///
+    /// **Unsuitable for production**, only available with the `test-utils` feature.
+    #[cfg(feature = "test-utils")]
+    pub fn with_deterministic_scheduling(self) -> (Self, DeterministicScheduler) {
+        let scheduler = DeterministicScheduler::new();
+        let task_executor = scheduler.runtime();
+        if let Some(hook) = self.task_executor.task_panic_hook() {
+            task_executor.set_task_panic_hook(move |name, payload| hook(name, payload));
+        }
+        (Self { builder: self.builder, task_executor }, scheduler)
+    }
 }
 
 impl<DB, ChainSpec> WithLaunchContext<NodeBuilder<DB, ChainSpec>> {
@@ -362,6 +458,14 @@ where
         self
     }
 
+    /// Retains receipts of the last `distance` blocks and prunes older ones.
+    ///
+    /// See [`NodeConfig::with_receipts_retention`].
+    pub fn with_receipts_retention(mut self, distance: u64) -> eyre::Result<Self> {
+        self.builder = self.builder.with_receipts_retention(distance)?;
+        Ok(self)
+    }
+
     /// Configures the types of the node.
     pub fn with_types<T>(self) -> WithLaunchContext<NodeBuilderWithTypes<RethFullAdapter<DB, T>>>
     where
@@ -552,6 +656,31 @@ where
         Self { builder: self.builder.on_node_started(hook), task_executor: self.task_executor }
     }
 
+    /// Sets the async hook that is run once the node has started.
+    ///
+    /// See [`NodeBuilderWithComponents::on_node_started_async`].
+    pub fn on_node_started_async<F, Fut>(self, hook: F) -> Self
+    where
+        F: FnOnce(FullNode<NodeAdapter<T, CB::Components>, AO>) -> Fut + Send + 'static,
+        Fut: Future<Output = eyre::Result<()>> + Send + 'static,
+    {
+        Self {
+            builder: self.builder.on_node_started_async(hook),
+            task_executor: self.task_executor,
+        }
+    }
+
+    /// Sets the hook that is run once the node is shutting down.
+    ///
+    /// See [`NodeBuilderWithComponents::on_shutdown`].
+    pub fn on_shutdown<F, Fut>(self, hook: F) -> Self
+    where
+        F: FnOnce(NodeAdapter<T, CB::Components>) -> Fut + Send + 'static,
+        Fut: Future<Output = eyre::Result<()>> + Send + 'static,
+    {
+        Self { builder: self.builder.on_shutdown(hook), task_executor: self.task_executor }
+    }
+
     /// Modifies the addons with the given closure.
     ///
     /// This method provides access to methods on the addons type that don't have
@@ -594,6 +723,17 @@ where
         Self { builder: self.builder.on_rpc_started(hook), task_executor: self.task_executor }
     }
 
+    /// Sets the hook that is run after the rpc servers have stopped during the graceful shutdown.
+    ///
+    /// See [`NodeBuilderWithComponents::on_rpc_stopped`].
+    pub fn on_rpc_stopped<F, Fut>(self, hook: F) -> Self
+    where
+        F: FnOnce(RethRpcServerHandles) -> Fut + Send + 'static,
+        Fut: Future<Output = eyre::Result<()>> + Send + 'static,
+    {
+        Self { builder: self.builder.on_rpc_stopped(hook), task_executor: self.task_executor }
+    }
+
     /// Sets the hook that is run to configure the rpc modules.
     ///
     /// This hook can obtain the node's components (txpool, provider, etc.) and can modify the
@@ -637,6 +777,20 @@ where
         Self { builder: self.builder.extend_rpc_modules(hook), task_executor: self.task_executor }
     }
 
+    /// Returns the IDs of all installed `ExExes`, in installation order.
+    ///
+    /// See [`NodeBuilderWithComponents::installed_exex_ids`].
+    pub fn installed_exex_ids(&self) -> impl Iterator<Item = &str> {
+        self.builder.installed_exex_ids()
+    }
+
+    /// Checks that all installed `ExExes` have unique IDs, reporting the colliding IDs otherwise.
+    ///
+    /// See [`NodeBuilderWithComponents::validate_exex_ids`].
+    pub fn validate_exex_ids(&self) -> eyre::Result<()> {
+        self.builder.validate_exex_ids()
+    }
+
     /// Installs an `ExEx` (Execution Extension) in the node.
     ///
     /// # Note
@@ -654,6 +808,30 @@ where
         }
     }
 
+    /// Installs an `ExEx` (Execution Extension) in the node with the given priority.
+    ///
+    /// See [`NodeBuilderWithComponents::install_exex_with_priority`].
+    ///
+    /// # Note
+    ///
+    /// The `ExEx` ID must be unique.
+    pub fn install_exex_with_priority<F, R, E>(
+        self,
+        exex_id: impl Into<String>,
+        priority: i32,
+        exex: F,
+    ) -> Self
+    where
+        F: FnOnce(ExExContext<NodeAdapter<T, CB::Components>>) -> R + Send + 'static,
+        R: Future<Output = eyre::Result<E>> + Send,
+        E: Future<Output = eyre::Result<()>> + Send,
+    {
+        Self {
+            builder: self.builder.install_exex_with_priority(exex_id, priority, exex),
+            task_executor: self.task_executor,
+        }
+    }
+
     /// Installs an `ExEx` (Execution Extension) in the node if the condition is true.
     ///
     /// # Note
@@ -672,6 +850,93 @@ where
         }
     }
 
+    /// Registers an external sink that periodically receives a snapshot of the node's metrics.
+    ///
+    /// See [`NodeBuilderWithComponents::with_metrics_sink`].
+    pub fn with_metrics_sink(self, sink: impl MetricsSink + 'static) -> Self {
+        Self { builder: self.builder.with_metrics_sink(sink), task_executor: self.task_executor }
+    }
+
+    /// Registers a custom source of peers, e.g. a DNS tree or an HTTP endpoint.
+    ///
+    /// See [`NodeBuilderWithComponents::with_discovery_source`].
+    pub fn with_discovery_source(self, source: impl DiscoverySource + 'static) -> Self {
+        Self {
+            builder: self.builder.with_discovery_source(source),
+            task_executor: self.task_executor,
+        }
+    }
+
+    /// Skips the given pipeline stages during sync.
+    ///
+    /// See [`NodeBuilderWithComponents::with_skipped_stages`].
+    pub fn with_skipped_stages(self, stages: impl IntoIterator<Item = StageId>) -> Self {
+        Self {
+            builder: self.builder.with_skipped_stages(stages),
+            task_executor: self.task_executor,
+        }
+    }
+
+    /// Imports the headers of the static file snapshot in the given directory before launch.
+    ///
+    /// See [`NodeBuilderWithComponents::with_static_file_snapshot`].
+    pub fn with_static_file_snapshot(self, path: impl Into<PathBuf>) -> Self {
+        Self {
+            builder: self.builder.with_static_file_snapshot(path),
+            task_executor: self.task_executor,
+        }
+    }
+
+    /// Imports the RLP-encoded blocks of the given file into the engine during launch.
+    ///
+    /// See [`NodeBuilderWithComponents::with_block_import_file`].
+    pub fn with_block_import_file(self, path: impl Into<PathBuf>) -> Self {
+        Self {
+            builder: self.builder.with_block_import_file(path),
+            task_executor: self.task_executor,
+        }
+    }
+
+    /// Replays the engine API calls of the given call log against the engine after launch.
+    ///
+    /// See [`NodeBuilderWithComponents::with_engine_replay`].
+    pub fn with_engine_replay(self, path: impl Into<PathBuf>) -> Self {
+        Self { builder: self.builder.with_engine_replay(path), task_executor: self.task_executor }
+    }
+
+    /// Overrides the genesis state root that is computed from the chain spec's allocation.
+    ///
+    /// See [`NodeBuilderWithComponents::with_genesis_state_root`].
+    pub fn with_genesis_state_root(self, root: B256) -> Self
+    where
+        <T::Types as NodeTypes>::ChainSpec: GenesisHeaderMut<Header: HeaderMut> + Clone,
+    {
+        Self {
+            builder: self.builder.with_genesis_state_root(root),
+            task_executor: self.task_executor,
+        }
+    }
+
+    /// Applies the given function to the pool config of the node before the pool is built.
+    ///
+    /// See [`NodeBuilderWithComponents::map_pool_config`].
+    pub fn map_pool_config<F>(self, f: F) -> Self
+    where
+        F: Fn(PoolConfig) -> PoolConfig + Send + Sync + 'static,
+    {
+        Self { builder: self.builder.map_pool_config(f), task_executor: self.task_executor }
+    }
+
+    /// Sets a filter that decides whether data that is eligible for pruning is kept anyway.
+    ///
+    /// See [`NodeBuilderWithComponents::with_prune_filter`].
+    pub fn with_prune_filter<F>(self, filter: F) -> Self
+    where
+        F: PruneFilter + 'static,
+    {
+        Self { builder: self.builder.with_prune_filter(filter), task_executor: self.task_executor }
+    }
+
     /// Launches the node with the given launcher.
     pub async fn launch_with<L>(self, launcher: L) -> eyre::Result<L::Node>
     where
@@ -706,6 +971,29 @@ where
         self.builder.launch_with(launcher).await
     }
 
+    /// Launches the node with the [`EngineNodeLauncher`] like [`WithLaunchContext::launch`], but
+    /// fails if the launch does not complete within the given `timeout`.
+    ///
+    /// The returned error names the [`LaunchPhase`] that was still pending when the deadline was
+    /// hit.
+    pub async fn launch_with_timeout(
+        self,
+        timeout: Duration,
+    ) -> eyre::Result<<EngineNodeLauncher as LaunchNode<NodeBuilderWithComponents<T, CB, AO>>>::Node>
+    where
+        EngineNodeLauncher: LaunchNode<NodeBuilderWithComponents<T, CB, AO>>,
+    {
+        let launcher = self.engine_api_launcher();
+        let launch_phase = launcher.ctx.launch_phase.clone();
+        match tokio::time::timeout(timeout, self.builder.launch_with(launcher)).await {
+            Ok(node) => node,
+            Err(_) => eyre::bail!(
+                "node launch timed out after {timeout:?} during {} phase",
+                launch_phase.get()
+            ),
+        }
+    }
+
     /// Launches the node with the [`DebugNodeLauncher`].
     ///
     /// This is equivalent to [`WithLaunchContext::launch`], but will enable the debugging features,
@@ -741,6 +1029,10 @@ where
     }
 }
 
+/// Transform applied to the [`PoolConfig`] of the node before the pool is built, see
+/// [`NodeBuilderWithComponents::map_pool_config`].
+pub type PoolConfigTransform = Arc<dyn Fn(PoolConfig) -> PoolConfig + Send + Sync>;
+
 /// Captures the necessary context for building the components of the node.
 pub struct BuilderContext<Node: FullNodeTypes> {
     /// The current head of the blockchain at launch.
@@ -751,6 +1043,10 @@ pub struct BuilderContext<Node: FullNodeTypes> {
     pub(crate) executor: TaskExecutor,
     /// Config container
     pub(crate) config_container: WithConfigs<<Node::Types as NodeTypes>::ChainSpec>,
+    /// Tracks the launch phase, if the context is created during a node launch.
+    pub(crate) launch_phase: Option<LaunchPhaseTracker>,
+    /// Transform applied to the pool config derived from the node config.
+    pub(crate) pool_config_transform: Option<PoolConfigTransform>,
 }
 
 impl<Node: FullNodeTypes> BuilderContext<Node> {
@@ -761,7 +1057,36 @@ impl<Node: FullNodeTypes> BuilderContext<Node> {
         executor: TaskExecutor,
         config_container: WithConfigs<<Node::Types as NodeTypes>::ChainSpec>,
     ) -> Self {
-        Self { head, provider, executor, config_container }
+        Self {
+            head,
+            provider,
+            executor,
+            config_container,
+            launch_phase: None,
+            pool_config_transform: None,
+        }
+    }
+
+    /// Sets the transform that is applied to the pool config, see [`Self::pool_config`].
+    pub(crate) fn with_pool_config_transform(
+        mut self,
+        pool_config_transform: Option<PoolConfigTransform>,
+    ) -> Self {
+        self.pool_config_transform = pool_config_transform;
+        self
+    }
+
+    /// Sets the tracker that records the phase of the node launch this context belongs to.
+    pub(crate) fn with_launch_phase(mut self, launch_phase: LaunchPhaseTracker) -> Self {
+        self.launch_phase = Some(launch_phase);
+        self
+    }
+
+    /// Records that the node launch entered the given phase, if it is tracked.
+    fn set_launch_phase(&self, phase: LaunchPhase) {
+        if let Some(launch_phase) = &self.launch_phase {
+            launch_phase.set(phase);
+        }
     }
 
     /// Returns the configured provider to interact with the blockchain.
@@ -801,19 +1126,47 @@ impl<Node: FullNodeTypes> BuilderContext<Node> {
         self.provider().chain_spec()
     }
 
+    /// Returns the directory the node's static files are stored in.
+    ///
+    /// This is the directory used by the provider, which creates it on startup.
+    pub fn static_files_dir(&self) -> PathBuf {
+        self.provider().static_file_provider().directory().to_path_buf()
+    }
+
     /// Returns true if the node is configured as --dev
     pub const fn is_dev(&self) -> bool {
         self.config().dev.dev
     }
 
     /// Returns the transaction pool config of the node.
+    ///
+    /// This is derived from the txpool settings of the node config, with the transform of
+    /// [`NodeBuilderWithComponents::map_pool_config`] applied, if any.
     pub fn pool_config(&self) -> PoolConfig {
-        self.config().txpool.pool_config()
+        let pool_config = self.config().txpool.pool_config();
+        match &self.pool_config_transform {
+            Some(transform) => transform(pool_config),
+            None => pool_config,
+        }
     }
 
-    /// Loads `EnvKzgSettings::Default`.
-    pub const fn kzg_settings(&self) -> eyre::Result<EnvKzgSettings> {
-        Ok(EnvKzgSettings::Default)
+    /// Loads the KZG settings.
+    ///
+    /// Returns `EnvKzgSettings::Default` unless a custom trusted setup file is configured, in which
+    /// case the file is parsed into `EnvKzgSettings::Custom`.
+    pub fn kzg_settings(&self) -> eyre::Result<EnvKzgSettings> {
+        let Some(path) = &self.config().builder.trusted_setup_file else {
+            return Ok(EnvKzgSettings::Default)
+        };
+
+        if !path.is_file() {
+            eyre::bail!("KZG trusted setup file not found: {}", path.display())
+        }
+        let settings = c_kzg::KzgSettings::load_trusted_setup_file(path, 0).map_err(|err| {
+            eyre::eyre!("failed to load KZG trusted setup from {}: {err:?}", path.display())
+        })?;
+
+        Ok(EnvKzgSettings::Custom(Arc::new(settings)))
     }
 
     /// Returns the config for payload building.
@@ -845,7 +1198,7 @@ impl<Node: FullNodeTypes> BuilderContext<Node> {
             builder,
             pool,
             self.config().network.transactions_manager_config(),
-            self.config().network.tx_propagation_policy,
+            self.config().network.resolved_tx_propagation_policy(),
         )
     }
 
@@ -917,6 +1270,14 @@ impl<Node: FullNodeTypes> BuilderContext<Node> {
             .transactions_with_policies(pool, tx_config, propagation_policy, announcement_policy)
             .request_handler(self.provider().clone())
             .split_with_handle();
+        let eth = eth
+            .with_max_concurrent_receipt_requests(
+                self.config().network.max_concurrent_receipt_requests,
+            )
+            .with_max_queued_receipt_requests(self.config().network.max_queued_receipt_requests);
+
+        // the network is running, the remaining components are built next
+        self.set_launch_phase(LaunchPhase::Components);
 
         self.executor.spawn_critical_blocking_task("p2p txpool", txpool);
         self.executor.spawn_critical_blocking_task("p2p eth request handler", eth);
@@ -946,6 +1307,15 @@ impl<Node: FullNodeTypes> BuilderContext<Node> {
         handle
     }
 
+    /// Returns the secret key of the node's network identity, e.g. to derive its peer ID.
+    ///
+    /// This is the key the network is started with: the key passed with `--p2p-secret-key-hex`,
+    /// otherwise the key loaded from `--p2p-secret-key` or from the default path in the data
+    /// dir. If no key file exists yet, a new key is generated and written to the file.
+    pub fn network_secret_key(&self) -> eyre::Result<SecretKey> {
+        self.network_secret(&self.config().datadir())
+    }
+
     /// Get the network secret from the given data dir
     fn network_secret(&self, data_dir: &ChainPath<DataDirPath>) -> eyre::Result<SecretKey> {
         let secret_key = self.config().network.secret_key(data_dir.p2p_secret())?;
@@ -971,6 +1341,7 @@ impl<Node: FullNodeTypes<Types: NodeTypes<ChainSpec: Hardforks>>> BuilderContext
     where
         N: NetworkPrimitives,
     {
+        self.set_launch_phase(LaunchPhase::Network);
         let network_config = self.network_config()?;
         let builder = NetworkManager::builder(network_config).await?;
         Ok(builder)
@@ -990,7 +1361,8 @@ impl<Node: FullNodeTypes<Types: NodeTypes<ChainSpec: Hardforks>>> BuilderContext
     where
         N: NetworkPrimitives,
     {
-        let secret_key = self.network_secret(&self.config().datadir())?;
+        self.config().network.validate()?;
+        let secret_key = self.network_secret_key()?;
         let default_peers_path = self.config().datadir().known_peers();
         let builder = self
             .config()
@@ -1006,6 +1378,21 @@ impl<Node: FullNodeTypes<Types: NodeTypes<ChainSpec: Hardforks>>> BuilderContext
 
         Ok(builder)
     }
+
+    /// Returns all hardforks of the chain spec that are not yet active at the current [`Head`],
+    /// together with their activation condition.
+    ///
+    /// Forks are returned in the order of the chain spec, this includes block, total difficulty
+    /// and timestamp based forks. Forks that are never activated are skipped.
+    pub fn upcoming_forks(&self) -> Vec<(&'static str, ForkCondition)> {
+        self.chain_spec()
+            .forks_iter()
+            .filter(|(_, condition)| {
+                *condition != ForkCondition::Never && !condition.active_at_head(&self.head)
+            })
+            .map(|(fork, condition)| (fork.name(), condition))
+            .collect()
+    }
 }
 
 impl<Node: FullNodeTypes> std::fmt::Debug for BuilderContext<Node> {
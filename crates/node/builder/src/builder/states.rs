@@ -10,15 +10,30 @@ use crate::{
     hooks::NodeHooks,
     launch::LaunchNode,
     rpc::{RethRpcAddOns, RethRpcServerHandles, RpcContext},
-    AddOns, ComponentsFor, FullNode,
+    AddOns, ComponentsFor, FullNode, PoolConfigTransform,
 };
 
+use alloy_primitives::B256;
+use reth_chainspec::GenesisHeaderMut;
 use reth_exex::ExExContext;
+use reth_network::DiscoverySource;
 use reth_node_api::{FullNodeComponents, FullNodeTypes, NodeAddOns, NodeTypes};
 use reth_node_core::node_config::NodeConfig;
+use reth_node_metrics::sink::{BoxedMetricsSink, MetricsSink};
+use reth_primitives_traits::header::HeaderMut;
 use reth_provider::providers::RocksDBProvider;
+use reth_prune::{PruneFilter, SharedPruneFilter};
+use reth_stages::StageId;
 use reth_tasks::TaskExecutor;
-use std::{fmt, fmt::Debug, future::Future};
+use reth_transaction_pool::PoolConfig;
+use std::{
+    collections::{HashMap, HashSet},
+    fmt,
+    fmt::Debug,
+    future::Future,
+    path::PathBuf,
+    sync::Arc,
+};
 
 /// A node builder that also has the configured types.
 pub struct NodeBuilderWithTypes<T: FullNodeTypes> {
@@ -52,7 +67,20 @@ impl<T: FullNodeTypes> NodeBuilderWithTypes<T> {
             adapter,
             rocksdb_provider,
             components_builder,
-            add_ons: AddOns { hooks: NodeHooks::default(), exexs: Vec::new(), add_ons: () },
+            add_ons: AddOns {
+                hooks: NodeHooks::default(),
+                exexs: Vec::new(),
+                exex_priorities: HashMap::new(),
+                metrics_sinks: Vec::new(),
+                discovery_sources: Vec::new(),
+                skipped_stages: Vec::new(),
+                static_file_snapshot: None,
+                block_import_file: None,
+                engine_replay: None,
+                prune_filter: None,
+                pool_config_transform: None,
+                add_ons: (),
+            },
         }
     }
 }
@@ -181,7 +209,20 @@ where
             adapter,
             rocksdb_provider,
             components_builder,
-            add_ons: AddOns { hooks: NodeHooks::default(), exexs: Vec::new(), add_ons },
+            add_ons: AddOns {
+                hooks: NodeHooks::default(),
+                exexs: Vec::new(),
+                exex_priorities: HashMap::new(),
+                metrics_sinks: Vec::new(),
+                discovery_sources: Vec::new(),
+                skipped_stages: Vec::new(),
+                static_file_snapshot: None,
+                block_import_file: None,
+                engine_replay: None,
+                prune_filter: None,
+                pool_config_transform: None,
+                add_ons,
+            },
         }
     }
 }
@@ -212,6 +253,33 @@ where
         self
     }
 
+    /// Sets the async hook that is run once the node has started.
+    ///
+    /// The returned future is awaited by the launcher after the
+    /// [`on_node_started`](Self::on_node_started) hook, an error aborts the launch.
+    pub fn on_node_started_async<F, Fut>(mut self, hook: F) -> Self
+    where
+        F: FnOnce(FullNode<NodeAdapter<T, CB::Components>, AO>) -> Fut + Send + 'static,
+        Fut: Future<Output = eyre::Result<()>> + Send + 'static,
+    {
+        self.add_ons.hooks.set_on_node_started_async(hook);
+        self
+    }
+
+    /// Sets the hook that is run once the node is shutting down.
+    ///
+    /// The hook receives the node's components and the returned future is run once the graceful
+    /// shutdown signal fires. The task executor waits for it to complete before shutting down, so
+    /// it can be used to flush or close external resources.
+    pub fn on_shutdown<F, Fut>(mut self, hook: F) -> Self
+    where
+        F: FnOnce(NodeAdapter<T, CB::Components>) -> Fut + Send + 'static,
+        Fut: Future<Output = eyre::Result<()>> + Send + 'static,
+    {
+        self.add_ons.hooks.set_on_shutdown(hook);
+        self
+    }
+
     /// Installs an `ExEx` (Execution Extension) in the node.
     ///
     /// # Note
@@ -223,7 +291,193 @@ where
         R: Future<Output = eyre::Result<E>> + Send,
         E: Future<Output = eyre::Result<()>> + Send,
     {
-        self.add_ons.exexs.push((exex_id.into(), Box::new(exex)));
+        self.install_exex_with_priority(exex_id, 0, exex)
+    }
+
+    /// Installs an `ExEx` (Execution Extension) in the node with the given priority.
+    ///
+    /// Each canonical state notification is dispatched to the `ExExes` in ascending priority order,
+    /// `ExExes` with the same priority in installation order. Only the dispatch is ordered: the
+    /// manager does not wait for an `ExEx` to process a notification before dispatching it to the
+    /// next one. On graceful shutdown, `ExExes` are torn down in ascending priority order, e.g. an
+    /// `ExEx` with priority `0` is torn down before one with priority `1`. `ExExes` with the same
+    /// priority are torn down concurrently.
+    /// [`Self::install_exex`] installs with priority `0`.
+    ///
+    /// # Note
+    ///
+    /// The `ExEx` ID must be unique.
+    pub fn install_exex_with_priority<F, R, E>(
+        mut self,
+        exex_id: impl Into<String>,
+        priority: i32,
+        exex: F,
+    ) -> Self
+    where
+        F: FnOnce(ExExContext<NodeAdapter<T, CB::Components>>) -> R + Send + 'static,
+        R: Future<Output = eyre::Result<E>> + Send,
+        E: Future<Output = eyre::Result<()>> + Send,
+    {
+        let exex_id = exex_id.into();
+        self.add_ons.exex_priorities.insert(exex_id.clone(), priority);
+        self.add_ons.exexs.push((exex_id, Box::new(exex)));
+        self
+    }
+
+    /// Returns the IDs of all installed `ExExes`, in installation order.
+    pub fn installed_exex_ids(&self) -> impl Iterator<Item = &str> {
+        self.add_ons.exexs.iter().map(|(id, _)| id.as_str())
+    }
+
+    /// Checks that all installed `ExExes` have unique IDs.
+    ///
+    /// Returns an error listing every ID that was installed more than once.
+    pub fn validate_exex_ids(&self) -> eyre::Result<()> {
+        let mut seen = HashSet::new();
+        let mut duplicates = Vec::new();
+        for id in self.installed_exex_ids() {
+            if !seen.insert(id) && !duplicates.contains(&id) {
+                duplicates.push(id);
+            }
+        }
+
+        if !duplicates.is_empty() {
+            eyre::bail!("duplicate ExEx IDs: {}", duplicates.join(", "))
+        }
+        Ok(())
+    }
+
+    /// Registers an external sink that periodically receives a snapshot of the node's metrics.
+    ///
+    /// Snapshots are delivered at the interval configured via
+    /// `--metrics.prometheus.push.interval`. The built-in Prometheus exporter is unaffected.
+    pub fn with_metrics_sink(mut self, sink: impl MetricsSink + 'static) -> Self {
+        self.add_ons.metrics_sinks.push(BoxedMetricsSink::new(sink));
+        self
+    }
+
+    /// Registers a custom source of peers, e.g. a DNS tree or an HTTP endpoint.
+    ///
+    /// The source is queried once the network is started and then periodically, all discovered
+    /// peers are added to the network's peer set next to the configured bootnodes.
+    ///
+    /// # Panics
+    ///
+    /// If the source's [`DiscoverySource::interval`] is zero.
+    pub fn with_discovery_source(mut self, source: impl DiscoverySource + 'static) -> Self {
+        assert!(!source.interval().is_zero(), "discovery source interval must be non-zero");
+        self.add_ons.discovery_sources.push(Box::new(source));
+        self
+    }
+
+    /// Skips the given pipeline stages during sync.
+    ///
+    /// This is intended to speed up the development loop, e.g. when iterating on an `ExEx` or RPC
+    /// extension, and produces an incomplete node that is **not** suitable for production.
+    ///
+    /// Launching fails if any of the stages is required outside of `--dev` mode, see
+    /// [`LOAD_BEARING_STAGES`](crate::setup::LOAD_BEARING_STAGES).
+    pub fn with_skipped_stages(mut self, stages: impl IntoIterator<Item = StageId>) -> Self {
+        self.add_ons.skipped_stages.extend(stages);
+        self
+    }
+
+    /// Imports the static file snapshot in the given directory before launch.
+    ///
+    /// The snapshot is validated and its header files are linked into the node's static files
+    /// directory together with the headers stage checkpoint, the snapshot itself is left
+    /// untouched, see [`import_static_file_snapshot`](crate::setup::import_static_file_snapshot).
+    /// Launching fails if the snapshot is corrupt or incomplete. The import is skipped if the
+    /// storage already contains headers past genesis.
+    ///
+    /// Only the headers segment is imported. Bodies, transactions and receipts can't be restored
+    /// from static files alone, since they rely on database tables like the block body indices,
+    /// so they are validated but synced by the pipeline.
+    pub fn with_static_file_snapshot(mut self, path: impl Into<PathBuf>) -> Self {
+        self.add_ons.static_file_snapshot = Some(path.into());
+        self
+    }
+
+    /// Imports the RLP-encoded blocks of the given file into the engine during launch.
+    ///
+    /// This is the library equivalent of the `import` command and is intended for bootstrapping
+    /// private networks from a pre-built chain. The blocks are validated by the engine before the
+    /// node is reported as started, blocks that are already canonical are skipped, see
+    /// [`import_blocks_from_file`](crate::setup::import_blocks_from_file). Launching fails at the
+    /// first invalid block.
+    pub fn with_block_import_file(mut self, path: impl Into<PathBuf>) -> Self {
+        self.add_ons.block_import_file = Some(path.into());
+        self
+    }
+
+    /// Replays the engine API messages stored in the given directory against the engine after
+    /// launch.
+    ///
+    /// This is intended for reproducing bugs in the interaction with the consensus layer on a fresh
+    /// node. The directory is the one written with `--debug.engine-api-store`, which records the
+    /// engine's responses alongside the messages, see
+    /// [`EngineMessageStore`](reth_engine_util::engine_store::EngineMessageStore). The messages
+    /// are sent in order in a background task and every response that diverges from the recorded
+    /// one is logged, see [`replay_engine_calls`](crate::setup::replay_engine_calls).
+    pub fn with_engine_replay(mut self, path: impl Into<PathBuf>) -> Self {
+        self.add_ons.engine_replay = Some(path.into());
+        self
+    }
+
+    /// Overrides the genesis state root that is computed from the chain spec's allocation.
+    ///
+    /// This is intended for chains with a non-standard genesis, e.g. with predeployed precompiles,
+    /// whose genesis state root differs from the root computed by reth. The genesis header of the
+    /// node's chain spec is replaced with one with the given state root, so the genesis hash, and
+    /// with it the fork ID and the `Status` message, change accordingly, see
+    /// [`GenesisHeaderMut::set_genesis_state_root`]. During genesis initialization a warning is
+    /// logged if the state root computed from the allocation differs from the override.
+    ///
+    /// Note: a wrong override will cause the node to diverge from the network.
+    pub fn with_genesis_state_root(mut self, root: B256) -> Self
+    where
+        <T::Types as NodeTypes>::ChainSpec: GenesisHeaderMut<Header: HeaderMut> + Clone,
+    {
+        Arc::make_mut(&mut self.config.chain).set_genesis_state_root(root);
+        self
+    }
+
+    /// Applies the given function to the pool config of the node before the pool is built.
+    ///
+    /// The pool config is derived from the txpool settings of the node config, this allows
+    /// adjusting it with data that isn't available on the CLI, e.g. raising the account slots of
+    /// known relayers. Calling this multiple times composes the functions in call order. The
+    /// function is applied on every call of
+    /// [`BuilderContext::pool_config`](crate::BuilderContext::pool_config), so it should be
+    /// cheap and deterministic.
+    pub fn map_pool_config<F>(mut self, f: F) -> Self
+    where
+        F: Fn(PoolConfig) -> PoolConfig + Send + Sync + 'static,
+    {
+        let transform: PoolConfigTransform = match self.add_ons.pool_config_transform.take() {
+            Some(prev) => Arc::new(move |pool_config| f(prev(pool_config))),
+            None => Arc::new(f),
+        };
+        self.add_ons.pool_config_transform = Some(transform);
+        self
+    }
+
+    /// Sets a filter that decides whether data that is eligible for pruning is kept anyway.
+    ///
+    /// This allows custom retention rules that can't be expressed with the prune modes of the
+    /// CLI or the config file, e.g. keeping all receipts of specific contracts.
+    ///
+    /// Receipts in the database are pruned individually. Receipts in static files, the default for
+    /// new nodes, can only be pruned by whole files, so pruning of static file receipts stops at
+    /// the file that contains the first exempt receipt.
+    ///
+    /// The filter is called for every receipt the pruner visits, so a filter that exempts a lot of
+    /// data slows down pruning and keeps storage from shrinking. See [`PruneFilter`].
+    pub fn with_prune_filter<F>(mut self, filter: F) -> Self
+    where
+        F: PruneFilter + 'static,
+    {
+        self.add_ons.prune_filter = Some(SharedPruneFilter::new(filter));
         self
     }
 
@@ -303,6 +557,21 @@ where
         })
     }
 
+    /// Sets the hook that is run after the rpc servers have stopped during the graceful shutdown.
+    ///
+    /// The hook receives the handles of the stopped servers and is awaited as part of the graceful
+    /// shutdown, see [`OnRpcStopped`](crate::rpc::OnRpcStopped).
+    pub fn on_rpc_stopped<F, Fut>(self, hook: F) -> Self
+    where
+        F: FnOnce(RethRpcServerHandles) -> Fut + Send + 'static,
+        Fut: Future<Output = eyre::Result<()>> + Send + 'static,
+    {
+        self.map_add_ons(|mut add_ons| {
+            add_ons.hooks_mut().set_on_rpc_stopped(hook);
+            add_ons
+        })
+    }
+
     /// Sets the hook that is run to configure the rpc modules.
     pub fn extend_rpc_modules<F>(self, hook: F) -> Self
     where
@@ -1,5 +1,6 @@
-use std::fmt;
+use std::{fmt, future::Future};
 
+use futures::future::BoxFuture;
 use reth_node_api::{FullNodeComponents, NodeAddOns};
 
 use crate::node::FullNode;
@@ -10,6 +11,10 @@ pub struct NodeHooks<Node: FullNodeComponents, AddOns: NodeAddOns<Node>> {
     pub on_component_initialized: Box<dyn OnComponentInitializedHook<Node>>,
     /// Hook to run once the node is started.
     pub on_node_started: Box<dyn OnNodeStartedHook<Node, AddOns>>,
+    /// Async hook to run once the node is started.
+    pub on_node_started_async: Box<dyn OnNodeStartedAsyncHook<Node, AddOns>>,
+    /// Hook to run once the node is shutting down, if any.
+    pub on_shutdown: Option<Box<dyn OnShutdownHook<Node>>>,
 }
 
 impl<Node, AddOns> NodeHooks<Node, AddOns>
@@ -22,6 +27,8 @@ where
         Self {
             on_component_initialized: Box::<()>::default(),
             on_node_started: Box::<()>::default(),
+            on_node_started_async: Box::<()>::default(),
+            on_shutdown: None,
         }
     }
 
@@ -62,6 +69,24 @@ where
         self.set_on_node_started(hook);
         self
     }
+
+    /// Sets the async hook that is run once the node has started.
+    pub(crate) fn set_on_node_started_async<F>(&mut self, hook: F) -> &mut Self
+    where
+        F: OnNodeStartedAsyncHook<Node, AddOns> + 'static,
+    {
+        self.on_node_started_async = Box::new(hook);
+        self
+    }
+
+    /// Sets the hook that is run once the node is shutting down.
+    pub(crate) fn set_on_shutdown<F>(&mut self, hook: F) -> &mut Self
+    where
+        F: OnShutdownHook<Node> + 'static,
+    {
+        self.on_shutdown = Some(Box::new(hook));
+        self
+    }
 }
 
 impl<Node, AddOns> Default for NodeHooks<Node, AddOns>
@@ -82,6 +107,8 @@ where
         f.debug_struct("NodeHooks")
             .field("on_component_initialized", &"...")
             .field("on_node_started", &"...")
+            .field("on_node_started_async", &"...")
+            .field("on_shutdown", &self.on_shutdown.as_ref().map(|_| "..."))
             .finish()
     }
 }
@@ -122,6 +149,52 @@ where
     }
 }
 
+/// A helper trait for an async hook that is run once the node is started.
+pub trait OnNodeStartedAsyncHook<Node: FullNodeComponents, AddOns: NodeAddOns<Node>>: Send {
+    /// Consumes the event hook and returns the future that runs it.
+    ///
+    /// The future is awaited by the launcher, if it resolves to an error, the node launch will be
+    /// aborted.
+    fn on_event(
+        self: Box<Self>,
+        node: FullNode<Node, AddOns>,
+    ) -> BoxFuture<'static, eyre::Result<()>>;
+}
+
+impl<Node, AddOns, F, Fut> OnNodeStartedAsyncHook<Node, AddOns> for F
+where
+    Node: FullNodeComponents,
+    AddOns: NodeAddOns<Node>,
+    F: FnOnce(FullNode<Node, AddOns>) -> Fut + Send,
+    Fut: Future<Output = eyre::Result<()>> + Send + 'static,
+{
+    fn on_event(
+        self: Box<Self>,
+        node: FullNode<Node, AddOns>,
+    ) -> BoxFuture<'static, eyre::Result<()>> {
+        Box::pin((*self)(node))
+    }
+}
+
+/// A helper trait for the event hook that is run once the node is shutting down.
+pub trait OnShutdownHook<Node>: Send {
+    /// Consumes the event hook and returns the future that runs it.
+    ///
+    /// The future is run once the graceful shutdown signal fires and the task executor waits for
+    /// it to complete before shutting down.
+    fn on_event(self: Box<Self>, node: Node) -> BoxFuture<'static, eyre::Result<()>>;
+}
+
+impl<Node, F, Fut> OnShutdownHook<Node> for F
+where
+    F: FnOnce(Node) -> Fut + Send,
+    Fut: Future<Output = eyre::Result<()>> + Send + 'static,
+{
+    fn on_event(self: Box<Self>, node: Node) -> BoxFuture<'static, eyre::Result<()>> {
+        Box::pin((*self)(node))
+    }
+}
+
 impl<Node> OnComponentInitializedHook<Node> for () {
     fn on_event(self: Box<Self>, _node: Node) -> eyre::Result<()> {
         Ok(())
@@ -137,3 +210,16 @@ where
         Ok(())
     }
 }
+
+impl<Node, AddOns> OnNodeStartedAsyncHook<Node, AddOns> for ()
+where
+    Node: FullNodeComponents,
+    AddOns: NodeAddOns<Node>,
+{
+    fn on_event(
+        self: Box<Self>,
+        _node: FullNode<Node, AddOns>,
+    ) -> BoxFuture<'static, eyre::Result<()>> {
+        Box::pin(async { Ok(()) })
+    }
+}
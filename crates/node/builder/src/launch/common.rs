@@ -32,7 +32,9 @@
 use crate::{
     components::{NodeComponents, NodeComponentsBuilder},
     hooks::OnComponentInitializedHook,
-    BuilderContext, ExExLauncher, NodeAdapter, PrimitivesTy,
+    setup::import_static_file_snapshot,
+    BuilderContext, ExExLauncher, LaunchPhaseTracker, NodeAdapter, NodeStartupSummary,
+    PoolConfigTransform, PrimitivesTy,
 };
 use alloy_eips::eip2124::Head;
 use alloy_primitives::{BlockNumber, B256};
@@ -62,13 +64,14 @@ use reth_node_metrics::{
     hooks::Hooks,
     recorder::install_prometheus_recorder,
     server::{MetricServer, MetricServerConfig},
+    sink::BoxedMetricsSink,
     version::VersionInfo,
 };
 use reth_provider::{
     providers::{NodeTypesForProvider, ProviderNodeTypes, RocksDBProvider, StaticFileProvider},
-    BlockHashReader, BlockNumReader, ProviderError, ProviderFactory, ProviderResult,
-    RocksDBProviderFactory, StageCheckpointReader, StaticFileProviderBuilder,
-    StaticFileProviderFactory,
+    BlockHashReader, BlockNumReader, HeaderProvider, ProviderError, ProviderFactory,
+    ProviderResult, RocksDBProviderFactory, StageCheckpointReader, StaticFileProviderBuilder,
+    StaticFileProviderFactory, StaticFileSegment,
 };
 use reth_prune::{PruneModes, PrunerBuilder};
 use reth_rpc_builder::config::RethRpcServerConfig;
@@ -85,7 +88,9 @@ use reth_tracing::{
 };
 use reth_transaction_pool::TransactionPool;
 use reth_trie_db::ChangesetCache;
-use std::{num::NonZeroUsize, sync::Arc, thread::available_parallelism, time::Duration};
+use std::{
+    num::NonZeroUsize, path::Path, sync::Arc, thread::available_parallelism, time::Duration,
+};
 use tokio::sync::{
     mpsc::{unbounded_channel, UnboundedSender},
     oneshot, watch,
@@ -119,12 +124,14 @@ pub struct LaunchContext {
     pub task_executor: TaskExecutor,
     /// The data directory for the node.
     pub data_dir: ChainPath<DataDirPath>,
+    /// Tracks the phase the launch is currently in.
+    pub launch_phase: LaunchPhaseTracker,
 }
 
 impl LaunchContext {
     /// Create a new instance of the default node launcher.
-    pub const fn new(task_executor: TaskExecutor, data_dir: ChainPath<DataDirPath>) -> Self {
-        Self { task_executor, data_dir }
+    pub fn new(task_executor: TaskExecutor, data_dir: ChainPath<DataDirPath>) -> Self {
+        Self { task_executor, data_dir, launch_phase: Default::default() }
     }
 
     /// Create launch context with attachment.
@@ -169,6 +176,7 @@ impl LaunchContext {
 
         // Update the config with the command line arguments
         toml_config.peers.trusted_nodes_only = config.network.trusted_only;
+        config.network.apply_downloader_timeouts(&mut toml_config);
 
         // Merge static file CLI arguments with config file, giving priority to CLI
         toml_config.static_files =
@@ -452,6 +460,47 @@ impl<R, ChainSpec: EthChainSpec> LaunchContextWith<Attached<WithConfigs<ChainSpe
         Ok(secret)
     }
 
+    /// Checks that the JWT secret for the engine API can be loaded, see [`Self::auth_jwt_secret`].
+    ///
+    /// A missing or malformed secret would otherwise only surface once the consensus client
+    /// connects, so this fails at startup with the path of the offending file instead. If no
+    /// secret file was configured and the default one does not exist yet, it is created.
+    pub fn with_auth_jwt_secret_check(self) -> eyre::Result<Self> {
+        let path =
+            self.node_config().rpc.auth_jwtsecret.clone().unwrap_or_else(|| self.data_dir().jwt());
+        self.auth_jwt_secret().wrap_err_with(|| {
+            format!(
+                "Failed to load the engine API JWT secret from {}. The file must contain a 32 byte \
+                 hex encoded secret.",
+                path.display()
+            )
+        })?;
+        Ok(self)
+    }
+
+    /// Assembles the [`NodeStartupSummary`] from the configs and the number of installed ExExes.
+    pub fn startup_summary(&self, exex_count: usize) -> NodeStartupSummary
+    where
+        ChainSpec: reth_chainspec::EthereumHardforks,
+    {
+        let network = &self.node_config().network;
+        let connections = &self.toml_config().peers.connection_info;
+        let rpc_modules = self.node_config().rpc.transport_rpc_module_config();
+        NodeStartupSummary {
+            chain: self.chain_spec().chain(),
+            prune_modes: self.prune_modes(),
+            http_api: rpc_modules.http().cloned(),
+            ws_api: rpc_modules.ws().cloned(),
+            exex_count,
+            max_outbound_peers: network
+                .resolved_max_outbound_peers()
+                .unwrap_or(connections.max_outbound),
+            max_inbound_peers: network
+                .resolved_max_inbound_peers()
+                .unwrap_or(connections.max_inbound),
+        }
+    }
+
     /// Returns the [`MiningMode`] intended for --dev mode.
     pub fn dev_mining_mode<Pool>(&self, pool: Pool) -> MiningMode<Pool>
     where
@@ -616,6 +665,26 @@ where
         self.right().static_file_provider()
     }
 
+    /// Runs [`Self::check_header_consistency`] unless it was disabled with
+    /// `--debug.skip-header-consistency-check`.
+    pub fn with_header_consistency_check(self) -> eyre::Result<Self> {
+        if self.node_config().debug.skip_header_consistency_check {
+            warn!(target: "reth::cli", "Skipping header consistency check between database and static files");
+        } else {
+            self.check_header_consistency()?;
+        }
+        Ok(self)
+    }
+
+    /// Checks that the highest header in static files matches what the database expects.
+    ///
+    /// Headers are stored in static files while their hash to number mapping is stored in the
+    /// database. After an unclean shutdown these can diverge, which leads to subtle errors later
+    /// on, so this fails early with a descriptive error instead.
+    pub fn check_header_consistency(&self) -> eyre::Result<()> {
+        check_header_consistency(self.provider_factory())
+    }
+
     /// This launches the prometheus endpoint.
     ///
     /// Convenience function to [`Self::start_prometheus_endpoint`]
@@ -624,6 +693,15 @@ where
         Ok(self)
     }
 
+    /// Spawns the tasks that forward metric snapshots to the given external sinks.
+    pub fn with_metrics_sinks(self, sinks: Vec<BoxedMetricsSink>) -> Self {
+        let interval = self.node_config().metrics.push_gateway_interval;
+        for sink in sinks {
+            sink.spawn(interval, self.task_executor());
+        }
+        self
+    }
+
     /// Starts the prometheus endpoint.
     pub async fn start_prometheus_endpoint(&self) -> eyre::Result<()> {
         // ensure recorder runs upkeep periodically
@@ -668,6 +746,17 @@ where
         init_genesis_with_settings(self.provider_factory(), self.node_config().storage_settings())
     }
 
+    /// Imports the headers of the given static file snapshot, if any.
+    ///
+    /// This must run after the genesis has been initialized, see
+    /// [`import_static_file_snapshot`].
+    pub fn with_static_file_snapshot(self, snapshot: Option<&Path>) -> eyre::Result<Self> {
+        if let Some(snapshot) = snapshot {
+            import_static_file_snapshot(self.provider_factory(), snapshot)?;
+        }
+        Ok(self)
+    }
+
     /// Creates a new `WithMeteredProvider` container and attaches it to the
     /// launch context.
     ///
@@ -785,6 +874,7 @@ where
         on_component_initialized: Box<
             dyn OnComponentInitializedHook<NodeAdapter<T, CB::Components>>,
         >,
+        pool_config_transform: Option<PoolConfigTransform>,
     ) -> eyre::Result<
         LaunchContextWith<
             Attached<WithConfigs<<T::Types as NodeTypes>::ChainSpec>, WithComponents<T, CB>>,
@@ -801,7 +891,9 @@ where
             self.blockchain_db().clone(),
             self.task_executor().clone(),
             self.configs().clone(),
-        );
+        )
+        .with_launch_phase(self.inner.launch_phase.clone())
+        .with_pool_config_transform(pool_config_transform);
 
         debug!(target: "reth::cli", "creating components");
         let components = components_builder.build_components(&builder_ctx).await?;
@@ -1268,11 +1360,54 @@ pub fn metrics_hooks<N: NodeTypesWithDB>(provider_factory: &ProviderFactory<N>)
         .build()
 }
 
+/// Checks that the highest header in static files is indexed by the database, see
+/// [`LaunchContextWith::check_header_consistency`].
+fn check_header_consistency<N: ProviderNodeTypes>(
+    factory: &ProviderFactory<N>,
+) -> eyre::Result<()> {
+    let Some(highest) =
+        factory.static_file_provider().get_highest_static_file_block(StaticFileSegment::Headers)
+    else {
+        // nothing written yet
+        return Ok(())
+    };
+
+    let Some(header) = factory.sealed_header(highest)? else {
+        eyre::bail!(
+            "Highest static file header #{highest} could not be read. The static files may be \
+             corrupted: run `reth db stats` to inspect them, and consider `reth stage unwind` or \
+             restoring the datadir from a backup."
+        )
+    };
+
+    let db_number = factory.block_number(header.hash())?;
+    if db_number != Some(highest) {
+        eyre::bail!(
+            "Header consistency check failed: highest static file header #{highest} ({}) maps to \
+             {db_number:?} in the database. The database and static files have diverged, likely \
+             due to an unclean shutdown. Unwind past the divergence with \
+             `reth stage unwind to-block <BLOCK>` or restore the datadir from a backup. The check \
+             can be disabled with `--debug.skip-header-consistency-check`.",
+            header.hash()
+        )
+    }
+
+    debug!(target: "reth::cli", highest, hash = %header.hash(), "Header consistency check passed");
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
-    use super::{LaunchContext, NodeConfig};
+    use super::{check_header_consistency, LaunchContext, NodeConfig};
+    use alloy_consensus::Header;
     use reth_config::Config;
+    use reth_db_common::init::init_genesis;
     use reth_node_core::args::PruningArgs;
+    use reth_primitives_traits::SealedHeader;
+    use reth_provider::{
+        test_utils::create_test_provider_factory, DatabaseProviderFactory,
+        StaticFileProviderFactory, StaticFileSegment, StaticFileWriter,
+    };
 
     const EXTENSION: &str = "toml";
 
@@ -1324,4 +1459,27 @@ mod tests {
             assert_eq!(reth_config, loaded_config);
         })
     }
+
+    #[test]
+    fn test_check_header_consistency() {
+        let factory = create_test_provider_factory();
+        init_genesis(&factory).unwrap();
+        check_header_consistency(&factory).unwrap();
+
+        // a header in static files that the database doesn't know about
+        let provider_rw = factory.database_provider_rw().unwrap();
+        let header = SealedHeader::seal_slow(Header { number: 1, ..Default::default() });
+        provider_rw
+            .static_file_provider()
+            .latest_writer(StaticFileSegment::Headers)
+            .unwrap()
+            .append_header(header.header(), &header.hash())
+            .unwrap();
+        provider_rw.commit().unwrap();
+
+        let err = check_header_consistency(&factory).unwrap_err();
+        assert!(err
+            .to_string()
+            .starts_with("Header consistency check failed: highest static file header #1"));
+    }
 }
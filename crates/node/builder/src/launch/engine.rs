@@ -4,13 +4,17 @@ use crate::{
     common::{Attached, LaunchContextWith, WithConfigs},
     hooks::NodeHooks,
     rpc::{EngineShutdown, EngineValidatorAddOn, EngineValidatorBuilder, RethRpcAddOns, RpcHandle},
-    setup::build_networked_pipeline,
-    AddOns, AddOnsContext, FullNode, LaunchContext, LaunchNode, NodeAdapter,
+    setup::{
+        build_networked_pipeline, ensure_skippable_stages, import_blocks_from_file,
+        replay_engine_calls,
+    },
+    AddOns, AddOnsContext, FullNode, LaunchContext, LaunchNode, LaunchPhase, NodeAdapter,
     NodeBuilderWithComponents, NodeComponents, NodeComponentsBuilder, NodeHandle, NodeTypesAdapter,
 };
 use alloy_consensus::BlockHeader;
 use futures::{stream::FusedStream, stream_select, FutureExt, StreamExt};
 use reth_chainspec::{EthChainSpec, EthereumHardforks};
+use reth_engine_primitives::PersistenceThresholdHandle;
 use reth_engine_tree::{
     chain::{ChainEvent, FromOrchestrator},
     engine::{EngineApiKind, EngineApiRequest, EngineRequestHandler},
@@ -19,7 +23,9 @@ use reth_engine_tree::{
 };
 use reth_engine_util::EngineMessageStreamExt;
 use reth_exex::ExExManagerHandle;
-use reth_network::{types::BlockRangeUpdate, NetworkSyncUpdater, SyncState};
+use reth_network::{
+    discovery_source::feed_discovery_source, types::BlockRangeUpdate, NetworkSyncUpdater, SyncState,
+};
 use reth_network_api::BlockDownloaderProvider;
 use reth_node_api::{
     BuiltPayload, ConsensusEngineHandle, FullNodeTypes, NodeTypes, NodeTypesWithDBAdapter,
@@ -27,7 +33,7 @@ use reth_node_api::{
 use reth_node_core::{
     dirs::{ChainPath, DataDirPath},
     exit::NodeExitFuture,
-    primitives::Head,
+    utils::SealedHeaderAsHead,
 };
 use reth_node_events::node;
 use reth_provider::{
@@ -36,7 +42,7 @@ use reth_provider::{
 };
 use reth_tasks::TaskExecutor;
 use reth_tokio_util::EventSender;
-use reth_tracing::tracing::{debug, error, info};
+use reth_tracing::tracing::{debug, error, info, warn};
 use reth_trie_db::ChangesetCache;
 use std::{future::Future, pin::Pin, sync::Arc};
 use tokio::sync::{mpsc::unbounded_channel, oneshot};
@@ -55,7 +61,7 @@ pub struct EngineNodeLauncher {
 
 impl EngineNodeLauncher {
     /// Create a new instance of the ethereum node launcher.
-    pub const fn new(
+    pub fn new(
         task_executor: TaskExecutor,
         data_dir: ChainPath<DataDirPath>,
         engine_tree_config: TreeConfig,
@@ -79,14 +85,34 @@ impl EngineNodeLauncher {
             + EngineValidatorAddOn<NodeAdapter<T, CB::Components>>,
     {
         let Self { ctx, engine_tree_config } = self;
+        let launch_phase = ctx.launch_phase.clone();
         let NodeBuilderWithComponents {
             adapter: NodeTypesAdapter { database },
             rocksdb_provider,
             components_builder,
-            add_ons: AddOns { hooks, exexs: installed_exex, add_ons },
+            add_ons:
+                AddOns {
+                    hooks,
+                    exexs: installed_exex,
+                    exex_priorities,
+                    metrics_sinks,
+                    discovery_sources,
+                    skipped_stages,
+                    static_file_snapshot,
+                    block_import_file,
+                    engine_replay,
+                    prune_filter,
+                    pool_config_transform,
+                    add_ons,
+                },
             config,
         } = target;
-        let NodeHooks { on_component_initialized, on_node_started, .. } = hooks;
+        let NodeHooks {
+            on_component_initialized,
+            on_node_started,
+            on_node_started_async,
+            on_shutdown,
+        } = hooks;
 
         // Create changeset cache that will be shared across the engine
         let changeset_cache = ChangesetCache::new();
@@ -102,16 +128,22 @@ impl EngineNodeLauncher {
             .attach(database.clone())
             // ensure certain settings take effect
             .with_adjusted_configs()
+            // fail early if the engine API JWT secret is unusable
+            .with_auth_jwt_secret_check()?
             // Create the provider factory with changeset cache
             .with_provider_factory::<_, <CB::Components as NodeComponents<T>>::Evm>(changeset_cache.clone(), rocksdb_provider).await?
             .inspect(|_| {
                 info!(target: "reth::cli", "Database opened");
             })
+            .with_header_consistency_check()?
             .with_prometheus_server().await?
+            .with_metrics_sinks(metrics_sinks)
             .inspect(|this| {
                 debug!(target: "reth::cli", chain=%this.chain_id(), genesis=?this.genesis_hash(), "Initializing genesis");
             })
             .with_genesis()?
+            // import the static file snapshot, if any
+            .with_static_file_snapshot(static_file_snapshot.as_deref())?
             .inspect(|this: &LaunchContextWith<Attached<WithConfigs<<T::Types as NodeTypes>::ChainSpec>, _>>| {
                 info!(target: "reth::cli", "\n{}", this.chain_spec().display_hardforks());
                 let settings = this.provider_factory().cached_storage_settings();
@@ -123,18 +155,48 @@ impl EngineNodeLauncher {
             .with_blockchain_db::<T, _>(move |provider_factory| {
                 Ok(BlockchainProvider::new(provider_factory)?)
             })?
-            .with_components(components_builder, on_component_initialized).await?;
+            .inspect(|_| launch_phase.set(LaunchPhase::Components))
+            .with_components(components_builder, on_component_initialized, pool_config_transform).await?;
+
+        // run the shutdown hook, if any, once the graceful shutdown signal fires
+        if let Some(on_shutdown) = on_shutdown {
+            let node = ctx.node_adapter().clone();
+            ctx.task_executor().spawn_critical_with_graceful_shutdown_signal(
+                "node shutdown hook",
+                |shutdown| async move {
+                    let _guard = shutdown.await;
+                    if let Err(err) = on_shutdown.on_event(node).await {
+                        error!(target: "reth::cli", %err, "Node shutdown hook failed");
+                    }
+                },
+            );
+        }
 
         // spawn exexs if any
-        let maybe_exex_manager_handle = ctx.launch_exex(installed_exex).await?;
+        launch_phase.set(LaunchPhase::ExExs);
+        let exex_count = installed_exex.len();
+        let maybe_exex_manager_handle =
+            ctx.exex_launcher(installed_exex).with_priorities(exex_priorities).launch().await?;
 
         // create pipeline
+        launch_phase.set(LaunchPhase::Engine);
         let network_handle = ctx.components().network().clone();
+
+        // feed peers from custom discovery sources into the network
+        for source in discovery_sources {
+            ctx.task_executor().spawn_task(feed_discovery_source(source, network_handle.clone()));
+        }
+
         let network_client = network_handle.fetch_client().await?;
         let (consensus_engine_tx, consensus_engine_rx) = unbounded_channel();
 
         let node_config = ctx.node_config();
 
+        if !skipped_stages.is_empty() {
+            ensure_skippable_stages(&skipped_stages, node_config.dev.dev)?;
+            warn!(target: "reth::cli", ?skipped_stages, "Skipping pipeline stages, the node is incomplete and not suitable for production");
+        }
+
         // We always assume that node is syncing after a restart
         network_handle.update_sync_state(SyncState::Syncing);
 
@@ -159,6 +221,7 @@ impl EngineNodeLauncher {
             ctx.components().evm_config().clone(),
             maybe_exex_manager_handle.clone().unwrap_or_else(ExExManagerHandle::empty),
             ctx.era_import_source(),
+            &skipped_stages,
         )?;
 
         // The new engine writes directly to static files. This ensures that they're up to the tip.
@@ -167,6 +230,9 @@ impl EngineNodeLauncher {
         let pipeline_events = pipeline.events();
 
         let mut pruner_builder = ctx.pruner_builder();
+        if let Some(filter) = prune_filter {
+            pruner_builder = pruner_builder.filter(filter);
+        }
         if let Some(exex_manager_handle) = &maybe_exex_manager_handle {
             pruner_builder =
                 pruner_builder.finished_exex_height(exex_manager_handle.finished_height());
@@ -226,6 +292,9 @@ impl EngineNodeLauncher {
             EngineApiKind::Ethereum
         };
 
+        // Shared with the engine tree so the threshold can be adjusted at runtime
+        let persistence_threshold = PersistenceThresholdHandle::new(&engine_tree_config);
+
         let mut orchestrator = build_engine_orchestrator(
             engine_kind,
             consensus.clone(),
@@ -239,6 +308,7 @@ impl EngineNodeLauncher {
             ctx.components().payload_builder_handle().clone(),
             engine_validator,
             engine_tree_config,
+            persistence_threshold.clone(),
             ctx.sync_metrics_tx(),
             ctx.components().evm_config().clone(),
             changeset_cache,
@@ -265,6 +335,7 @@ impl EngineNodeLauncher {
             ),
         );
 
+        launch_phase.set(LaunchPhase::Rpc);
         let RpcHandle {
             rpc_server_handles,
             rpc_registry,
@@ -336,15 +407,11 @@ impl EngineNodeLauncher {
                                 if let Some(head) = ev.canonical_header() {
                                     // Once we're progressing via live sync, we can consider the node is not syncing anymore
                                     network_handle.update_sync_state(SyncState::Idle);
-                                    let head_block = Head {
-                                        number: head.number(),
-                                        hash: head.hash(),
-                                        difficulty: head.difficulty(),
-                                        timestamp: head.timestamp(),
-                                        total_difficulty: chainspec.final_paris_total_difficulty()
+                                    let head_block = head.as_head(
+                                        chainspec.final_paris_total_difficulty()
                                             .filter(|_| chainspec.is_paris_active_at_block(head.number()))
                                             .unwrap_or_default(),
-                                    };
+                                    );
                                     network_handle.update_status(head_block);
 
                                     let updated = BlockRangeUpdate {
@@ -392,8 +459,48 @@ impl EngineNodeLauncher {
         ctx.task_executor()
             .spawn_critical_with_graceful_shutdown_signal("consensus engine", consensus_engine);
 
+        if let Some(path) = block_import_file {
+            import_blocks_from_file::<T::Types, _>(
+                &path,
+                consensus,
+                ctx.blockchain_db(),
+                &beacon_engine_handle,
+            )
+            .await?;
+        }
+
+        if let Some(path) = engine_replay {
+            let engine = beacon_engine_handle.clone();
+            ctx.task_executor().spawn_task(async move {
+                match replay_engine_calls::<T::Types>(&path, &engine).await {
+                    Ok(0) => {
+                        info!(target: "reth::cli", path = %path.display(), "Engine API replay matched the recorded responses")
+                    }
+                    Ok(divergences) => {
+                        error!(target: "reth::cli", divergences, path = %path.display(), "Engine API replay diverged from the recorded responses")
+                    }
+                    Err(err) => {
+                        error!(target: "reth::cli", %err, path = %path.display(), "Engine API replay failed")
+                    }
+                }
+            });
+        }
+
         let engine_events_for_ethstats = engine_events.new_listener();
 
+        let startup_summary = ctx.startup_summary(exex_count);
+        info!(
+            target: "reth::cli",
+            chain = %startup_summary.chain,
+            prune_modes = ?startup_summary.prune_modes,
+            http_api = ?startup_summary.http_api,
+            ws_api = ?startup_summary.ws_api,
+            exex_count = startup_summary.exex_count,
+            max_outbound_peers = startup_summary.max_outbound_peers,
+            max_inbound_peers = startup_summary.max_inbound_peers,
+            "Node startup summary"
+        );
+
         let full_node = FullNode {
             evm_config: ctx.components().evm_config().clone(),
             pool: ctx.components().pool().clone(),
@@ -410,9 +517,13 @@ impl EngineNodeLauncher {
                 beacon_engine_handle,
                 engine_shutdown,
             },
+            persistence_threshold,
+            startup_summary: Arc::new(startup_summary),
         };
         // Notify on node started
+        launch_phase.set(LaunchPhase::NodeStartedHooks);
         on_node_started.on_event(FullNode::clone(&full_node))?;
+        on_node_started_async.on_event(FullNode::clone(&full_node)).await?;
 
         ctx.spawn_ethstats(engine_events_for_ethstats).await?;
 
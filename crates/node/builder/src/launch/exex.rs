@@ -10,9 +10,16 @@ use reth_exex::{
 };
 use reth_node_api::{FullNodeComponents, NodeTypes, PrimitivesTy};
 use reth_provider::CanonStateSubscriptions;
+use reth_tasks::TaskExecutor;
 use reth_tracing::tracing::{debug, info};
-use std::{fmt, fmt::Debug};
-use tracing::Instrument;
+use std::{
+    collections::{BTreeMap, HashMap},
+    fmt,
+    fmt::Debug,
+    future::Future,
+};
+use tokio::{sync::oneshot, task::JoinHandle};
+use tracing::{Instrument, Span};
 
 use crate::{common::WithConfigs, exex::BoxedLaunchExEx};
 
@@ -26,6 +33,8 @@ pub struct ExExLauncher<Node: FullNodeComponents> {
     wal_blocks_warning: usize,
     /// The max notification buffer capacity for the ExEx manager.
     capacity: usize,
+    /// The priorities of the extensions, keyed by `ExEx` ID.
+    priorities: HashMap<String, i32>,
 }
 
 impl<Node: FullNodeComponents + Clone> ExExLauncher<Node> {
@@ -43,9 +52,21 @@ impl<Node: FullNodeComponents + Clone> ExExLauncher<Node> {
             config_container,
             wal_blocks_warning: DEFAULT_WAL_BLOCKS_WARNING,
             capacity: DEFAULT_EXEX_MANAGER_CAPACITY,
+            priorities: HashMap::new(),
         }
     }
 
+    /// Sets the priorities of the extensions, keyed by `ExEx` ID.
+    ///
+    /// Each notification is dispatched to the extensions in ascending priority order, extensions
+    /// with the same priority in the order they were added. On graceful shutdown, extensions are
+    /// torn down in ascending priority order, extensions with the same priority are torn down
+    /// concurrently. Extensions without a priority default to `0`.
+    pub fn with_priorities(mut self, priorities: HashMap<String, i32>) -> Self {
+        self.priorities = priorities;
+        self
+    }
+
     /// Sets the threshold for the number of blocks in the WAL before emitting a warning.
     ///
     /// For L2 chains with faster block times, this value should be increased proportionally
@@ -69,8 +90,15 @@ impl<Node: FullNodeComponents + Clone> ExExLauncher<Node> {
     pub async fn launch(
         self,
     ) -> eyre::Result<Option<ExExManagerHandle<PrimitivesTy<Node::Types>>>> {
-        let Self { head, extensions, components, config_container, wal_blocks_warning, capacity } =
-            self;
+        let Self {
+            head,
+            mut extensions,
+            components,
+            config_container,
+            wal_blocks_warning,
+            capacity,
+            priorities,
+        } = self;
         let head = BlockNumHash::new(head.number, head.hash);
 
         if extensions.is_empty() {
@@ -88,10 +116,17 @@ impl<Node: FullNodeComponents + Clone> ExExLauncher<Node> {
                 .exex_wal(),
         )?;
 
+        // the manager dispatches notifications in the order of the handles
+        sort_by_priority(&mut extensions, &priorities);
+
         let mut exex_handles = Vec::with_capacity(extensions.len());
         let mut exexes = Vec::with_capacity(extensions.len());
+        let mut teardowns = BTreeMap::<i32, Vec<_>>::new();
 
         for (id, exex) in extensions {
+            let priority = priorities.get(&id).copied().unwrap_or_default();
+            let (teardown_tx, teardown_rx) = oneshot::channel::<()>();
+
             // create a new exex handle
             let (handle, events, notifications) = ExExHandle::new(
                 id.clone(),
@@ -120,24 +155,15 @@ impl<Node: FullNodeComponents + Clone> ExExLauncher<Node> {
                 // init the exex
                 let exex = exex.launch(context).instrument(span.clone()).await?;
 
-                // spawn it as a crit task
-                executor.spawn_critical_task(
-                    "exex",
-                    async move {
-                        info!(target: "reth::cli", "ExEx started");
-                        match exex.await {
-                            Ok(_) => panic!("ExEx {id} finished. ExExes should run indefinitely"),
-                            Err(err) => panic!("ExEx {id} crashed: {err}"),
-                        }
-                    }
-                    .instrument(span),
-                );
+                let task = spawn_exex(&executor, id, exex, teardown_rx, span);
 
-                Ok::<(), eyre::Error>(())
+                Ok::<_, eyre::Error>((priority, teardown_tx, task))
             });
         }
 
-        future::try_join_all(exexes).await?;
+        for (priority, teardown_tx, task) in future::try_join_all(exexes).await? {
+            teardowns.entry(priority).or_default().push((teardown_tx, task));
+        }
 
         // spawn exex manager
         debug!(target: "reth::cli", "spawning exex manager");
@@ -150,31 +176,114 @@ impl<Node: FullNodeComponents + Clone> ExExLauncher<Node> {
         )
         .with_wal_blocks_warning(wal_blocks_warning);
         let exex_manager_handle = exex_manager.handle();
-        components.task_executor().spawn_critical_task("exex manager", async move {
-            exex_manager.await.expect("exex manager crashed");
-        });
+        let (manager_teardown_tx, manager_teardown_rx) = oneshot::channel::<()>();
+        // the manager keeps delivering notifications to the extensions until they are torn down
+        let manager_task = components.task_executor().spawn_critical_with_graceful_shutdown_signal(
+            "exex manager",
+            move |shutdown| async move {
+                let _shutdown = shutdown;
+                tokio::select! {
+                    res = exex_manager => res.expect("exex manager crashed"),
+                    _ = manager_teardown_rx => {}
+                }
+            },
+        );
 
         // send notifications from the blockchain tree to exex manager
         let mut canon_state_notifications = components.provider().subscribe_to_canonical_state();
         let mut handle = exex_manager_handle.clone();
-        components.task_executor().spawn_critical_task(
+        let (notifications_teardown_tx, notifications_teardown_rx) = oneshot::channel::<()>();
+        let notifications_task = components.task_executor().spawn_critical_task(
             "exex manager blockchain tree notifications",
             async move {
-                while let Ok(notification) = canon_state_notifications.recv().await {
-                    handle
-                        .send_async(ExExNotificationSource::BlockchainTree, notification.into())
-                        .await
-                        .expect("blockchain tree notification could not be sent to exex manager");
+                let forward = async move {
+                    while let Ok(notification) = canon_state_notifications.recv().await {
+                        handle
+                            .send_async(ExExNotificationSource::BlockchainTree, notification.into())
+                            .await
+                            .expect(
+                                "blockchain tree notification could not be sent to exex manager",
+                            );
+                    }
+                };
+                tokio::select! {
+                    _ = forward => {}
+                    _ = notifications_teardown_rx => {}
                 }
             },
         );
 
+        // on graceful shutdown, stop forwarding notifications, tear down the extensions in
+        // ascending priority order and then the manager
+        components.task_executor().spawn_critical_with_graceful_shutdown_signal(
+            "exex shutdown",
+            |shutdown| async move {
+                let _guard = shutdown.await;
+
+                let _ = notifications_teardown_tx.send(());
+                let _ = notifications_task.await;
+                tear_down_exexs(teardowns).await;
+                let _ = manager_teardown_tx.send(());
+                let _ = manager_task.await;
+            },
+        );
+
         info!(target: "reth::cli", "ExEx Manager started");
 
         Ok(Some(exex_manager_handle))
     }
 }
 
+/// Sorts the extensions by ascending priority, extensions without a priority default to `0`.
+///
+/// The sort is stable, so extensions with the same priority keep the order they were added in.
+fn sort_by_priority<T>(extensions: &mut [(String, T)], priorities: &HashMap<String, i32>) {
+    extensions.sort_by_key(|(id, _)| priorities.get(id).copied().unwrap_or_default());
+}
+
+/// Spawns the given `ExEx` as a critical task that runs until `teardown_rx` fires.
+///
+/// The task is not cancelled by the shutdown signal, so that the extensions can be torn down in
+/// priority order, see [`tear_down_exexs`]. The graceful shutdown of the node waits for the task.
+fn spawn_exex<F>(
+    executor: &TaskExecutor,
+    id: String,
+    exex: F,
+    teardown_rx: oneshot::Receiver<()>,
+    span: Span,
+) -> JoinHandle<()>
+where
+    F: Future<Output = eyre::Result<()>> + Send + 'static,
+{
+    executor.spawn_critical_with_graceful_shutdown_signal("exex", move |shutdown| {
+        async move {
+            let _shutdown = shutdown;
+            info!(target: "reth::cli", "ExEx started");
+            tokio::select! {
+                res = exex => match res {
+                    Ok(_) => panic!("ExEx {id} finished. ExExes should run indefinitely"),
+                    Err(err) => panic!("ExEx {id} crashed: {err}"),
+                },
+                _ = teardown_rx => info!(target: "reth::cli", "ExEx shut down"),
+            }
+        }
+        .instrument(span)
+    })
+}
+
+/// Tears down the extensions in ascending priority order, extensions with the same priority are
+/// torn down concurrently.
+async fn tear_down_exexs(teardowns: BTreeMap<i32, Vec<(oneshot::Sender<()>, JoinHandle<()>)>>) {
+    for (priority, exexes) in teardowns {
+        debug!(target: "reth::cli", priority, "Shutting down ExExes");
+        let tasks = exexes.into_iter().map(|(teardown_tx, task)| {
+            let _ = teardown_tx.send(());
+            task
+        });
+        future::join_all(tasks).await;
+    }
+}
+
 impl<Node: FullNodeComponents> Debug for ExExLauncher<Node> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("ExExLauncher")
@@ -183,6 +292,71 @@ impl<Node: FullNodeComponents> Debug for ExExLauncher<Node> {
             .field("components", &"...")
             .field("config_container", &self.config_container)
             .field("wal_blocks_warning", &self.wal_blocks_warning)
+            .field("priorities", &self.priorities)
             .finish()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{
+        sync::{Arc, Mutex},
+        time::Duration,
+    };
+
+    /// Records the ID of an `ExEx` when it's dropped.
+    struct RecordOnDrop(&'static str, Arc<Mutex<Vec<&'static str>>>);
+
+    impl Drop for RecordOnDrop {
+        fn drop(&mut self) {
+            self.1.lock().unwrap().push(self.0);
+        }
+    }
+
+    #[test]
+    fn exexs_are_torn_down_in_ascending_priority_order() {
+        let executor = TaskExecutor::test();
+        let torn_down = Arc::new(Mutex::new(Vec::new()));
+
+        let mut teardowns = BTreeMap::<i32, Vec<_>>::new();
+        for (id, priority) in [("low", -1), ("default", 0), ("high", 1)] {
+            let (teardown_tx, teardown_rx) = oneshot::channel();
+            let record = RecordOnDrop(id, Arc::clone(&torn_down));
+            let exex = async move {
+                let _record = record;
+                future::pending::<eyre::Result<()>>().await
+            };
+            let task = spawn_exex(&executor, id.to_string(), exex, teardown_rx, Span::none());
+            teardowns.entry(priority).or_default().push((teardown_tx, task));
+        }
+
+        executor.spawn_critical_with_graceful_shutdown_signal(
+            "exex shutdown",
+            |shutdown| async move {
+                let _guard = shutdown.await;
+                tear_down_exexs(teardowns).await;
+            },
+        );
+
+        std::thread::sleep(Duration::from_millis(100));
+        assert!(torn_down.lock().unwrap().is_empty());
+
+        // the shutdown signal doesn't cancel the extensions, they are torn down one by one
+        executor.graceful_shutdown();
+        assert_eq!(*torn_down.lock().unwrap(), ["low", "default", "high"]);
+    }
+
+    #[test]
+    fn exexs_are_sorted_by_ascending_priority() {
+        let mut extensions = ["b", "high", "a", "low", "c"].map(|id| (id.to_string(), ())).to_vec();
+        let priorities =
+            HashMap::from([("high".to_string(), 1), ("low".to_string(), -1), ("a".to_string(), 0)]);
+
+        sort_by_priority(&mut extensions, &priorities);
+
+        // ties keep the order the extensions were added in
+        let ids = extensions.iter().map(|(id, _)| id.as_str()).collect::<Vec<_>>();
+        assert_eq!(ids, ["low", "b", "a", "c", "high"]);
+    }
+}
@@ -10,7 +10,8 @@ pub(crate) mod engine;
 pub use common::LaunchContext;
 pub use exex::ExExLauncher;
 
-use std::future::IntoFuture;
+use parking_lot::Mutex;
+use std::{fmt, future::IntoFuture, sync::Arc};
 
 /// A general purpose trait that launches a new node of any kind.
 ///
@@ -44,3 +45,71 @@ where
         self(target)
     }
 }
+
+/// The phases a node goes through while it is being launched.
+///
+/// See also [`WithLaunchContext::launch_with_timeout`](crate::WithLaunchContext::launch_with_timeout)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum LaunchPhase {
+    /// Opening the database and initializing genesis.
+    #[default]
+    Database,
+    /// Building the node components.
+    Components,
+    /// Starting the network, this happens while the components are built.
+    Network,
+    /// Launching the installed `ExEx`es.
+    ExExs,
+    /// Setting up the pipeline and the consensus engine.
+    Engine,
+    /// Launching the add-ons, this includes starting the RPC servers.
+    Rpc,
+    /// Running the node started hooks.
+    NodeStartedHooks,
+}
+
+impl fmt::Display for LaunchPhase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let phase = match self {
+            Self::Database => "database",
+            Self::Components => "components init",
+            Self::Network => "network start",
+            Self::ExExs => "exex launch",
+            Self::Engine => "engine setup",
+            Self::Rpc => "rpc start",
+            Self::NodeStartedHooks => "node started hooks",
+        };
+        f.write_str(phase)
+    }
+}
+
+/// Shared handle that keeps track of the [`LaunchPhase`] the node launch is currently in.
+#[derive(Debug, Clone, Default)]
+pub struct LaunchPhaseTracker(Arc<Mutex<LaunchPhase>>);
+
+impl LaunchPhaseTracker {
+    /// Records that the launch entered the given phase.
+    pub fn set(&self, phase: LaunchPhase) {
+        *self.0.lock() = phase;
+    }
+
+    /// Returns the phase the launch is currently in.
+    pub fn get(&self) -> LaunchPhase {
+        *self.0.lock()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn launch_phase_tracker() {
+        let tracker = LaunchPhaseTracker::default();
+        assert_eq!(tracker.get(), LaunchPhase::Database);
+
+        tracker.clone().set(LaunchPhase::Network);
+        assert_eq!(tracker.get(), LaunchPhase::Network);
+        assert_eq!(tracker.get().to_string(), "network start");
+    }
+}
@@ -6,20 +6,39 @@ use crate::{
     components::NodeComponentsBuilder, rpc::RethRpcAddOns, NodeAdapter, NodeAddOns, NodeHandle,
     RethFullAdapter,
 };
-use reth_node_api::{EngineTypes, FullNodeComponents, PayloadTypes};
+use alloy_consensus::BlockHeader;
+use alloy_eips::BlockId;
+use alloy_primitives::{BlockNumber, Keccak256, B256};
+use alloy_rpc_types_engine::ForkchoiceState;
+use futures::StreamExt;
+use reth_chainspec::{Chain, EthChainSpec};
+use reth_engine_primitives::{PersistenceThresholdError, PersistenceThresholdHandle};
+use reth_node_api::{BlockTy, EngineTypes, ForkChainInfo, FullNodeComponents, PayloadTypes};
 use reth_node_core::{
     dirs::{ChainPath, DataDirPath},
     node_config::NodeConfig,
 };
 use reth_payload_builder::PayloadBuilderHandle;
-use reth_provider::ChainSpecProvider;
+use reth_primitives_traits::SealedBlock;
+use reth_provider::{
+    providers::{BlockchainProvider, ProviderNodeTypes},
+    BlockHashReader, BlockReader, BlockReaderIdExt, CanonStateSubscriptions, ChainSpecProvider,
+    ProviderError, ProviderResult, StageCheckpointReader, TransactionVariant,
+};
+use reth_prune::PruneModes;
 use reth_rpc_api::EngineApiClient;
-use reth_rpc_builder::{auth::AuthServerHandle, RpcServerHandle};
+use reth_rpc_builder::{auth::AuthServerHandle, RpcModuleSelection, RpcServerHandle};
+use reth_stages::StageId;
 use reth_tasks::TaskExecutor;
+use reth_transaction_pool::{
+    maintain::{restore_pool, revalidate_pool, snapshot_pool, RevalidationReport},
+    PoolStatus, TransactionPool, TransactionPoolExt,
+};
 use std::{
     fmt::Debug,
     marker::PhantomData,
-    ops::{Deref, DerefMut},
+    ops::{Deref, DerefMut, RangeInclusive},
+    path::PathBuf,
     sync::Arc,
 };
 
@@ -46,6 +65,36 @@ pub trait Node<N: FullNodeTypes>: NodeTypes + Clone {
     fn add_ons(&self) -> Self::AddOns;
 }
 
+/// The fees of the next block, see [`FullNode::next_block_fees`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct NextBlockFees {
+    /// The EIP-1559 base fee per gas, `None` before London.
+    pub base_fee: Option<u64>,
+    /// The EIP-4844 base fee per blob gas, `None` before Cancun.
+    pub blob_base_fee: Option<u128>,
+}
+
+/// Summary of the node's configuration, logged once the node is launched.
+///
+/// See [`FullNode::startup_summary`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NodeStartupSummary {
+    /// The chain the node is running.
+    pub chain: Chain,
+    /// The configured prune modes, all unset for archive nodes.
+    pub prune_modes: PruneModes,
+    /// The RPC namespaces served over HTTP, `None` if the HTTP server is disabled.
+    pub http_api: Option<RpcModuleSelection>,
+    /// The RPC namespaces served over WS, `None` if the WS server is disabled.
+    pub ws_api: Option<RpcModuleSelection>,
+    /// The number of installed ExExes.
+    pub exex_count: usize,
+    /// The maximum number of outbound peers.
+    pub max_outbound_peers: usize,
+    /// The maximum number of inbound peers.
+    pub max_inbound_peers: usize,
+}
+
 /// A [`Node`] type builder
 #[derive(Clone, Default, Debug)]
 pub struct AnyNode<N = (), C = (), AO = ()>(PhantomData<N>, C, AO);
@@ -123,6 +172,10 @@ pub struct FullNode<Node: FullNodeComponents, AddOns: NodeAddOns<Node>> {
     pub data_dir: ChainPath<DataDirPath>,
     /// The handle to launched add-ons
     pub add_ons_handle: AddOns::Handle,
+    /// Handle to adjust the engine tree's persistence threshold at runtime.
+    pub persistence_threshold: PersistenceThresholdHandle,
+    /// Summary of the node's configuration at launch.
+    pub startup_summary: Arc<NodeStartupSummary>,
 }
 
 impl<Node: FullNodeComponents, AddOns: NodeAddOns<Node>> Clone for FullNode<Node, AddOns> {
@@ -137,6 +190,8 @@ impl<Node: FullNodeComponents, AddOns: NodeAddOns<Node>> Clone for FullNode<Node
             config: self.config.clone(),
             data_dir: self.data_dir.clone(),
             add_ons_handle: self.add_ons_handle.clone(),
+            persistence_threshold: self.persistence_threshold.clone(),
+            startup_summary: self.startup_summary.clone(),
         }
     }
 }
@@ -153,6 +208,259 @@ where
     }
 }
 
+impl<Node, AddOns> FullNode<Node, AddOns>
+where
+    Node: FullNodeComponents,
+    AddOns: NodeAddOns<Node>,
+{
+    /// Returns a future that resolves to the new head block of the next canonical chain update.
+    ///
+    /// The node is subscribed to canonical state notifications when this is called, so the
+    /// returned future also resolves for updates that happen before it is first polled.
+    ///
+    /// The future resolves to an error if the node shuts down before the next canonical update.
+    pub fn next_canonical_block(
+        &self,
+    ) -> impl Future<Output = ProviderResult<SealedBlock<BlockTy<Node::Types>>>> + use<Node, AddOns>
+    {
+        let mut stream = self.provider.canonical_state_stream();
+        async move {
+            loop {
+                let Some(notification) = stream.next().await else {
+                    return Err(ProviderError::other(std::io::Error::other(
+                        "canonical state notification stream closed",
+                    )))
+                };
+                if let Some(tip) = notification.tip_checked() {
+                    return Ok(tip.clone_sealed_block())
+                }
+            }
+        }
+    }
+
+    /// Returns a future that resolves to the canonical block with the given number once the
+    /// canonical chain reaches it.
+    ///
+    /// If the block is already canonical, the future resolves immediately. Otherwise the canonical
+    /// block at that height is read again after every canonical chain update, so the block that
+    /// is returned is the one that is canonical after any reorgs.
+    ///
+    /// The future resolves to an error if the node shuts down before the block becomes canonical.
+    pub fn await_block(
+        &self,
+        number: BlockNumber,
+    ) -> impl Future<Output = ProviderResult<SealedBlock<BlockTy<Node::Types>>>> + use<Node, AddOns>
+    {
+        let provider = self.provider.clone();
+        // subscribe before the first read so that no update is missed
+        let mut stream = provider.canonical_state_stream();
+        async move {
+            loop {
+                if let Some(block) =
+                    provider.recovered_block(number.into(), TransactionVariant::NoHash)?
+                {
+                    return Ok(block.into_sealed_block())
+                }
+                if stream.next().await.is_none() {
+                    return Err(ProviderError::other(std::io::Error::other(
+                        "canonical state notification stream closed",
+                    )))
+                }
+            }
+        }
+    }
+
+    /// Returns the summary of the node's configuration that was logged at launch.
+    pub fn startup_summary(&self) -> &NodeStartupSummary {
+        &self.startup_summary
+    }
+
+    /// Returns the utilization of the transaction pool's sub-pools against their configured
+    /// limits.
+    pub fn pool_status(&self) -> PoolStatus {
+        self.pool.pool_status()
+    }
+
+    /// Writes all transactions currently in the pool to the file at `path`.
+    ///
+    /// This uses the same format as the local transactions backup written on shutdown, see
+    /// [`snapshot_pool`].
+    pub fn snapshot_pool_to(
+        &self,
+        path: PathBuf,
+    ) -> impl Future<Output = eyre::Result<()>> + use<Node, AddOns> {
+        let pool = self.pool.clone();
+        let task_executor = self.task_executor.clone();
+        async move {
+            snapshot_pool(&pool, &path, task_executor).await?;
+            Ok(())
+        }
+    }
+
+    /// Inserts the transactions of a snapshot written by [`Self::snapshot_pool_to`] into the
+    /// pool and returns how many were inserted.
+    ///
+    /// The transactions are re-validated against the current state, so transactions that became
+    /// invalid since the snapshot was taken are skipped, see [`restore_pool`].
+    pub fn restore_pool_from(
+        &self,
+        path: PathBuf,
+    ) -> impl Future<Output = eyre::Result<usize>> + use<Node, AddOns> {
+        let pool = self.pool.clone();
+        let task_executor = self.task_executor.clone();
+        async move { Ok(restore_pool(&pool, &path, task_executor).await?) }
+    }
+
+    /// Returns the EIP-1559 base fee and EIP-4844 blob base fee of the next block with the given
+    /// timestamp, derived from the current head and the chain spec.
+    ///
+    /// The fee parameters are the ones active at `timestamp`, so forks that change them are
+    /// accounted for when the next block activates them. A fee is `None` if the corresponding fork
+    /// is not active at the head.
+    pub fn next_block_fees(&self, timestamp: u64) -> ProviderResult<NextBlockFees> {
+        let head = self.provider.latest_header()?.ok_or(ProviderError::BestBlockNotFound)?;
+        let chain_spec = self.provider.chain_spec();
+        Ok(NextBlockFees {
+            base_fee: chain_spec.next_block_base_fee(head.header(), timestamp),
+            blob_base_fee: head
+                .maybe_next_block_blob_fee(chain_spec.blob_params_at_timestamp(timestamp)),
+        })
+    }
+
+    /// Returns the state root at the given block, which uniquely identifies the state.
+    pub fn state_fingerprint(&self, block: BlockId) -> eyre::Result<B256> {
+        let header = self
+            .provider
+            .header_by_id(block)?
+            .ok_or_else(|| eyre::eyre!("block {block:?} not found"))?;
+        Ok(header.state_root())
+    }
+
+    /// Returns the keccak256 hash of the concatenated canonical block hashes in the given range.
+    ///
+    /// Two nodes agree on the canonical chain in that range if their fingerprints match. Fails if
+    /// any block of the range is not canonical.
+    pub fn chain_fingerprint(&self, range: RangeInclusive<BlockNumber>) -> eyre::Result<B256> {
+        let (start, end) = range.into_inner();
+        let end = end.saturating_add(1);
+        let hashes = self.provider.canonical_hashes_range(start, end)?;
+        let expected = end.saturating_sub(start);
+        eyre::ensure!(
+            hashes.len() as u64 == expected,
+            "only {} of {expected} blocks in range {start}..{end} are canonical",
+            hashes.len()
+        );
+
+        let mut hasher = Keccak256::new();
+        for hash in hashes {
+            hasher.update(hash);
+        }
+        Ok(hasher.finalize())
+    }
+
+    /// Returns the block number of the checkpoint of every stage of the pipeline, in pipeline
+    /// order.
+    ///
+    /// Stages that never ran report block `0`.
+    pub fn stage_checkpoints(&self) -> ProviderResult<Vec<(StageId, BlockNumber)>> {
+        StageId::ALL
+            .into_iter()
+            .map(|id| {
+                let checkpoint = self.provider.get_stage_checkpoint(id)?.unwrap_or_default();
+                Ok((id, checkpoint.block_number))
+            })
+            .collect()
+    }
+
+    /// Adjusts the engine tree's persistence threshold at runtime.
+    ///
+    /// This is the maximum number of canonical blocks kept only in memory before persistence is
+    /// triggered. The new value must be at least 1 and less than the configured persistence
+    /// backpressure threshold, and takes effect the next time the engine checks whether to
+    /// persist.
+    pub fn set_persistence_threshold(&self, blocks: u64) -> Result<(), PersistenceThresholdError> {
+        self.persistence_threshold.set(blocks)
+    }
+}
+
+impl<N, Node, AddOns> FullNode<Node, AddOns>
+where
+    N: ProviderNodeTypes,
+    Node: FullNodeComponents<Provider = BlockchainProvider<N>>,
+    AddOns: NodeAddOns<Node>,
+{
+    /// Returns the number of canonical blocks the engine tree holds in memory that are not
+    /// persisted yet.
+    pub fn buffered_block_count(&self) -> usize {
+        self.provider.canonical_in_memory_state().buffered_block_count()
+    }
+
+    /// Returns an approximate memory footprint, in bytes, of the canonical blocks the engine tree
+    /// holds in memory that are not persisted yet.
+    pub fn buffered_bytes_estimate(&self) -> usize {
+        self.provider.canonical_in_memory_state().buffered_bytes_estimate()
+    }
+
+    /// Returns the number of the highest persisted block and the number of the highest block held
+    /// in memory, i.e. the canonical head.
+    ///
+    /// The gap between the two is the persistence lag. It is bounded by the engine's persistence
+    /// threshold, see [`Self::set_persistence_threshold`], a gap well above the threshold means
+    /// persistence is falling behind.
+    pub fn persistence_status(&self) -> (BlockNumber, BlockNumber) {
+        let state = self.provider.canonical_in_memory_state();
+        let head = state.chain_info().best_number;
+        // Nothing was persisted since launch, so the block below the lowest in-memory block is the
+        // highest persisted one.
+        let persisted =
+            state.get_persisted_num_hash().map(|block| block.number).unwrap_or_else(|| {
+                state
+                    .canonical_chain()
+                    .last()
+                    .map_or(head, |block| block.number().saturating_sub(1))
+            });
+        (persisted, head)
+    }
+
+    /// Returns the timestamp of the current canonical head.
+    ///
+    /// This reads the cached head, which makes it cheap enough for liveness checks comparing the
+    /// tip against wall-clock time.
+    pub fn head_timestamp(&self) -> u64 {
+        self.provider.canonical_in_memory_state().get_canonical_timestamp()
+    }
+
+    /// Returns the head, safe and finalized block hashes as last set by the engine API.
+    ///
+    /// The safe and finalized hashes are zero if they were not set yet.
+    pub fn forkchoice_state(&self) -> ForkchoiceState {
+        let state = self.provider.canonical_in_memory_state();
+        ForkchoiceState {
+            head_block_hash: state.chain_info().best_hash,
+            safe_block_hash: state.get_safe_num_hash().map(|b| b.hash).unwrap_or_default(),
+            finalized_block_hash: state
+                .get_finalized_num_hash()
+                .map(|b| b.hash)
+                .unwrap_or_default(),
+        }
+    }
+}
+
+impl<Node, AddOns> FullNode<Node, AddOns>
+where
+    Node: FullNodeComponents<Pool: TransactionPoolExt>,
+    AddOns: NodeAddOns<Node>,
+{
+    /// Re-validates all transactions in the pool against the current state and reports how many
+    /// were dropped.
+    ///
+    /// The pool maintenance task only does this in response to new blocks, see
+    /// [`revalidate_pool`].
+    pub fn revalidate_pool(&self) -> impl Future<Output = RevalidationReport> + use<Node, AddOns> {
+        revalidate_pool(self.provider.clone(), self.pool.clone(), self.task_executor.clone())
+    }
+}
+
 impl<Payload, Node, AddOns> FullNode<Node, AddOns>
 where
     Payload: PayloadTypes,
@@ -168,6 +476,13 @@ where
     pub const fn auth_server_handle(&self) -> &AuthServerHandle {
         &self.add_ons_handle.rpc_server_handles.auth
     }
+
+    /// Returns the fork chains currently tracked by the engine tree.
+    ///
+    /// See [`ConsensusEngineHandle::tracked_forks`](reth_node_api::ConsensusEngineHandle::tracked_forks).
+    pub async fn tracked_forks(&self) -> Option<Vec<ForkChainInfo>> {
+        self.add_ons_handle.beacon_engine_handle.tracked_forks().await
+    }
 }
 
 impl<Engine, Node, AddOns> FullNode<Node, AddOns>
@@ -36,7 +36,10 @@ use reth_rpc::{
     eth::{core::EthRpcConverterFor, DevSigner, EthApiTypes, FullEthApiServer},
     AdminApi,
 };
-use reth_rpc_api::{eth::helpers::EthTransactions, IntoEngineApiRpcModule};
+use reth_rpc_api::{
+    eth::helpers::{EthFees, EthTransactions},
+    IntoEngineApiRpcModule,
+};
 use reth_rpc_builder::{
     auth::{AuthRpcModule, AuthServerHandle},
     config::RethRpcServerConfig,
@@ -45,12 +48,13 @@ use reth_rpc_builder::{
 use reth_rpc_engine_api::{capabilities::EngineCapabilities, EngineApi};
 use reth_rpc_eth_types::{cache::cache_new_blocks_task, EthConfig, EthStateCache};
 use reth_tokio_util::EventSender;
-use reth_tracing::tracing::{debug, info};
+use reth_tracing::tracing::{debug, error, info};
 use std::{
     fmt::{self, Debug},
     future::Future,
     ops::{Deref, DerefMut},
-    sync::Arc,
+    pin::Pin,
+    sync::{atomic::Ordering, Arc},
 };
 use tokio::sync::oneshot;
 
@@ -71,6 +75,8 @@ pub struct RpcHooks<Node: FullNodeComponents, EthApi> {
     pub on_rpc_started: Box<dyn OnRpcStarted<Node, EthApi>>,
     /// Hooks to run to configure RPC server API.
     pub extend_rpc_modules: Box<dyn ExtendRpcModules<Node, EthApi>>,
+    /// Hook to run once the node is shutting down, if any.
+    pub on_rpc_stopped: Option<Box<dyn OnRpcStopped>>,
 }
 
 impl<Node, EthApi> Default for RpcHooks<Node, EthApi>
@@ -79,7 +85,11 @@ where
     EthApi: EthApiTypes,
 {
     fn default() -> Self {
-        Self { on_rpc_started: Box::<()>::default(), extend_rpc_modules: Box::<()>::default() }
+        Self {
+            on_rpc_started: Box::<()>::default(),
+            extend_rpc_modules: Box::<()>::default(),
+            on_rpc_stopped: None,
+        }
     }
 }
 
@@ -107,6 +117,15 @@ where
         self
     }
 
+    /// Sets the hook that is run once the node is shutting down.
+    pub(crate) fn set_on_rpc_stopped<F>(&mut self, hook: F) -> &mut Self
+    where
+        F: OnRpcStopped + 'static,
+    {
+        self.on_rpc_stopped = Some(Box::new(hook));
+        self
+    }
+
     /// Sets the hook that is run to configure the rpc modules.
     pub(crate) fn set_extend_rpc_modules<F>(&mut self, hook: F) -> &mut Self
     where
@@ -136,6 +155,7 @@ where
         f.debug_struct("RpcHooks")
             .field("on_rpc_started", &"...")
             .field("extend_rpc_modules", &"...")
+            .field("on_rpc_stopped", &self.on_rpc_stopped.as_ref().map(|_| "..."))
             .finish()
     }
 }
@@ -179,6 +199,31 @@ where
     }
 }
 
+/// Event hook that is called once the rpc servers have stopped during the graceful shutdown.
+///
+/// The hook receives the [`RethRpcServerHandles`] of the stopped servers. It is run as part of the
+/// graceful shutdown, so the task executor waits for the returned future before it shuts down.
+pub trait OnRpcStopped: Send {
+    /// The hook that is called once the rpc servers have stopped.
+    fn on_rpc_stopped(
+        self: Box<Self>,
+        handles: RethRpcServerHandles,
+    ) -> Pin<Box<dyn Future<Output = eyre::Result<()>> + Send>>;
+}
+
+impl<F, Fut> OnRpcStopped for F
+where
+    F: FnOnce(RethRpcServerHandles) -> Fut + Send,
+    Fut: Future<Output = eyre::Result<()>> + Send + 'static,
+{
+    fn on_rpc_stopped(
+        self: Box<Self>,
+        handles: RethRpcServerHandles,
+    ) -> Pin<Box<dyn Future<Output = eyre::Result<()>> + Send>> {
+        Box::pin((*self)(handles))
+    }
+}
+
 /// Event hook that is called when the rpc server is started.
 pub trait ExtendRpcModules<Node: FullNodeComponents, EthApi: EthApiTypes>: Send {
     /// The hook that is called once the rpc server is started.
@@ -312,6 +357,24 @@ where
         self.node.pool()
     }
 
+    /// Configures whether raw transactions submitted through the node's RPC are added to the pool
+    /// as local transactions.
+    ///
+    /// Unlike `--txpool.locals`, which exempts specific sender addresses, this treats every
+    /// transaction received via `eth_sendRawTransaction` as local based on its origin.
+    ///
+    /// Returns an error if the configured eth API does not support this.
+    pub fn set_local_rpc_transactions(&self, local: bool) -> eyre::Result<()>
+    where
+        EthApi: EthTransactions,
+    {
+        let Some(flag) = self.registry.eth_api().local_rpc_transactions() else {
+            eyre::bail!("eth API does not support local RPC transactions")
+        };
+        flag.store(local, Ordering::Relaxed);
+        Ok(())
+    }
+
     /// Returns provider to interact with the node.
     pub fn provider(&self) -> &Node::Provider {
         self.node.provider()
@@ -418,6 +481,30 @@ impl<Node: FullNodeComponents, EthApi: EthApiTypes> RpcHandle<Node, EthApi> {
     }
 }
 
+impl<Node, EthApi> RpcHandle<Node, EthApi>
+where
+    Node: FullNodeComponents,
+    EthApi: EthFees,
+{
+    /// Returns a suggestion for the gas price of legacy transactions, in wei.
+    ///
+    /// This is the estimate `eth_gasPrice` returns, computed in-process without going through the
+    /// rpc server: the base fee of the latest block plus the suggested priority fee of the gas
+    /// price oracle. The oracle takes the configured percentile (`--gpo.percentile`, 60 by
+    /// default) of the tips paid in the most recent blocks (`--gpo.blocks`, 20 by default).
+    pub fn suggested_gas_price(
+        &self,
+    ) -> impl Future<Output = eyre::Result<u128>> + use<Node, EthApi> {
+        let eth_api = self.eth_api().clone();
+        async move {
+            let price = EthFees::gas_price(&eth_api)
+                .await
+                .map_err(|err| eyre::eyre!("failed to estimate gas price: {err}"))?;
+            Ok(u128::try_from(price)?)
+        }
+    }
+}
+
 /// Handle returned when only the regular RPC server (HTTP/WS/IPC) is launched.
 ///
 /// This handle provides access to the RPC server endpoints and registry, but does not
@@ -502,6 +589,7 @@ struct RpcSetupContext<'a, Node: FullNodeComponents, EthApi: EthApiTypes> {
     auth_config: reth_rpc_builder::auth::AuthServerConfig,
     registry: RpcRegistry<Node, EthApi>,
     on_rpc_started: Box<dyn OnRpcStarted<Node, EthApi>>,
+    on_rpc_stopped: Option<Box<dyn OnRpcStopped>>,
     engine_events: EventSender<ConsensusEngineEvent<<Node::Types as NodeTypes>::Primitives>>,
     engine_handle: ConsensusEngineHandle<<Node::Types as NodeTypes>::Payload>,
 }
@@ -901,6 +989,18 @@ where
         self
     }
 
+    /// Sets the hook that is run after the rpc servers have stopped during the graceful shutdown.
+    ///
+    /// See [`OnRpcStopped`].
+    pub fn on_rpc_stopped<F, Fut>(mut self, hook: F) -> Self
+    where
+        F: FnOnce(RethRpcServerHandles) -> Fut + Send + 'static,
+        Fut: Future<Output = eyre::Result<()>> + Send + 'static,
+    {
+        self.hooks.set_on_rpc_stopped(hook);
+        self
+    }
+
     /// Sets the hook that is run to configure the rpc modules.
     pub fn extend_rpc_modules<F>(mut self, hook: F) -> Self
     where
@@ -967,6 +1067,7 @@ where
             auth_config: _,
             mut registry,
             on_rpc_started,
+            on_rpc_stopped,
             engine_events,
             engine_handle,
         } = setup_ctx;
@@ -987,8 +1088,9 @@ where
             &node,
             config,
             on_rpc_started,
-            handles,
+            handles.clone(),
         )?;
+        Self::spawn_on_rpc_stopped(&node, on_rpc_stopped, handles);
 
         Ok(RpcServerOnlyHandle {
             rpc_server_handle,
@@ -1039,6 +1141,7 @@ where
             auth_config,
             mut registry,
             on_rpc_started,
+            on_rpc_stopped,
             engine_events,
             engine_handle,
         } = setup_ctx;
@@ -1077,6 +1180,7 @@ where
             on_rpc_started,
             handles.clone(),
         )?;
+        Self::spawn_on_rpc_stopped(&node, on_rpc_stopped, handles.clone());
 
         Ok(RpcHandle {
             rpc_server_handles: handles,
@@ -1158,7 +1262,7 @@ where
             auth_module: &mut auth_module,
         };
 
-        let RpcHooks { on_rpc_started, extend_rpc_modules } = hooks;
+        let RpcHooks { on_rpc_started, extend_rpc_modules, on_rpc_stopped } = hooks;
 
         ext(RpcModuleContainer {
             modules: ctx.modules,
@@ -1175,6 +1279,7 @@ where
             auth_config,
             registry,
             on_rpc_started,
+            on_rpc_stopped,
             engine_events,
             engine_handle: beacon_engine_handle,
         })
@@ -1193,6 +1298,9 @@ where
         if let Some(path) = handle.ipc_endpoint() {
             info!(target: "reth::cli", %path, "RPC IPC server started");
         }
+        if let Some(path) = handle.uds_endpoint() {
+            info!(target: "reth::cli", %path, "RPC Unix domain socket server started");
+        }
         if let Some(addr) = handle.http_local_addr() {
             info!(target: "reth::cli", url=%addr, "RPC HTTP server started");
         }
@@ -1235,6 +1343,31 @@ where
         on_rpc_started.on_rpc_started(ctx, handles)?;
         Ok(())
     }
+
+    /// Spawns a task that stops the rpc servers once the node is shutting down and then runs the
+    /// [`OnRpcStopped`] hook, if any.
+    fn spawn_on_rpc_stopped(
+        node: &N,
+        on_rpc_stopped: Option<Box<dyn OnRpcStopped>>,
+        handles: RethRpcServerHandles,
+    ) {
+        let Some(on_rpc_stopped) = on_rpc_stopped else { return };
+        node.task_executor().spawn_with_graceful_shutdown_signal(
+            "rpc stopped hook",
+            |shutdown| async move {
+                let _guard = shutdown.await;
+                // the servers may already be stopped if the handles were used to stop them
+                let _ = handles.rpc.clone().stop();
+                let _ = handles.auth.clone().stop();
+                handles.rpc.clone().stopped().await;
+                handles.auth.clone().stopped().await;
+
+                if let Err(err) = on_rpc_stopped.on_rpc_stopped(handles).await {
+                    error!(target: "reth::cli", %err, "RPC stopped hook failed");
+                }
+            },
+        );
+    }
 }
 
 impl<N, EthB, PVB, EB, EVB, RpcMiddleware, AuthHttpMiddleware> NodeAddOns<N>
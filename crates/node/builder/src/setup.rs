@@ -1,32 +1,325 @@
 //! Helpers for setting up parts of the node.
 
-use std::sync::Arc;
+use std::{path::Path, sync::Arc};
 
 use crate::BlockTy;
+use alloy_consensus::BlockHeader;
 use alloy_primitives::{BlockNumber, B256};
+use alloy_rpc_types_engine::{ForkchoiceState, PayloadStatus, PayloadStatusEnum};
 use reth_config::{config::StageConfig, PruneConfig};
-use reth_consensus::FullConsensus;
+use reth_consensus::{Consensus, FullConsensus};
+use reth_db::static_file::iter_static_files;
+use reth_db_api::{tables, transaction::DbTxMut};
 use reth_downloaders::{
-    bodies::bodies::BodiesDownloaderBuilder,
+    bodies::bodies::BodiesDownloaderBuilder, file_client::ChunkedFileReader,
     headers::reverse_headers::ReverseHeadersDownloaderBuilder,
 };
+use reth_engine_util::engine_store::{EngineMessageStore, StoredEngineApiMessage};
 use reth_evm::ConfigureEvm;
 use reth_exex::ExExManagerHandle;
 use reth_network_p2p::{
     bodies::downloader::BodyDownloader, headers::downloader::HeaderDownloader, BlockClient,
 };
-use reth_node_api::HeaderTy;
-use reth_provider::{providers::ProviderNodeTypes, ProviderFactory};
+use reth_node_api::{ConsensusEngineHandle, HeaderTy, NodeTypes, PayloadTypes};
+use reth_primitives_traits::SealedHeader;
+use reth_provider::{
+    providers::{ProviderNodeTypes, StaticFileProvider},
+    BlockHashReader, DBProvider, DatabaseProviderFactory, HeaderProvider, ProviderFactory,
+    StageCheckpointReader, StageCheckpointWriter, StaticFileProviderFactory, StaticFileSegment,
+};
 use reth_stages::{
     prelude::DefaultStages,
     stages::{EraImportSource, ExecutionStage},
-    Pipeline, StageSet,
+    Pipeline, StageCheckpoint, StageId, StageSet,
 };
 use reth_static_file::StaticFileProducer;
 use reth_tasks::TaskExecutor;
-use reth_tracing::tracing::debug;
+use reth_tracing::tracing::{debug, info, warn};
 use tokio::sync::watch;
 
+/// Stages the node can't follow the chain without, unless it runs in dev mode where blocks are
+/// produced locally.
+pub const LOAD_BEARING_STAGES: [StageId; 3] =
+    [StageId::Headers, StageId::Bodies, StageId::Execution];
+
+/// Ensures that none of the given stages is required to run the node in the requested mode.
+///
+/// See [`LOAD_BEARING_STAGES`].
+pub fn ensure_skippable_stages(skipped_stages: &[StageId], dev: bool) -> eyre::Result<()> {
+    if dev {
+        return Ok(())
+    }
+    if let Some(stage) = skipped_stages.iter().find(|stage| LOAD_BEARING_STAGES.contains(stage)) {
+        eyre::bail!("stage {stage} can only be skipped in dev mode")
+    }
+    Ok(())
+}
+
+/// Imports the RLP-encoded blocks of the given file into the running engine.
+///
+/// The file is decoded in chunks and every block is validated by the engine as a new payload,
+/// blocks that are already canonical are skipped. After each chunk the imported blocks are made
+/// canonical with a forkchoice update. The import stops at the first block the engine does not
+/// accept as valid.
+pub async fn import_blocks_from_file<N, P>(
+    path: &Path,
+    consensus: Arc<dyn Consensus<BlockTy<N>>>,
+    provider: &P,
+    engine: &ConsensusEngineHandle<N::Payload>,
+) -> eyre::Result<()>
+where
+    N: NodeTypes,
+    P: BlockHashReader,
+{
+    info!(target: "reth::cli", path = %path.display(), "Importing blocks from file");
+
+    let mut reader = ChunkedFileReader::new(path, None).await?;
+    let mut parent_header = None;
+    let (mut imported, mut skipped) = (0usize, 0usize);
+
+    while let Some(file_client) =
+        reader.next_chunk::<BlockTy<N>>(consensus.clone(), parent_header.take()).await?
+    {
+        parent_header = file_client.tip_header();
+
+        let mut head = None;
+        for block in file_client.into_blocks() {
+            let num_hash = block.num_hash();
+            if provider.block_hash(num_hash.number)? == Some(num_hash.hash) {
+                skipped += 1;
+                continue
+            }
+
+            let status = engine.new_payload(N::Payload::block_to_payload(block)).await?;
+            match status.status {
+                PayloadStatusEnum::Valid => {}
+                PayloadStatusEnum::Invalid { validation_error } => {
+                    eyre::bail!(
+                        "invalid block {} ({}) in import file: {validation_error}",
+                        num_hash.number,
+                        num_hash.hash
+                    )
+                }
+                status => eyre::bail!(
+                    "block {} ({}) in import file could not be validated: {status:?}",
+                    num_hash.number,
+                    num_hash.hash
+                ),
+            }
+            imported += 1;
+            head = Some(num_hash.hash);
+        }
+
+        if let Some(head) = head {
+            let state = ForkchoiceState {
+                head_block_hash: head,
+                safe_block_hash: B256::ZERO,
+                finalized_block_hash: B256::ZERO,
+            };
+            let updated = engine.fork_choice_updated(state, None).await?;
+            eyre::ensure!(
+                updated.payload_status.is_valid(),
+                "failed to make imported block {head} canonical: {:?}",
+                updated.payload_status.status
+            );
+        }
+        info!(target: "reth::cli", imported, skipped, "Imported chain file chunk");
+    }
+
+    info!(target: "reth::cli", imported, skipped, "Finished importing blocks from file");
+    Ok(())
+}
+
+/// Replays the engine API messages stored in the directory at `path` against the running engine.
+///
+/// The directory is expected to be written by an
+/// [`EngineMessageStore`], e.g. with `--debug.engine-api-store`. The messages are sent in the
+/// order they were received, each after the engine responded to the previous one. Every message
+/// with a recorded response is checked against the engine's response, see [`response_diverges`].
+/// Divergences are logged and don't stop the replay.
+///
+/// Returns the number of divergent responses.
+pub async fn replay_engine_calls<N: NodeTypes>(
+    path: &Path,
+    engine: &ConsensusEngineHandle<N::Payload>,
+) -> eyre::Result<usize> {
+    let messages = EngineMessageStore::new(path.to_path_buf()).engine_messages_iter()?;
+    info!(target: "reth::cli", path = %path.display(), "Replaying engine API messages");
+
+    let mut divergences = 0usize;
+    for message_path in messages {
+        let (message, recorded) = EngineMessageStore::read_message::<N::Payload>(&message_path)?;
+        let status = match message {
+            StoredEngineApiMessage::NewPayload { payload } => engine.new_payload(payload).await?,
+            StoredEngineApiMessage::ForkchoiceUpdated { state, payload_attrs } => {
+                // recorded forkchoice updated responses only carry the status, see
+                // `EngineStoreStream`
+                let updated = engine.fork_choice_updated(state, payload_attrs).await?;
+                PayloadStatus::from_status(updated.payload_status.status)
+            }
+        };
+
+        if let Some(recorded) = recorded &&
+            response_diverges(&recorded, &status)
+        {
+            divergences += 1;
+            warn!(
+                target: "reth::cli",
+                message = %message_path.display(),
+                ?recorded,
+                actual = ?status,
+                validation_error = ?status.status.validation_error(),
+                "Engine API response diverged from recording"
+            );
+        }
+    }
+
+    info!(target: "reth::cli", divergences, "Finished replaying engine API messages");
+    Ok(divergences)
+}
+
+/// Returns true if the engine's response diverges from the recorded one.
+///
+/// A response diverges if its status kind or its latest valid hash differs from the recorded one.
+/// Validation errors are not compared, since their messages are not stable across versions.
+fn response_diverges(recorded: &PayloadStatus, actual: &PayloadStatus) -> bool {
+    core::mem::discriminant(&recorded.status) != core::mem::discriminant(&actual.status) ||
+        recorded.latest_valid_hash != actual.latest_valid_hash
+}
+
+/// Number of headers that are read from a static file snapshot at once.
+const SNAPSHOT_HEADERS_CHUNK: u64 = 10_000;
+
+/// Imports the headers segment of a static file snapshot into the storage of the given provider
+/// factory.
+///
+/// The snapshot is validated before it is imported: the files of every segment must cover a
+/// contiguous block range that doesn't extend past the headers, the headers must start at the
+/// chain's genesis and every header must match its stored hash and link to its parent. The header
+/// files are then linked into the static files directory, see
+/// [`StaticFileProvider::import_segment_files`].
+///
+/// Only the headers segment is imported. The other segments rely on database tables that are not
+/// part of static files, like the block body indices and withdrawals, so they are synced by the
+/// pipeline instead.
+///
+/// The header number index and the headers stage checkpoint are committed after the files are
+/// linked. If the import is interrupted, the startup consistency check removes the linked headers
+/// and the import is retried on the next launch.
+///
+/// The import is skipped if the storage already contains headers past genesis.
+pub fn import_static_file_snapshot<N: ProviderNodeTypes>(
+    factory: &ProviderFactory<N>,
+    snapshot: &Path,
+) -> eyre::Result<()> {
+    eyre::ensure!(
+        snapshot.is_dir(),
+        "static file snapshot {} is not a directory",
+        snapshot.display()
+    );
+
+    let checkpoint = factory.get_stage_checkpoint(StageId::Headers)?.unwrap_or_default();
+    if checkpoint.block_number > 0 {
+        info!(target: "reth::cli", checkpoint = checkpoint.block_number, "Storage already contains headers, skipping snapshot import");
+        return Ok(())
+    }
+
+    let static_files = iter_static_files(snapshot)?;
+    let Some(header_files) = static_files.get(StaticFileSegment::Headers) else {
+        eyre::bail!("static file snapshot {} contains no headers", snapshot.display())
+    };
+    eyre::ensure!(
+        header_files[0].0.start() == 0,
+        "static file snapshot headers start at block {} instead of genesis",
+        header_files[0].0.start()
+    );
+    let highest_header = header_files[header_files.len() - 1].0.end();
+
+    for (segment, files) in &*static_files {
+        for window in files.windows(2) {
+            let (previous, next) = (&window[0].0, &window[1].0);
+            eyre::ensure!(
+                next.start() == previous.end() + 1,
+                "static file snapshot {segment} files are not contiguous: {previous} is followed by {next}"
+            );
+        }
+
+        let highest = files[files.len() - 1].0.end();
+        eyre::ensure!(
+            highest <= highest_header,
+            "static file snapshot {segment} files end at block {highest}, after the last header {highest_header}"
+        );
+    }
+
+    let snapshot_provider = StaticFileProvider::<N::Primitives>::read_only(snapshot)?;
+    let genesis_hash = factory
+        .block_hash(0)?
+        .ok_or_else(|| eyre::eyre!("genesis must be initialized before importing a snapshot"))?;
+
+    // validate the whole chain and index the header hashes in a single pass, nothing is committed
+    // before the chain is known to be valid
+    let provider_rw = factory.database_provider_rw()?;
+    let mut parent_hash = genesis_hash;
+    let mut number = 0;
+    while number <= highest_header {
+        let end = highest_header.min(number + SNAPSHOT_HEADERS_CHUNK - 1);
+        let headers = snapshot_provider.headers_range(number..=end)?;
+        let hashes = snapshot_provider.canonical_hashes_range(number, end + 1)?;
+        eyre::ensure!(
+            headers.len() as u64 == end - number + 1 && hashes.len() == headers.len(),
+            "static file snapshot is missing headers in {number}..={end}"
+        );
+        for (header, stored_hash) in headers.into_iter().zip(hashes) {
+            let header = SealedHeader::seal_slow(header);
+            eyre::ensure!(
+                header.hash() == stored_hash,
+                "static file snapshot header {} does not match its stored hash",
+                header.number()
+            );
+            if header.number() == 0 {
+                eyre::ensure!(
+                    header.hash() == genesis_hash,
+                    "static file snapshot genesis {} does not match the chain's genesis {genesis_hash}",
+                    header.hash()
+                );
+            } else {
+                eyre::ensure!(
+                    header.parent_hash() == parent_hash,
+                    "static file snapshot header {} does not link to its parent",
+                    header.number()
+                );
+                provider_rw
+                    .tx_ref()
+                    .put::<tables::HeaderNumbers>(header.hash(), header.number())?;
+            }
+            parent_hash = header.hash();
+        }
+        number = end + 1;
+    }
+    drop(snapshot_provider);
+
+    let skipped = static_files
+        .keys()
+        .filter(|segment| *segment != StaticFileSegment::Headers)
+        .collect::<Vec<_>>();
+    info!(target: "reth::cli", ?snapshot, highest_header, ?skipped, "Importing static file snapshot headers");
+
+    let header_paths = header_files
+        .iter()
+        .map(|(_, header)| {
+            snapshot.join(StaticFileSegment::Headers.filename(&header.expected_block_range()))
+        })
+        .collect::<Vec<_>>();
+    provider_rw
+        .static_file_provider()
+        .import_segment_files(StaticFileSegment::Headers, &header_paths)?;
+
+    provider_rw.save_stage_checkpoint(StageId::Headers, StageCheckpoint::new(highest_header))?;
+    provider_rw.commit()?;
+
+    Ok(())
+}
+
 /// Constructs a [Pipeline] that's wired to the network
 #[expect(clippy::too_many_arguments)]
 pub fn build_networked_pipeline<N, Client, Evm>(
@@ -42,6 +335,7 @@ pub fn build_networked_pipeline<N, Client, Evm>(
     evm_config: Evm,
     exex_manager_handle: ExExManagerHandle<N::Primitives>,
     era_import_source: Option<EraImportSource>,
+    skipped_stages: &[StageId],
 ) -> eyre::Result<Pipeline<N>>
 where
     N: ProviderNodeTypes,
@@ -70,6 +364,7 @@ where
         evm_config,
         exex_manager_handle,
         era_import_source,
+        skipped_stages,
     )?;
 
     Ok(pipeline)
@@ -90,6 +385,7 @@ pub fn build_pipeline<N, H, B, Evm>(
     evm_config: Evm,
     exex_manager_handle: ExExManagerHandle<N::Primitives>,
     era_import_source: Option<EraImportSource>,
+    skipped_stages: &[StageId],
 ) -> eyre::Result<Pipeline<N>>
 where
     N: ProviderNodeTypes,
@@ -127,9 +423,199 @@ where
                 stage_config.execution.into(),
                 stage_config.execution_external_clean_threshold(),
                 exex_manager_handle,
-            )),
+            ))
+            .disable_all(skipped_stages),
         )
         .build(provider_factory, static_file_producer);
 
     Ok(pipeline)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_consensus::Header;
+    use reth_chainspec::SEPOLIA;
+    use reth_db_common::init::init_genesis;
+    use reth_provider::{
+        test_utils::{
+            create_test_provider_factory, create_test_provider_factory_with_chain_spec,
+            MockNodeTypesWithDB,
+        },
+        BlockNumReader, StaticFileWriter,
+    };
+    use std::ffi::OsString;
+
+    /// Creates a storage with the headers up to the given block on top of the mainnet genesis.
+    fn create_snapshot(tip: BlockNumber) -> ProviderFactory<MockNodeTypesWithDB> {
+        let factory = create_test_provider_factory();
+        init_genesis(&factory).unwrap();
+
+        let provider_rw = factory.database_provider_rw().unwrap();
+        let static_file_provider = provider_rw.static_file_provider();
+        let mut writer = static_file_provider.latest_writer(StaticFileSegment::Headers).unwrap();
+        let mut parent = factory.sealed_header(0).unwrap().unwrap();
+        for number in 1..=tip {
+            let header = SealedHeader::seal_slow(Header {
+                number,
+                parent_hash: parent.hash(),
+                ..Default::default()
+            });
+            writer.append_header(header.header(), &header.hash()).unwrap();
+            parent = header;
+        }
+        drop(writer);
+        provider_rw.commit().unwrap();
+
+        factory
+    }
+
+    fn read_files(dir: &Path) -> Vec<(OsString, Vec<u8>)> {
+        let mut files = reth_fs_util::read_dir(dir)
+            .unwrap()
+            .map(|entry| {
+                let entry = entry.unwrap();
+                (entry.file_name(), reth_fs_util::read(entry.path()).unwrap())
+            })
+            .collect::<Vec<_>>();
+        files.sort();
+        files
+    }
+
+    #[test]
+    fn import_snapshot_headers() {
+        let snapshot = create_snapshot(5);
+        let snapshot_dir = snapshot.static_file_provider().directory().to_path_buf();
+        let tip = snapshot.sealed_header(5).unwrap().unwrap();
+        let snapshot_files = read_files(&snapshot_dir);
+
+        let factory = create_test_provider_factory();
+        init_genesis(&factory).unwrap();
+        import_static_file_snapshot(&factory, &snapshot_dir).unwrap();
+
+        assert_eq!(
+            factory.get_stage_checkpoint(StageId::Headers).unwrap(),
+            Some(StageCheckpoint::new(5))
+        );
+        assert_eq!(factory.sealed_header(5).unwrap(), Some(tip.clone()));
+        assert_eq!(factory.block_number(tip.hash()).unwrap(), Some(5));
+
+        // the imported headers survive the startup consistency check
+        assert_eq!(factory.check_consistency().unwrap(), (None, None));
+        assert_eq!(
+            factory
+                .static_file_provider()
+                .get_highest_static_file_block(StaticFileSegment::Headers),
+            Some(5)
+        );
+
+        // the snapshot is only read
+        assert_eq!(read_files(&snapshot_dir), snapshot_files);
+
+        // the headers are only imported once
+        import_static_file_snapshot(&factory, &snapshot_dir).unwrap();
+        assert_eq!(
+            factory.get_stage_checkpoint(StageId::Headers).unwrap(),
+            Some(StageCheckpoint::new(5))
+        );
+
+        // the next writer continues from the imported headers without touching the snapshot
+        let static_file_provider = factory.static_file_provider();
+        let mut writer = static_file_provider.latest_writer(StaticFileSegment::Headers).unwrap();
+        let next = SealedHeader::seal_slow(Header {
+            number: 6,
+            parent_hash: tip.hash(),
+            ..Default::default()
+        });
+        writer.append_header(next.header(), &next.hash()).unwrap();
+        drop(writer);
+        static_file_provider.commit().unwrap();
+        assert_eq!(factory.sealed_header(6).unwrap(), Some(next));
+        assert_eq!(read_files(&snapshot_dir), snapshot_files);
+    }
+
+    #[test]
+    fn retry_interrupted_snapshot_import() {
+        let snapshot = create_snapshot(5);
+        let snapshot_dir = snapshot.static_file_provider().directory().to_path_buf();
+
+        // headers committed to static files without the database transaction
+        let factory = create_test_provider_factory();
+        init_genesis(&factory).unwrap();
+        let static_file_provider = factory.static_file_provider();
+        let mut writer = static_file_provider.latest_writer(StaticFileSegment::Headers).unwrap();
+        for header in snapshot.sealed_headers_range(1..=3).unwrap() {
+            writer.append_header(header.header(), &header.hash()).unwrap();
+        }
+        drop(writer);
+        static_file_provider.commit().unwrap();
+
+        // the consistency check on launch removes the partially imported headers
+        assert_eq!(factory.check_consistency().unwrap(), (None, None));
+        assert_eq!(
+            static_file_provider.get_highest_static_file_block(StaticFileSegment::Headers),
+            Some(0)
+        );
+
+        import_static_file_snapshot(&factory, &snapshot_dir).unwrap();
+        assert_eq!(
+            factory.get_stage_checkpoint(StageId::Headers).unwrap(),
+            Some(StageCheckpoint::new(5))
+        );
+        assert_eq!(
+            static_file_provider.get_highest_static_file_block(StaticFileSegment::Headers),
+            Some(5)
+        );
+    }
+
+    #[test]
+    fn fail_import_snapshot_of_other_chain() {
+        let snapshot = create_snapshot(5);
+        let snapshot_dir = snapshot.static_file_provider().directory().to_path_buf();
+
+        let factory = create_test_provider_factory_with_chain_spec(SEPOLIA.clone());
+        init_genesis(&factory).unwrap();
+        let err = import_static_file_snapshot(&factory, &snapshot_dir).unwrap_err();
+        assert!(err.to_string().contains("does not match the chain's genesis"), "{err}");
+
+        assert_eq!(
+            factory.get_stage_checkpoint(StageId::Headers).unwrap(),
+            Some(StageCheckpoint::new(0))
+        );
+        assert_eq!(
+            factory
+                .static_file_provider()
+                .get_highest_static_file_block(StaticFileSegment::Headers),
+            Some(0)
+        );
+    }
+
+    #[test]
+    fn engine_response_divergence() {
+        let hash = B256::with_last_byte(1);
+        let valid = PayloadStatus::new(PayloadStatusEnum::Valid, Some(hash));
+        assert!(!response_diverges(&valid, &valid));
+
+        // a different status kind diverges, regardless of the validation error
+        let invalid = |error: &str| {
+            PayloadStatus::new(
+                PayloadStatusEnum::Invalid { validation_error: error.to_string() },
+                Some(hash),
+            )
+        };
+        assert!(response_diverges(&valid, &invalid("")));
+        assert!(!response_diverges(&invalid(""), &invalid("bad block")));
+
+        // a different latest valid hash diverges, also if only one of the responses has one
+        let other = PayloadStatus::new(PayloadStatusEnum::Valid, Some(B256::with_last_byte(2)));
+        assert!(response_diverges(&valid, &other));
+        let recorded = PayloadStatus::from_status(PayloadStatusEnum::Valid);
+        assert!(response_diverges(&recorded, &other));
+        assert!(response_diverges(&other, &recorded));
+        assert!(!response_diverges(&recorded, &recorded));
+        assert!(response_diverges(
+            &recorded,
+            &PayloadStatus::from_status(PayloadStatusEnum::Syncing)
+        ));
+    }
+}
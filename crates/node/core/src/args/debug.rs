@@ -108,6 +108,12 @@ pub struct DebugArgs {
     /// the backfill, but did not yet receive any new blocks.
     #[arg(long = "debug.startup-sync-state-idle", help_heading = "Debug")]
     pub startup_sync_state_idle: bool,
+
+    /// Skip the startup check that the highest static file header matches the database.
+    ///
+    /// Speeds up restarts at the risk of not detecting a diverged database early.
+    #[arg(long = "debug.skip-header-consistency-check", help_heading = "Debug")]
+    pub skip_header_consistency_check: bool,
 }
 
 impl Default for DebugArgs {
@@ -127,6 +133,7 @@ impl Default for DebugArgs {
             healthy_node_rpc_url: None,
             ethstats: None,
             startup_sync_state_idle: false,
+            skip_header_consistency_check: false,
         }
     }
 }
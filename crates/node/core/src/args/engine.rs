@@ -4,8 +4,9 @@ use clap::{builder::Resettable, Args};
 use eyre::ensure;
 use reth_cli_util::{parse_duration_from_secs_or_ms, parsers::format_duration_as_secs_or_ms};
 use reth_engine_primitives::{
-    TreeConfig, DEFAULT_MULTIPROOF_TASK_CHUNK_SIZE, DEFAULT_PERSISTENCE_BACKPRESSURE_THRESHOLD,
-    DEFAULT_SPARSE_TRIE_MAX_HOT_ACCOUNTS, DEFAULT_SPARSE_TRIE_MAX_HOT_SLOTS,
+    TreeConfig, DEFAULT_MAX_REORG_DEPTH, DEFAULT_MULTIPROOF_TASK_CHUNK_SIZE,
+    DEFAULT_PERSISTENCE_BACKPRESSURE_THRESHOLD, DEFAULT_SPARSE_TRIE_MAX_HOT_ACCOUNTS,
+    DEFAULT_SPARSE_TRIE_MAX_HOT_SLOTS,
 };
 use std::{sync::OnceLock, time::Duration};
 
@@ -49,6 +50,7 @@ pub struct DefaultEngineValues {
     state_root_task_timeout: Option<String>,
     share_execution_cache_with_payload_builder: bool,
     share_sparse_trie_with_payload_builder: bool,
+    max_reorg_depth: u64,
 }
 
 impl DefaultEngineValues {
@@ -226,6 +228,12 @@ impl DefaultEngineValues {
         self.share_sparse_trie_with_payload_builder = v;
         self
     }
+
+    /// Set the default maximum reorg depth
+    pub const fn with_max_reorg_depth(mut self, v: u64) -> Self {
+        self.max_reorg_depth = v;
+        self
+    }
 }
 
 impl Default for DefaultEngineValues {
@@ -258,6 +266,7 @@ impl Default for DefaultEngineValues {
             state_root_task_timeout: Some("1s".to_string()),
             share_execution_cache_with_payload_builder: false,
             share_sparse_trie_with_payload_builder: false,
+            max_reorg_depth: DEFAULT_MAX_REORG_DEPTH,
         }
     }
 }
@@ -459,6 +468,23 @@ pub struct EngineArgs {
     )]
     pub share_sparse_trie_with_payload_builder: bool,
 
+    /// Maximum number of canonical blocks a reorg may unwind.
+    ///
+    /// Forkchoice updates that would reorg deeper are rejected with an error. The limit applies to
+    /// reorgs below the finalized block as well, which are otherwise applied.
+    #[arg(long = "engine.max-reorg-depth", default_value_t = DefaultEngineValues::get_global().max_reorg_depth)]
+    pub max_reorg_depth: u64,
+
+    /// Comma-separated list of CPU cores to pin critical threads (engine, state root) to.
+    ///
+    /// Pinning avoids thread migration during heavy block processing, which can improve tail
+    /// latency on NUMA machines. Only supported on Linux, ignored with a warning on other
+    /// platforms.
+    ///
+    /// --engine.critical-cpu-cores 0,1,2
+    #[arg(long = "engine.critical-cpu-cores", value_name = "CORES", value_delimiter = ',')]
+    pub critical_cpu_cores: Option<Vec<usize>>,
+
     /// Add random jitter before each proof computation (trie-debug only).
     /// Each proof worker sleeps for a random duration up to this value before
     /// starting work. Useful for stress-testing timing-sensitive proof logic.
@@ -504,6 +530,7 @@ impl Default for EngineArgs {
             state_root_task_timeout,
             share_execution_cache_with_payload_builder,
             share_sparse_trie_with_payload_builder,
+            max_reorg_depth,
         } = DefaultEngineValues::get_global().clone();
         Self {
             persistence_threshold,
@@ -539,6 +566,8 @@ impl Default for EngineArgs {
                 .map(|s| humantime::parse_duration(s).expect("valid default duration")),
             share_execution_cache_with_payload_builder,
             share_sparse_trie_with_payload_builder,
+            max_reorg_depth,
+            critical_cpu_cores: None,
             #[cfg(feature = "trie-debug")]
             proof_jitter: None,
         }
@@ -588,7 +617,8 @@ impl EngineArgs {
             )
             .with_share_sparse_trie_with_payload_builder(
                 self.share_sparse_trie_with_payload_builder,
-            );
+            )
+            .with_max_reorg_depth(self.max_reorg_depth);
         #[cfg(feature = "trie-debug")]
         let config = config.with_proof_jitter(self.proof_jitter);
         config
@@ -649,6 +679,8 @@ mod tests {
             state_root_task_timeout: Some(Duration::from_secs(2)),
             share_execution_cache_with_payload_builder: false,
             share_sparse_trie_with_payload_builder: false,
+            max_reorg_depth: 64,
+            critical_cpu_cores: Some(vec![0, 2]),
             #[cfg(feature = "trie-debug")]
             proof_jitter: None,
         };
@@ -691,6 +723,10 @@ mod tests {
             "--engine.disable-sparse-trie-cache-pruning",
             "--engine.state-root-task-timeout",
             "2s",
+            "--engine.max-reorg-depth",
+            "64",
+            "--engine.critical-cpu-cores",
+            "0,2",
         ])
         .args;
 
@@ -8,12 +8,15 @@ use std::{
     ops::Not,
     path::PathBuf,
     sync::OnceLock,
+    time::Duration,
 };
 
 use crate::version::version_metadata;
 use clap::Args;
 use reth_chainspec::EthChainSpec;
-use reth_cli_util::{get_secret_key, load_secret_key::SecretKeyError};
+use reth_cli_util::{
+    get_secret_key, load_secret_key::SecretKeyError, parse_duration_from_secs_or_ms,
+};
 use reth_config::Config;
 use reth_discv4::{NodeRecord, DEFAULT_DISCOVERY_ADDR, DEFAULT_DISCOVERY_PORT};
 use reth_discv5::{
@@ -23,6 +26,8 @@ use reth_discv5::{
 use reth_net_banlist::IpFilter;
 use reth_net_nat::{NatResolver, DEFAULT_NET_IF_NAME};
 use reth_network::{
+    config::DEFAULT_MAX_BLOCK_ANNOUNCEMENTS_PER_SEC,
+    eth_requests::{DEFAULT_MAX_CONCURRENT_RECEIPT_REQUESTS, DEFAULT_MAX_QUEUED_RECEIPT_REQUESTS},
     transactions::{
         config::{TransactionIngressPolicy, TransactionPropagationKind},
         constants::{
@@ -38,6 +43,7 @@ use reth_network::{
         DEFAULT_SOFT_LIMIT_BYTE_SIZE_POOLED_TRANSACTIONS_RESP_ON_PACK_GET_POOLED_TRANSACTIONS_REQ,
         SOFT_LIMIT_BYTE_SIZE_POOLED_TRANSACTIONS_RESPONSE,
     },
+    types::EthVersion,
     HelloMessageWithProtocols, NetworkConfigBuilder, NetworkPrimitives,
 };
 use reth_network_peers::{mainnet_nodes, TrustedPeer};
@@ -76,6 +82,12 @@ pub struct DefaultNetworkArgs {
     pub soft_limit_byte_size_pooled_transactions_response_on_pack_request: usize,
     /// Default max capacity of cache of hashes for transactions pending fetch.
     pub max_capacity_cache_txns_pending_fetch: u32,
+    /// Default max number of receipt requests that are served concurrently.
+    pub max_concurrent_receipt_requests: usize,
+    /// Default max number of receipt requests that are queued while all serving slots are busy.
+    pub max_queued_receipt_requests: usize,
+    /// Default max number of inbound block announcements accepted per peer per second.
+    pub max_block_announcements_per_sec: u32,
     /// Default transaction propagation policy.
     pub tx_propagation_policy: TransactionPropagationKind,
     /// Default transaction ingress policy.
@@ -169,6 +181,25 @@ impl DefaultNetworkArgs {
         self
     }
 
+    /// Set the default max number of receipt requests that are served concurrently.
+    pub const fn with_max_concurrent_receipt_requests(mut self, v: usize) -> Self {
+        self.max_concurrent_receipt_requests = v;
+        self
+    }
+
+    /// Set the default max number of receipt requests that are queued while all serving slots
+    /// are busy.
+    pub const fn with_max_queued_receipt_requests(mut self, v: usize) -> Self {
+        self.max_queued_receipt_requests = v;
+        self
+    }
+
+    /// Set the default max number of inbound block announcements accepted per peer per second.
+    pub const fn with_max_block_announcements_per_sec(mut self, v: u32) -> Self {
+        self.max_block_announcements_per_sec = v;
+        self
+    }
+
     /// Set the default transaction propagation policy.
     pub const fn with_tx_propagation_policy(mut self, v: TransactionPropagationKind) -> Self {
         self.tx_propagation_policy = v;
@@ -210,6 +241,9 @@ impl Default for DefaultNetworkArgs {
             soft_limit_byte_size_pooled_transactions_response_on_pack_request:
                 DEFAULT_SOFT_LIMIT_BYTE_SIZE_POOLED_TRANSACTIONS_RESP_ON_PACK_GET_POOLED_TRANSACTIONS_REQ,
             max_capacity_cache_txns_pending_fetch: DEFAULT_MAX_CAPACITY_CACHE_PENDING_FETCH,
+            max_concurrent_receipt_requests: DEFAULT_MAX_CONCURRENT_RECEIPT_REQUESTS,
+            max_queued_receipt_requests: DEFAULT_MAX_QUEUED_RECEIPT_REQUESTS,
+            max_block_announcements_per_sec: DEFAULT_MAX_BLOCK_ANNOUNCEMENTS_PER_SEC,
             tx_propagation_policy: TransactionPropagationKind::default(),
             tx_ingress_policy: TransactionIngressPolicy::default(),
             propagation_mode: TransactionPropagationMode::Sqrt,
@@ -287,11 +321,11 @@ pub struct NetworkArgs {
     pub port: u16,
 
     /// Maximum number of outbound peers. default: 100
-    #[arg(long)]
+    #[arg(long, alias = "network.max-outbound-peers")]
     pub max_outbound_peers: Option<usize>,
 
     /// Maximum number of inbound peers. default: 30
-    #[arg(long)]
+    #[arg(long, alias = "network.max-inbound-peers")]
     pub max_inbound_peers: Option<usize>,
 
     /// Maximum number of total peers (inbound + outbound).
@@ -348,6 +382,58 @@ pub struct NetworkArgs {
     #[arg(long = "max-tx-pending-fetch", value_name = "COUNT", default_value_t = DefaultNetworkArgs::get_global().max_capacity_cache_txns_pending_fetch, verbatim_doc_comment)]
     pub max_capacity_cache_txns_pending_fetch: u32,
 
+    /// Max number of receipt requests from peers that are served concurrently.
+    ///
+    /// Further receipt requests are queued, see `--network.max-queued-receipt-requests`.
+    #[arg(long = "network.max-concurrent-receipt-requests", value_name = "COUNT", default_value_t = DefaultNetworkArgs::get_global().max_concurrent_receipt_requests, verbatim_doc_comment)]
+    pub max_concurrent_receipt_requests: usize,
+
+    /// Max number of receipt requests from peers that are queued while all serving slots are
+    /// busy.
+    ///
+    /// Any receipt request received while the queue is full is answered with an empty response.
+    #[arg(long = "network.max-queued-receipt-requests", value_name = "COUNT", default_value_t = DefaultNetworkArgs::get_global().max_queued_receipt_requests, verbatim_doc_comment)]
+    pub max_queued_receipt_requests: usize,
+
+    /// Max number of inbound block announcements accepted per peer per second.
+    ///
+    /// Excess `NewBlock` and `NewBlockHashes` announcements are dropped.
+    #[arg(long = "network.max-block-announcements-per-sec", value_name = "COUNT", default_value_t = DefaultNetworkArgs::get_global().max_block_announcements_per_sec, verbatim_doc_comment)]
+    pub max_block_announcements_per_sec: u32,
+
+    /// Timeout for a single headers request issued by the headers downloader.
+    ///
+    /// Overrides `stages.headers.downloader_request_timeout` from the config file. Defaults to
+    /// 20s.
+    #[arg(long = "downloader.header-timeout", value_name = "DURATION", value_parser = parse_duration_from_secs_or_ms, verbatim_doc_comment)]
+    pub downloader_header_timeout: Option<Duration>,
+
+    /// Timeout for a single bodies request issued by the bodies downloader.
+    ///
+    /// Overrides `stages.bodies.downloader_request_timeout` from the config file. Defaults to
+    /// 20s.
+    #[arg(long = "downloader.body-timeout", value_name = "DURATION", value_parser = parse_duration_from_secs_or_ms, verbatim_doc_comment)]
+    pub downloader_body_timeout: Option<Duration>,
+
+    /// Recompute the bloom of each receipt received from a peer and compare it to the bloom
+    /// supplied by the peer.
+    ///
+    /// Responses with a mismatching bloom are dropped and requested from another peer. Only
+    /// applies to eth/66-68 receipts, later protocol versions don't include blooms.
+    #[arg(long = "sync.verify-receipt-blooms", verbatim_doc_comment)]
+    pub verify_receipt_blooms: bool,
+
+    /// Comma separated list of eth protocol versions that should not be advertised or accepted.
+    ///
+    /// At least one supported version must remain enabled. Example: 69
+    #[arg(
+        long = "network.disable-eth-versions",
+        value_name = "VERSION",
+        value_delimiter = ',',
+        verbatim_doc_comment
+    )]
+    pub disable_eth_versions: Vec<EthVersion>,
+
     /// Name of network interface used to communicate with peers.
     ///
     /// If flag is set, but no value is passed, the default interface for docker `eth0` is tried.
@@ -360,6 +446,13 @@ pub struct NetworkArgs {
     #[arg(long = "tx-propagation-policy", default_value_t = DefaultNetworkArgs::get_global().tx_propagation_policy)]
     pub tx_propagation_policy: TransactionPropagationKind,
 
+    /// Disable transaction propagation
+    ///
+    /// Disables announcing and broadcasting all transactions to peers, regardless of the
+    /// `--tx-propagation-policy`. Incoming transactions from peers are still accepted.
+    #[arg(long = "network.no-tx-propagation")]
+    pub no_tx_propagation: bool,
+
     /// Transaction ingress policy
     ///
     /// Determines which peers' transactions are accepted over P2P.
@@ -395,7 +488,7 @@ pub struct NetworkArgs {
     pub network_id: Option<u64>,
 
     /// Maximum allowed ETH message size in bytes. Default is 10 MiB.
-    #[arg(long = "eth-max-message-size", value_name = "BYTES")]
+    #[arg(long = "eth-max-message-size", alias = "network.max-message-size", value_name = "BYTES")]
     pub eth_max_message_size: Option<NonZeroUsize>,
 
     /// Restrict network communication to the given IP networks (CIDR masks).
@@ -488,6 +581,18 @@ impl NetworkArgs {
         }
     }
 
+    /// Returns the transaction propagation policy to use.
+    ///
+    /// This is [`TransactionPropagationKind::None`] if `--network.no-tx-propagation` is set,
+    /// otherwise the configured `--tx-propagation-policy`.
+    pub const fn resolved_tx_propagation_policy(&self) -> TransactionPropagationKind {
+        if self.no_tx_propagation {
+            TransactionPropagationKind::None
+        } else {
+            self.tx_propagation_policy
+        }
+    }
+
     /// Build a [`NetworkConfigBuilder`] from a [`Config`] and a [`EthChainSpec`], in addition to
     /// the values in this option struct.
     ///
@@ -539,6 +644,7 @@ impl NetworkArgs {
                 builder.hello_message(
                     HelloMessageWithProtocols::builder(peer_id)
                         .client_version(&self.identity)
+                        .protocols(self.eth_versions().into_iter().map(Into::into))
                         .build(),
                 )
             })
@@ -559,9 +665,40 @@ impl NetworkArgs {
             .disable_tx_gossip(self.disable_tx_gossip)
             .required_block_hashes(self.required_block_hashes.clone())
             .eth_max_message_size_opt(self.eth_max_message_size.map(NonZeroUsize::get))
+            .max_block_announcements_per_sec(self.max_block_announcements_per_sec)
+            .verify_receipt_blooms(self.verify_receipt_blooms)
             .network_id(self.network_id)
     }
 
+    /// Returns the supported eth protocol versions that are not disabled via
+    /// `--network.disable-eth-versions`, in order of preference.
+    pub fn eth_versions(&self) -> Vec<EthVersion> {
+        EthVersion::ALL_VERSIONS
+            .iter()
+            .copied()
+            .filter(|version| !self.disable_eth_versions.contains(version))
+            .collect()
+    }
+
+    /// Validates cross-field network arguments.
+    pub fn validate(&self) -> eyre::Result<()> {
+        eyre::ensure!(
+            !self.eth_versions().is_empty(),
+            "--network.disable-eth-versions must leave at least one eth protocol version enabled"
+        );
+        Ok(())
+    }
+
+    /// Applies the downloader request timeouts set on the command line to the given [`Config`].
+    pub fn apply_downloader_timeouts(&self, config: &mut Config) {
+        if let Some(timeout) = self.downloader_header_timeout {
+            config.stages.headers.downloader_request_timeout = timeout;
+        }
+        if let Some(timeout) = self.downloader_body_timeout {
+            config.stages.bodies.downloader_request_timeout = timeout;
+        }
+    }
+
     /// If `no_persist_peers` is false then this returns the path to the persistent peers file path.
     pub fn persistent_peers_file(&self, peers_file: PathBuf) -> Option<PathBuf> {
         self.no_persist_peers.not().then_some(peers_file)
@@ -660,6 +797,9 @@ impl Default for NetworkArgs {
             soft_limit_byte_size_pooled_transactions_response,
             soft_limit_byte_size_pooled_transactions_response_on_pack_request,
             max_capacity_cache_txns_pending_fetch,
+            max_concurrent_receipt_requests,
+            max_queued_receipt_requests,
+            max_block_announcements_per_sec,
             tx_propagation_policy,
             tx_ingress_policy,
             propagation_mode,
@@ -689,9 +829,17 @@ impl Default for NetworkArgs {
             max_pending_pool_imports,
             max_seen_tx_history,
             max_capacity_cache_txns_pending_fetch,
+            max_concurrent_receipt_requests,
+            max_queued_receipt_requests,
+            max_block_announcements_per_sec,
+            downloader_header_timeout: None,
+            downloader_body_timeout: None,
+            verify_receipt_blooms: false,
+            disable_eth_versions: vec![],
             net_if: None,
             tx_propagation_policy,
             tx_ingress_policy,
+            no_tx_propagation: false,
             disable_tx_gossip: false,
             propagation_mode,
             required_block_hashes: vec![],
@@ -1008,12 +1156,78 @@ mod tests {
         }
     }
 
+    #[test]
+    fn parse_no_tx_propagation_args() {
+        let args = CommandParser::<NetworkArgs>::parse_from(["reth"]).args;
+        assert!(!args.no_tx_propagation);
+        assert_eq!(args.resolved_tx_propagation_policy(), TransactionPropagationKind::All);
+
+        let args =
+            CommandParser::<NetworkArgs>::parse_from(["reth", "--network.no-tx-propagation"]).args;
+        assert!(args.no_tx_propagation);
+        assert_eq!(args.resolved_tx_propagation_policy(), TransactionPropagationKind::None);
+    }
+
     #[test]
     fn parse_disable_tx_gossip_args() {
         let args = CommandParser::<NetworkArgs>::parse_from(["reth", "--disable-tx-gossip"]).args;
         assert!(args.disable_tx_gossip);
     }
 
+    #[test]
+    fn parse_verify_receipt_blooms_args() {
+        let args = CommandParser::<NetworkArgs>::parse_from(["reth"]).args;
+        assert!(!args.verify_receipt_blooms);
+
+        let args =
+            CommandParser::<NetworkArgs>::parse_from(["reth", "--sync.verify-receipt-blooms"]).args;
+        assert!(args.verify_receipt_blooms);
+    }
+
+    #[test]
+    fn parse_downloader_timeout_args() {
+        let args = CommandParser::<NetworkArgs>::parse_from([
+            "reth",
+            "--downloader.header-timeout",
+            "5",
+            "--downloader.body-timeout",
+            "1500ms",
+        ])
+        .args;
+        assert_eq!(args.downloader_header_timeout, Some(Duration::from_secs(5)));
+        assert_eq!(args.downloader_body_timeout, Some(Duration::from_millis(1500)));
+
+        let mut config = Config::default();
+        args.apply_downloader_timeouts(&mut config);
+        assert_eq!(config.stages.headers.downloader_request_timeout, Duration::from_secs(5));
+        assert_eq!(config.stages.bodies.downloader_request_timeout, Duration::from_millis(1500));
+    }
+
+    #[test]
+    fn parse_disable_eth_versions() {
+        let args = CommandParser::<NetworkArgs>::parse_from(["reth"]).args;
+        assert_eq!(args.eth_versions(), EthVersion::ALL_VERSIONS);
+
+        let args = CommandParser::<NetworkArgs>::parse_from([
+            "reth",
+            "--network.disable-eth-versions",
+            "69",
+        ])
+        .args;
+        assert_eq!(args.disable_eth_versions, vec![EthVersion::Eth69]);
+        assert!(!args.eth_versions().contains(&EthVersion::Eth69));
+        assert!(args.validate().is_ok());
+
+        let args = CommandParser::<NetworkArgs>::parse_from([
+            "reth",
+            "--network.disable-eth-versions",
+            "66,67,68,69",
+        ])
+        .args;
+        assert!(args.eth_versions().is_empty());
+        assert!(args.validate().is_err());
+    }
+
     #[test]
     fn parse_max_peers_flag() {
         let args = CommandParser::<NetworkArgs>::parse_from(["reth", "--max-peers", "90"]).args;
@@ -1095,6 +1309,21 @@ mod tests {
         assert_eq!(args.resolved_max_inbound_peers(), Some(15));
     }
 
+    #[test]
+    fn parse_namespaced_peer_limits() {
+        let args = CommandParser::<NetworkArgs>::parse_from([
+            "reth",
+            "--network.max-outbound-peers",
+            "20",
+            "--network.max-inbound-peers",
+            "80",
+        ])
+        .args;
+
+        assert_eq!(args.resolved_max_outbound_peers(), Some(20));
+        assert_eq!(args.resolved_max_inbound_peers(), Some(80));
+    }
+
     #[test]
     fn resolved_peers_with_defaults() {
         let args = CommandParser::<NetworkArgs>::parse_from(["reth"]).args;
@@ -1124,6 +1353,18 @@ mod tests {
         assert_eq!(args.eth_max_message_size, Some(NonZeroUsize::new(15 * 1024 * 1024).unwrap()));
     }
 
+    #[test]
+    fn parse_network_max_message_size_alias() {
+        let args = CommandParser::<NetworkArgs>::parse_from([
+            "reth",
+            "--network.max-message-size",
+            "1048576",
+        ])
+        .args;
+
+        assert_eq!(args.eth_max_message_size, Some(NonZeroUsize::new(1024 * 1024).unwrap()));
+    }
+
     #[test]
     fn parse_eth_max_message_size_zero_rejected() {
         let result =
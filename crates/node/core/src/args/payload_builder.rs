@@ -9,7 +9,7 @@ use reth_cli_util::{
     parse_duration_from_secs, parse_duration_from_secs_or_ms,
     parsers::format_duration_as_secs_or_ms,
 };
-use std::{ffi::OsStr, sync::OnceLock, time::Duration};
+use std::{ffi::OsStr, path::PathBuf, sync::OnceLock, time::Duration};
 
 /// Global static payload builder defaults
 static PAYLOAD_BUILDER_DEFAULTS: OnceLock<DefaultPayloadBuilderValues> = OnceLock::new();
@@ -128,6 +128,12 @@ pub struct PayloadBuilderArgs {
     /// Maximum number of blobs to include per block.
     #[arg(long = "builder.max-blobs", value_name = "COUNT")]
     pub max_blobs_per_block: Option<u64>,
+
+    /// Path to a custom KZG trusted setup file.
+    ///
+    /// If not set, the Ethereum mainnet trusted setup is used.
+    #[arg(long = "builder.trusted-setup-file", value_name = "PATH")]
+    pub trusted_setup_file: Option<PathBuf>,
 }
 
 impl Default for PayloadBuilderArgs {
@@ -140,6 +146,7 @@ impl Default for PayloadBuilderArgs {
             deadline: Duration::from_secs(defaults.deadline.parse().unwrap()),
             max_payload_tasks: defaults.max_payload_tasks,
             max_blobs_per_block: None,
+            trusted_setup_file: None,
         }
     }
 }
@@ -328,4 +335,15 @@ mod tests {
                 .args;
         assert_eq!(args.interval, Duration::from_millis(50));
     }
+
+    #[test]
+    fn test_args_with_trusted_setup_file() {
+        let args = CommandParser::<PayloadBuilderArgs>::parse_from([
+            "reth",
+            "--builder.trusted-setup-file",
+            "trusted_setup.txt",
+        ])
+        .args;
+        assert_eq!(args.trusted_setup_file, Some(PathBuf::from("trusted_setup.txt")));
+    }
 }
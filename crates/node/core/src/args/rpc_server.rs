@@ -428,6 +428,22 @@ pub struct RpcServerArgs {
     #[arg(long = "http.disable-compression", default_value_t = DefaultRpcServerArgs::get_global().http_disable_compression)]
     pub http_disable_compression: bool,
 
+    /// Enable HTTP/2 for the HTTP server.
+    ///
+    /// Connections are served over HTTP/1.1 or HTTP/2 (prior knowledge), depending on what the
+    /// client speaks. Not supported if the HTTP server shares its port with the WS server.
+    #[arg(long = "http.http2")]
+    pub http2: bool,
+
+    /// Maximum number of concurrent HTTP/2 streams per connection.
+    #[arg(
+        long = "http.http2-max-concurrent-streams",
+        value_name = "STREAMS",
+        value_parser = clap::value_parser!(u32).range(1..),
+        requires = "http2"
+    )]
+    pub http2_max_concurrent_streams: Option<u32>,
+
     /// Rpc Modules to be configured for the HTTP server
     #[arg(long = "http.api", value_parser = RpcModuleSelectionValueParser::default(), default_value = Resettable::from(DefaultRpcServerArgs::get_global().http_api.as_ref().map(|v| v.to_string().into())))]
     pub http_api: Option<RpcModuleSelection>,
@@ -470,6 +486,14 @@ pub struct RpcServerArgs {
     #[arg(long = "ipc.permissions", default_value = Resettable::from(DefaultRpcServerArgs::get_global().ipc_socket_permissions.as_ref().map(|v| v.to_string().into())))]
     pub ipc_socket_permissions: Option<String>,
 
+    /// Path of an additional Unix domain socket to serve JSON-RPC on.
+    ///
+    /// The server exposes the same modules as the IPC server. A stale socket file at this path is
+    /// removed on startup. The socket is created with the permissions set via `--ipc.permissions`,
+    /// or the system's umask if not specified.
+    #[arg(long = "rpc.uds-path", value_name = "PATH")]
+    pub rpc_uds_path: Option<PathBuf>,
+
     /// Auth server address to listen on
     #[arg(long = "authrpc.addr", default_value_t = DefaultRpcServerArgs::get_global().auth_addr)]
     pub auth_addr: IpAddr,
@@ -690,6 +714,12 @@ impl RpcServerArgs {
         self
     }
 
+    /// Configures the path of an additional Unix domain socket to serve JSON-RPC on.
+    pub fn with_rpc_uds_path(mut self, path: impl Into<PathBuf>) -> Self {
+        self.rpc_uds_path = Some(path.into());
+        self
+    }
+
     /// Enables the Auth IPC
     pub const fn with_auth_ipc(mut self) -> Self {
         self.auth_ipc = true;
@@ -793,8 +823,8 @@ impl RpcServerArgs {
         if self.ws && self.ws_api.as_ref().is_some_and(|api| api.contains(&ns)) {
             return true;
         }
-        // IPC exposes all modules when enabled
-        !self.ipcdisable
+        // IPC and the additional Unix domain socket expose all modules when enabled
+        !self.ipcdisable || self.rpc_uds_path.is_some()
     }
 
     /// Enables forced blob sidecar upcasting from EIP-4844 to EIP-7594 format.
@@ -855,6 +885,8 @@ impl Default for RpcServerArgs {
             http_addr,
             http_port,
             http_disable_compression,
+            http2: false,
+            http2_max_concurrent_streams: None,
             http_api,
             http_corsdomain,
             ws,
@@ -865,6 +897,7 @@ impl Default for RpcServerArgs {
             ipcdisable,
             ipcpath,
             ipc_socket_permissions,
+            rpc_uds_path: None,
             auth_addr,
             auth_port,
             auth_jwtsecret,
@@ -973,6 +1006,37 @@ mod tests {
         assert_eq!(apis, expected);
     }
 
+    #[test]
+    fn test_rpc_server_http2_args() {
+        let args = CommandParser::<RpcServerArgs>::parse_from([
+            "reth",
+            "--http.http2",
+            "--http.http2-max-concurrent-streams",
+            "64",
+        ])
+        .args;
+        assert!(args.http2);
+        assert_eq!(args.http2_max_concurrent_streams, Some(64));
+
+        let args = CommandParser::<RpcServerArgs>::parse_from(["reth"]).args;
+        assert!(!args.http2);
+        assert_eq!(args.http2_max_concurrent_streams, None);
+
+        assert!(CommandParser::<RpcServerArgs>::try_parse_from([
+            "reth",
+            "--http.http2",
+            "--http.http2-max-concurrent-streams",
+            "0",
+        ])
+        .is_err());
+        assert!(CommandParser::<RpcServerArgs>::try_parse_from([
+            "reth",
+            "--http.http2-max-concurrent-streams",
+            "64",
+        ])
+        .is_err());
+    }
+
     #[test]
     fn rpc_server_args_default_sanity_test() {
         let default_args = RpcServerArgs::default();
@@ -1016,6 +1080,8 @@ mod tests {
             http_addr: "127.0.0.1".parse().unwrap(),
             http_port: 8545,
             http_disable_compression: false,
+            http2: false,
+            http2_max_concurrent_streams: None,
             http_api: Some(RpcModuleSelection::try_from_selection(["eth", "admin"]).unwrap()),
             http_corsdomain: Some("*".to_string()),
             ws: true,
@@ -1026,6 +1092,7 @@ mod tests {
             ipcdisable: false,
             ipcpath: "reth.ipc".to_string(),
             ipc_socket_permissions: Some("0o666".to_string()),
+            rpc_uds_path: None,
             auth_addr: "127.0.0.1".parse().unwrap(),
             auth_port: 8551,
             auth_jwtsecret: Some(std::path::PathBuf::from("/tmp/jwt.hex")),
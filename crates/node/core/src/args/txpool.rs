@@ -55,6 +55,7 @@ pub struct DefaultTxPoolValues {
     transactions_backup_path: Option<PathBuf>,
     disable_transactions_backup: bool,
     max_batch_size: usize,
+    require_chain_id: bool,
 }
 
 impl DefaultTxPoolValues {
@@ -247,6 +248,12 @@ impl DefaultTxPoolValues {
         self.max_batch_size = v;
         self
     }
+
+    /// Set whether to reject transactions without a chain ID by default
+    pub const fn with_require_chain_id(mut self, v: bool) -> Self {
+        self.require_chain_id = v;
+        self
+    }
 }
 
 impl Default for DefaultTxPoolValues {
@@ -282,6 +289,7 @@ impl Default for DefaultTxPoolValues {
             transactions_backup_path: None,
             disable_transactions_backup: false,
             max_batch_size: 1,
+            require_chain_id: false,
         }
     }
 }
@@ -411,6 +419,11 @@ pub struct TxPoolArgs {
     /// Max batch size for transaction pool insertions
     #[arg(long = "txpool.max-batch-size", default_value_t = DefaultTxPoolValues::get_global().max_batch_size)]
     pub max_batch_size: usize,
+
+    /// Reject legacy transactions without a chain ID, i.e. transactions that are not replay
+    /// protected as per EIP-155.
+    #[arg(long = "txpool.require-chain-id", default_value_t = DefaultTxPoolValues::get_global().require_chain_id)]
+    pub require_chain_id: bool,
 }
 
 impl TxPoolArgs {
@@ -464,6 +477,7 @@ impl Default for TxPoolArgs {
             transactions_backup_path,
             disable_transactions_backup,
             max_batch_size,
+            require_chain_id,
         } = DefaultTxPoolValues::get_global().clone();
         Self {
             pending_max_count,
@@ -496,6 +510,7 @@ impl Default for TxPoolArgs {
             transactions_backup_path,
             disable_transactions_backup,
             max_batch_size,
+            require_chain_id,
         }
     }
 }
@@ -540,6 +555,7 @@ impl RethTransactionPoolConfig for TxPoolArgs {
             max_new_pending_txs_notifications: self.max_new_pending_txs_notifications,
             max_queued_lifetime: self.max_queued_lifetime,
             max_inflight_delegated_slot_limit: default_config.max_inflight_delegated_slot_limit,
+            require_chain_id: self.require_chain_id,
         }
     }
 
@@ -625,6 +641,7 @@ mod tests {
             transactions_backup_path: Some(PathBuf::from("/tmp/txpool-backup")),
             disable_transactions_backup: false,
             max_batch_size: 10,
+            require_chain_id: true,
         };
 
         let parsed_args = CommandParser::<TxPoolArgs>::parse_from([
@@ -685,6 +702,7 @@ mod tests {
             "/tmp/txpool-backup",
             "--txpool.max-batch-size",
             "10",
+            "--txpool.require-chain-id",
         ])
         .args;
 
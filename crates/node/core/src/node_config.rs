@@ -6,7 +6,7 @@ use crate::{
         PruningArgs, RpcServerArgs, StaticFilesArgs, StorageArgs, TxPoolArgs,
     },
     dirs::{ChainPath, DataDirPath},
-    utils::get_single_header,
+    utils::{get_single_header, SealedHeaderAsHead},
 };
 use alloy_consensus::BlockHeader;
 use alloy_eips::BlockHashOrNumber;
@@ -18,6 +18,7 @@ use reth_engine_local::MiningMode;
 use reth_ethereum_forks::{EthereumHardforks, Head};
 use reth_network_p2p::headers::client::HeadersClient;
 use reth_primitives_traits::SealedHeader;
+use reth_prune_types::PruneSegment;
 use reth_stages_types::StageId;
 use reth_storage_api::{
     BlockHashReader, DatabaseProviderFactory, HeaderProvider, StageCheckpointReader,
@@ -357,6 +358,34 @@ impl<ChainSpec> NodeConfig<ChainSpec> {
         self
     }
 
+    /// Retains receipts of the last `distance` blocks and prunes older ones, independent of the
+    /// other pruning segments.
+    ///
+    /// This is equivalent to `--prune.receipts.distance` and replaces any other receipts pruning
+    /// configuration, including the receipts log filter.
+    ///
+    /// Returns an error if the distance is lower than the minimum pruning distance of the receipts
+    /// segment, see [`PruneSegment::min_blocks`].
+    ///
+    /// Note: receipts of pruned blocks are no longer served over `eth` `GetReceipts`, the response
+    /// stops at the first pruned block, so pruned ranges show up as gaps and not as empty
+    /// receipts.
+    pub fn with_receipts_retention(mut self, distance: u64) -> eyre::Result<Self> {
+        let min_blocks = PruneSegment::Receipts.min_blocks();
+        if distance < min_blocks {
+            return Err(eyre!(
+                "receipts retention distance {distance} is below the minimum pruning distance of {min_blocks} blocks"
+            ))
+        }
+
+        self.pruning.receipts_full = false;
+        self.pruning.receipts_pre_merge = false;
+        self.pruning.receipts_before = None;
+        self.pruning.receipts_log_filter = None;
+        self.pruning.receipts_distance = Some(distance);
+        Ok(self)
+    }
+
     /// Set the storage args for the node
     pub const fn with_storage(mut self, storage: StorageArgs) -> Self {
         self.storage = storage;
@@ -427,13 +456,7 @@ impl<ChainSpec> NodeConfig<ChainSpec> {
             .block_hash(head)?
             .expect("the hash for the latest block is missing, database is corrupt");
 
-        Ok(Head {
-            number: head,
-            hash,
-            difficulty: header.difficulty(),
-            total_difficulty: U256::ZERO,
-            timestamp: header.timestamp(),
-        })
+        Ok(SealedHeader::new(header, hash).as_head(U256::ZERO))
     }
 
     /// Attempt to look up the block number for the tip hash in the database.
@@ -624,3 +647,20 @@ impl<ChainSpec> Clone for NodeConfig<ChainSpec> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reth_prune_types::PruneMode;
+
+    #[test]
+    fn receipts_retention() {
+        let min_blocks = PruneSegment::Receipts.min_blocks();
+        assert!(NodeConfig::test().with_receipts_retention(min_blocks - 1).is_err());
+
+        let config = NodeConfig::test().with_receipts_retention(min_blocks).unwrap();
+        let prune_config = config.prune_config().unwrap();
+        assert_eq!(prune_config.segments.receipts, Some(PruneMode::Distance(min_blocks)));
+        assert!(prune_config.segments.receipts_log_filter.is_empty());
+    }
+}
@@ -3,9 +3,12 @@
 
 use alloy_consensus::BlockHeader;
 use alloy_eips::BlockHashOrNumber;
+use alloy_primitives::U256;
+use alloy_rlp::Encodable;
 use alloy_rpc_types_engine::{JwtError, JwtSecret};
 use eyre::Result;
 use reth_consensus::Consensus;
+use reth_ethereum_forks::Head;
 use reth_network_p2p::{
     bodies::client::BodiesClient, headers::client::HeadersClient, priority::Priority,
 };
@@ -29,6 +32,37 @@ pub fn get_or_create_jwt_secret_from_path(path: &Path) -> Result<JwtSecret, JwtE
     }
 }
 
+/// Extension trait for converting a [`SealedHeader`] into a [`Head`].
+pub trait SealedHeaderAsHead {
+    /// Returns the [`Head`] of this header with the given total difficulty.
+    fn as_head(&self, total_difficulty: U256) -> Head;
+}
+
+impl<H: BlockHeader> SealedHeaderAsHead for SealedHeader<H> {
+    fn as_head(&self, total_difficulty: U256) -> Head {
+        Head {
+            number: self.number(),
+            hash: self.hash(),
+            difficulty: self.difficulty(),
+            total_difficulty,
+            timestamp: self.timestamp(),
+        }
+    }
+}
+
+/// Extension trait for size-budgeting [`SealedHeader`]s at the protocol layer.
+pub trait SealedHeaderEncodedSize {
+    /// Returns the RLP-encoded length of the inner header without encoding it into a buffer.
+    fn encoded_size(&self) -> usize;
+}
+
+impl<H: Encodable> SealedHeaderEncodedSize for SealedHeader<H> {
+    #[inline]
+    fn encoded_size(&self) -> usize {
+        self.header().length()
+    }
+}
+
 /// Get a single header from the network
 pub async fn get_single_header<Client>(
     client: Client,
@@ -85,3 +119,25 @@ where
 
     Ok(block)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_consensus::Header;
+    use alloy_primitives::{Bytes, B256};
+
+    #[test]
+    fn encoded_size_matches_rlp_encoding() {
+        let header = Header {
+            number: 1_000_000,
+            gas_limit: 30_000_000,
+            base_fee_per_gas: Some(7),
+            withdrawals_root: Some(B256::with_last_byte(1)),
+            extra_data: Bytes::from_static(b"reth"),
+            ..Default::default()
+        };
+        let sealed = SealedHeader::seal_slow(header.clone());
+
+        assert_eq!(sealed.encoded_size(), alloy_rlp::encode(&header).len());
+    }
+}
@@ -132,40 +132,46 @@ impl MetricServer {
 
         tracing::info!(target: "reth::cli", "Starting metrics endpoint at {}", listener.local_addr().unwrap());
 
-        task_executor.spawn_with_graceful_shutdown_signal(async move |mut signal| loop {
-            let io = tokio::select! {
-                _ = &mut signal => break,
-                io = listener.accept() => {
-                    match io {
-                        Ok((stream, _remote_addr)) => stream,
-                        Err(err) => {
-                            tracing::error!(%err, "failed to accept connection");
-                            continue;
+        task_executor.spawn_with_graceful_shutdown_signal(
+            "metrics endpoint",
+            async move |mut signal| loop {
+                let io = tokio::select! {
+                    _ = &mut signal => break,
+                    io = listener.accept() => {
+                        match io {
+                            Ok((stream, _remote_addr)) => stream,
+                            Err(err) => {
+                                tracing::error!(%err, "failed to accept connection");
+                                continue;
+                            }
                         }
                     }
-                }
-            };
+                };
 
-            let handle = install_prometheus_recorder();
-            let hook = hook.clone();
-            let pprof_dump_dir = pprof_dump_dir.clone();
-            let service = tower::service_fn(move |req: Request<_>| {
+                let handle = install_prometheus_recorder();
                 let hook = hook.clone();
                 let pprof_dump_dir = pprof_dump_dir.clone();
-                async move {
-                    let response =
-                        handle_request(req.uri().path(), &*hook, handle, &pprof_dump_dir).await;
-                    Ok::<_, Infallible>(response)
-                }
-            });
-
-            let mut shutdown = signal.clone().ignore_guard();
-            tokio::task::spawn(async move {
-                let _ = jsonrpsee_server::serve_with_graceful_shutdown(io, service, &mut shutdown)
-                    .await
-                    .inspect_err(|error| tracing::debug!(%error, "failed to serve request"));
-            });
-        });
+                let service = tower::service_fn(move |req: Request<_>| {
+                    let hook = hook.clone();
+                    let pprof_dump_dir = pprof_dump_dir.clone();
+                    async move {
+                        let response =
+                            handle_request(req.uri().path(), &*hook, handle, &pprof_dump_dir).await;
+                        Ok::<_, Infallible>(response)
+                    }
+                });
+
+                let mut shutdown = signal.clone().ignore_guard();
+                tokio::task::spawn(async move {
+                    let _ =
+                        jsonrpsee_server::serve_with_graceful_shutdown(io, service, &mut shutdown)
+                            .await
+                            .inspect_err(
+                                |error| tracing::debug!(%error, "failed to serve request"),
+                            );
+                });
+            },
+        );
 
         Ok(())
     }
@@ -181,7 +187,7 @@ impl MetricServer {
         let client = Client::builder()
             .build()
             .wrap_err("Could not create HTTP client to push metrics to gateway")?;
-        task_executor.spawn_with_graceful_shutdown_signal(async move |mut signal| {
+        task_executor.spawn_with_graceful_shutdown_signal("metrics push gateway", async move |mut signal| {
             tracing::info!(url = %url, interval = ?interval, "Starting task to push metrics to gateway");
             let handle = install_prometheus_recorder();
             loop {
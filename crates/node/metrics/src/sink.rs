@@ -0,0 +1,69 @@
+//! External metrics sinks.
+
+use crate::recorder::install_prometheus_recorder;
+use reth_tasks::TaskExecutor;
+use std::{fmt, time::Duration};
+
+/// A sink that periodically receives a snapshot of the node's metrics.
+///
+/// This can be used to integrate with collectors that don't scrape the Prometheus endpoint. The
+/// built-in exporter is not affected by registered sinks.
+pub trait MetricsSink: Send + Sync {
+    /// Receives a snapshot of all metrics, rendered in the Prometheus text exposition format.
+    fn on_snapshot(&self, snapshot: &str);
+}
+
+impl<F> MetricsSink for F
+where
+    F: Fn(&str) + Send + Sync,
+{
+    fn on_snapshot(&self, snapshot: &str) {
+        self(snapshot)
+    }
+}
+
+impl MetricsSink for tokio::sync::mpsc::UnboundedSender<String> {
+    fn on_snapshot(&self, snapshot: &str) {
+        let _ = self.send(snapshot.to_string());
+    }
+}
+
+/// A type-erased [`MetricsSink`].
+pub struct BoxedMetricsSink(Box<dyn MetricsSink>);
+
+impl BoxedMetricsSink {
+    /// Wraps the given sink.
+    pub fn new(sink: impl MetricsSink + 'static) -> Self {
+        Self(Box::new(sink))
+    }
+
+    /// Spawns a task that renders the global Prometheus recorder every `interval` and forwards
+    /// the snapshot to the sink.
+    ///
+    /// The task shuts down with the node.
+    pub fn spawn(self, interval: Duration, task_executor: &TaskExecutor) {
+        task_executor.spawn_with_graceful_shutdown_signal(
+            "metrics sink",
+            async move |mut signal| {
+                let handle = install_prometheus_recorder();
+                loop {
+                    tokio::select! {
+                        _ = &mut signal => {
+                            tracing::debug!("Shutting down metrics sink task");
+                            break;
+                        }
+                        _ = tokio::time::sleep(interval) => {
+                            self.0.on_snapshot(&handle.handle().render());
+                        }
+                    }
+                }
+            },
+        );
+    }
+}
+
+impl fmt::Debug for BoxedMetricsSink {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BoxedMetricsSink").finish_non_exhaustive()
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{segments::SegmentSet, Pruner};
+use crate::{segments::SegmentSet, Pruner, SharedPruneFilter};
 use alloy_eips::eip2718::Encodable2718;
 use reth_config::PruneConfig;
 use reth_db_api::{table::Value, transaction::DbTxMut};
@@ -29,6 +29,8 @@ pub struct PrunerBuilder {
     minimum_pruning_distance: Option<u64>,
     /// The finished height of all `ExEx`'s.
     finished_exex_height: watch::Receiver<FinishedExExHeight>,
+    /// Filter that exempts data from pruning.
+    filter: Option<SharedPruneFilter>,
 }
 
 impl PrunerBuilder {
@@ -80,6 +82,14 @@ impl PrunerBuilder {
         self
     }
 
+    /// Sets the filter that decides whether data eligible for pruning is kept anyway.
+    ///
+    /// See [`PruneFilter`](crate::PruneFilter) for the performance impact.
+    pub fn filter(mut self, filter: SharedPruneFilter) -> Self {
+        self.filter = Some(filter);
+        self
+    }
+
     /// Builds a [Pruner] from the current configuration with the given provider factory.
     pub fn build_with_provider_factory<PF>(self, provider_factory: PF) -> Pruner<PF::ProviderRW, PF>
     where
@@ -100,8 +110,11 @@ impl PrunerBuilder {
                 Primitives = <PF::ProviderRW as NodePrimitivesProvider>::Primitives,
             >,
     {
-        let segments =
-            SegmentSet::from_components(provider_factory.static_file_provider(), self.segments);
+        let segments = SegmentSet::from_components_with_filter(
+            provider_factory.static_file_provider(),
+            self.segments,
+            self.filter,
+        );
 
         let mut pruner = Pruner::new_with_factory(
             provider_factory,
@@ -136,7 +149,11 @@ impl PrunerBuilder {
             + StorageChangeSetReader
             + RocksDBProviderFactory,
     {
-        let segments = SegmentSet::<Provider>::from_components(static_file_provider, self.segments);
+        let segments = SegmentSet::<Provider>::from_components_with_filter(
+            static_file_provider,
+            self.segments,
+            self.filter,
+        );
 
         let mut pruner = Pruner::new(
             segments.into_vec(),
@@ -161,6 +178,7 @@ impl Default for PrunerBuilder {
             timeout: None,
             minimum_pruning_distance: None,
             finished_exex_height: watch::channel(FinishedExExHeight::NoExExs).1,
+            filter: None,
         }
     }
 }
@@ -0,0 +1,54 @@
+use alloy_primitives::{Log, TxNumber};
+use std::{fmt, sync::Arc};
+
+/// Decides whether data that is eligible for pruning is kept anyway.
+///
+/// This allows custom retention rules on top of the configured
+/// [`PruneModes`](reth_prune_types::PruneModes), e.g. keeping all receipts of specific contracts.
+///
+/// Receipts stored in the database are exempted individually. Receipts stored in static files are
+/// pruned by deleting whole files, so they can't be exempted individually. Instead, no static file
+/// at or above the block of the first exempt receipt is deleted, and every pruner run reads the
+/// receipts up to that block again.
+///
+/// The filter is called for every row the pruner visits, so it should be cheap. The delete limit
+/// of a pruner run only counts deleted rows, so a filter that exempts a lot of data makes every
+/// run walk over more rows before the limit is reached, and the exempt rows keep the tables from
+/// shrinking.
+pub trait PruneFilter: Send + Sync {
+    /// Returns `true` if the receipt of transaction `tx_num` that emitted `logs` must not be
+    /// pruned.
+    fn is_receipt_exempt(&self, tx_num: TxNumber, logs: &[Log]) -> bool;
+}
+
+impl<F> PruneFilter for F
+where
+    F: Fn(TxNumber, &[Log]) -> bool + Send + Sync,
+{
+    fn is_receipt_exempt(&self, tx_num: TxNumber, logs: &[Log]) -> bool {
+        self(tx_num, logs)
+    }
+}
+
+/// A shareable [`PruneFilter`].
+#[derive(Clone)]
+pub struct SharedPruneFilter(Arc<dyn PruneFilter>);
+
+impl SharedPruneFilter {
+    /// Creates a new [`SharedPruneFilter`] from the given filter.
+    pub fn new(filter: impl PruneFilter + 'static) -> Self {
+        Self(Arc::new(filter))
+    }
+
+    /// Returns `true` if the receipt of transaction `tx_num` that emitted `logs` must not be
+    /// pruned, see [`PruneFilter::is_receipt_exempt`].
+    pub fn is_receipt_exempt(&self, tx_num: TxNumber, logs: &[Log]) -> bool {
+        self.0.is_receipt_exempt(tx_num, logs)
+    }
+}
+
+impl fmt::Debug for SharedPruneFilter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SharedPruneFilter").finish_non_exhaustive()
+    }
+}
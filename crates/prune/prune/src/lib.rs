@@ -12,6 +12,7 @@
 mod builder;
 mod db_ext;
 mod error;
+mod filter;
 mod limiter;
 mod metrics;
 mod pruner;
@@ -20,6 +21,7 @@ pub mod segments;
 use crate::metrics::Metrics;
 pub use builder::PrunerBuilder;
 pub use error::PrunerError;
+pub use filter::{PruneFilter, SharedPruneFilter};
 pub use limiter::PruneLimiter;
 pub use pruner::{Pruner, PrunerResult, PrunerWithFactory, PrunerWithResult};
 
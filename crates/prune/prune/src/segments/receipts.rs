@@ -6,22 +6,27 @@
 use crate::{
     db_ext::DbTxPruneExt,
     segments::{self, PruneInput},
-    PrunerError,
+    PrunerError, SharedPruneFilter,
 };
+use alloy_consensus::TxReceipt;
+use alloy_primitives::BlockNumber;
 use reth_db_api::{table::Value, tables, transaction::DbTxMut};
 use reth_primitives_traits::NodePrimitives;
 use reth_provider::{
     errors::provider::ProviderResult, BlockReader, DBProvider, EitherWriter,
-    NodePrimitivesProvider, PruneCheckpointWriter, StaticFileProviderFactory, StorageSettingsCache,
-    TransactionsProvider,
+    NodePrimitivesProvider, PruneCheckpointWriter, ReceiptProvider, StaticFileProviderFactory,
+    StorageSettingsCache, TransactionsProvider,
+};
+use reth_prune_types::{
+    PruneCheckpoint, PruneProgress, PruneSegment, SegmentOutput, SegmentOutputCheckpoint,
 };
-use reth_prune_types::{PruneCheckpoint, PruneSegment, SegmentOutput, SegmentOutputCheckpoint};
 use reth_static_file_types::StaticFileSegment;
 use tracing::{debug, trace};
 
 pub(crate) fn prune<Provider>(
     provider: &Provider,
     input: PruneInput,
+    filter: Option<&SharedPruneFilter>,
 ) -> Result<SegmentOutput, PrunerError>
 where
     Provider: DBProvider<Tx: DbTxMut>
@@ -33,6 +38,24 @@ where
 {
     if EitherWriter::receipts_destination(provider).is_static_file() {
         debug!(target: "pruner", "Pruning receipts from static files.");
+        let mut input = input;
+        if let Some(filter) = filter &&
+            let Some(exempt_block) = first_exempt_static_file_block(provider, &input, filter)?
+        {
+            // Static files can only be deleted as a whole, so stop right before the file that
+            // contains the exempt receipt.
+            debug!(target: "pruner", %exempt_block, "Receipt exempt by prune filter, not pruning static files from its block");
+            let Some(to_block) = exempt_block.checked_sub(1) else {
+                return Ok(SegmentOutput {
+                    progress: PruneProgress::Finished,
+                    pruned: 0,
+                    checkpoint: input
+                        .previous_checkpoint
+                        .map(SegmentOutputCheckpoint::from_prune_checkpoint),
+                })
+            };
+            input.to_block = to_block;
+        }
         return segments::prune_static_files(provider, input, StaticFileSegment::Receipts)
     }
     debug!(target: "pruner", "Pruning receipts from database.");
@@ -49,17 +72,32 @@ where
 
     let mut limiter = input.limiter;
 
-    let mut last_pruned_transaction = tx_range_end;
+    let mut last_pruned_transaction = None;
+    let mut last_skipped_transaction = None;
     let (pruned, done) = provider.tx_ref().prune_table_with_range::<tables::Receipts<
         <Provider::Primitives as NodePrimitives>::Receipt,
     >>(
         tx_range,
         &mut limiter,
-        |_| false,
-        |row| last_pruned_transaction = row.0,
+        |(tx_num, receipt)| {
+            let skip =
+                filter.is_some_and(|filter| filter.is_receipt_exempt(*tx_num, receipt.logs()));
+            if skip {
+                last_skipped_transaction = Some(*tx_num);
+            }
+            skip
+        },
+        |row| last_pruned_transaction = Some(row.0),
     )?;
     trace!(target: "pruner", %pruned, %done, "Pruned receipts");
 
+    // Receipts exempt by the filter are kept, but they were checked, so the checkpoint can move
+    // past them.
+    let last_pruned_transaction = last_pruned_transaction
+        .max(last_skipped_transaction)
+        .filter(|_| !done)
+        .unwrap_or(tx_range_end);
+
     let last_pruned_block = provider
         .block_by_transaction_id(last_pruned_transaction)?
         .ok_or(PrunerError::InconsistentData("Block for transaction is not found"))?
@@ -79,6 +117,52 @@ where
     })
 }
 
+/// Number of static file receipts that are read at once when looking for exempt receipts.
+const EXEMPT_RECEIPTS_SCAN_CHUNK: u64 = 10_000;
+
+/// Returns the block of the first receipt in static files up to `input.to_block` that is exempt
+/// from pruning by the given filter.
+fn first_exempt_static_file_block<Provider>(
+    provider: &Provider,
+    input: &PruneInput,
+    filter: &SharedPruneFilter,
+) -> Result<Option<BlockNumber>, PrunerError>
+where
+    Provider: TransactionsProvider + BlockReader + StaticFileProviderFactory,
+{
+    let Some(tx_range) = input.get_next_tx_num_range(provider)? else { return Ok(None) };
+
+    // Receipts below the lowest static file are already gone.
+    let Some(lowest_block) =
+        provider.static_file_provider().get_lowest_range_start(StaticFileSegment::Receipts)
+    else {
+        return Ok(None)
+    };
+    let lowest_tx = provider
+        .block_body_indices(lowest_block)?
+        .map(|indices| indices.first_tx_num())
+        .unwrap_or_default();
+
+    let mut chunk_start = (*tx_range.start()).max(lowest_tx);
+    while chunk_start <= *tx_range.end() {
+        let chunk_end = (chunk_start + EXEMPT_RECEIPTS_SCAN_CHUNK - 1).min(*tx_range.end());
+        let receipts = provider.receipts_by_tx_range(chunk_start..=chunk_end)?;
+        if let Some(tx_num) = (chunk_start..)
+            .zip(&receipts)
+            .find(|(tx_num, receipt)| filter.is_receipt_exempt(*tx_num, receipt.logs()))
+            .map(|(tx_num, _)| tx_num)
+        {
+            let block = provider
+                .block_by_transaction_id(tx_num)?
+                .ok_or(PrunerError::InconsistentData("Block for transaction is not found"))?;
+            return Ok(Some(block))
+        }
+        chunk_start = chunk_end + 1;
+    }
+
+    Ok(None)
+}
+
 pub(crate) fn save_checkpoint(
     provider: impl PruneCheckpointWriter,
     checkpoint: PruneCheckpoint,
@@ -94,19 +178,28 @@ pub(crate) fn save_checkpoint(
 
 #[cfg(test)]
 mod tests {
-    use crate::segments::{PruneInput, PruneLimiter, SegmentOutput};
-    use alloy_primitives::{BlockNumber, TxNumber, B256};
+    use crate::{
+        segments::{PruneInput, PruneLimiter, SegmentOutput},
+        SharedPruneFilter,
+    };
+    use alloy_primitives::{BlockNumber, Log, TxNumber, B256};
     use assert_matches::assert_matches;
     use itertools::{
         FoldWhile::{Continue, Done},
         Itertools,
     };
+    use reth_db::test_utils::create_test_static_files_dir;
     use reth_db_api::tables;
-    use reth_provider::{DBProvider, DatabaseProviderFactory, PruneCheckpointReader};
+    use reth_provider::{
+        ChainSpecProvider, DBProvider, DatabaseProviderFactory, ProviderFactory,
+        PruneCheckpointReader, ReceiptProvider, RocksDBProviderFactory, StaticFileProviderBuilder,
+        StaticFileProviderFactory,
+    };
     use reth_prune_types::{
         PruneCheckpoint, PruneInterruptReason, PruneMode, PruneProgress, PruneSegment,
     };
     use reth_stages::test_utils::{StorageKind, TestStageDB};
+    use reth_static_file_types::StaticFileSegment;
     use reth_testing_utils::generators::{
         self, random_block_range, random_receipt, BlockRangeParams,
     };
@@ -188,7 +281,7 @@ mod tests {
                 .sub(1);
 
             let provider = db.factory.database_provider_rw().unwrap();
-            let result = super::prune(&provider, input).unwrap();
+            let result = super::prune(&provider, input, None).unwrap();
             limiter.increment_deleted_entries_count_by(result.pruned);
 
             assert_matches!(
@@ -244,4 +337,113 @@ mod tests {
         test_prune(6, (PruneProgress::Finished, 2));
         test_prune(10, (PruneProgress::Finished, 8));
     }
+
+    #[test]
+    fn prune_legacy_with_filter() {
+        let mut db = TestStageDB::default();
+        db.factory = db.factory.with_prune_modes(reth_prune_types::PruneModes {
+            receipts: Some(PruneMode::Full),
+            ..Default::default()
+        });
+        let mut rng = generators::rng();
+
+        let blocks = random_block_range(
+            &mut rng,
+            1..=10,
+            BlockRangeParams { parent: Some(B256::ZERO), tx_count: 2..3, ..Default::default() },
+        );
+        db.insert_blocks(blocks.iter(), StorageKind::Database(None)).expect("insert blocks");
+
+        let receipts = blocks
+            .iter()
+            .flat_map(|block| &block.body().transactions)
+            .enumerate()
+            .map(|(tx_num, transaction)| {
+                (tx_num as u64, random_receipt(&mut rng, transaction, Some(0), None))
+            })
+            .collect::<Vec<_>>();
+        let receipts_len = receipts.len();
+        db.insert_receipts(receipts).expect("insert receipts");
+
+        // keep the receipts of all even transactions
+        let filter = SharedPruneFilter::new(|tx_num: TxNumber, _: &[Log]| tx_num % 2 == 0);
+        let to_block = blocks.last().unwrap().number;
+        let input =
+            PruneInput { previous_checkpoint: None, to_block, limiter: PruneLimiter::default() };
+
+        let provider = db.factory.database_provider_rw().unwrap();
+        let result = super::prune(&provider, input, Some(&filter)).unwrap();
+        provider.commit().expect("commit");
+
+        assert!(result.progress.is_finished());
+        assert_eq!(result.pruned, receipts_len / 2);
+        assert_eq!(result.checkpoint.unwrap().tx_number, Some(receipts_len as TxNumber - 1));
+        assert!(db.table::<tables::Receipts>().unwrap().iter().all(|(tx_num, _)| tx_num % 2 == 0));
+    }
+
+    #[test]
+    fn prune_static_files_with_filter() {
+        let mut db = TestStageDB::default();
+        let (_static_dir, static_dir_path) = create_test_static_files_dir();
+        db.factory = ProviderFactory::new(
+            db.factory.db_ref().clone(),
+            db.factory.chain_spec(),
+            StaticFileProviderBuilder::read_write(static_dir_path)
+                .with_blocks_per_file(2)
+                .build()
+                .unwrap(),
+            db.factory.rocksdb_provider(),
+            reth_tasks::Runtime::test(),
+        )
+        .unwrap();
+        let mut rng = generators::rng();
+
+        // 10 blocks with 2 transactions each, stored in static files of 2 blocks each
+        let blocks = random_block_range(
+            &mut rng,
+            0..=9,
+            BlockRangeParams { parent: Some(B256::ZERO), tx_count: 2..3, ..Default::default() },
+        );
+        db.insert_blocks(blocks.iter(), StorageKind::Static).expect("insert blocks");
+
+        let mut tx_num = 0;
+        let receipts = blocks
+            .iter()
+            .map(|block| {
+                let receipts = block
+                    .body()
+                    .transactions
+                    .iter()
+                    .map(|transaction| {
+                        tx_num += 1;
+                        (tx_num - 1, random_receipt(&mut rng, transaction, Some(0), None))
+                    })
+                    .collect::<Vec<_>>();
+                (block.number, receipts)
+            })
+            .collect::<Vec<_>>();
+        db.insert_receipts_by_block(receipts, StorageKind::Static).expect("insert receipts");
+
+        // keep the receipt of the first transaction of block 5
+        let filter = SharedPruneFilter::new(|tx_num: TxNumber, _: &[Log]| tx_num == 10);
+        let input =
+            PruneInput { previous_checkpoint: None, to_block: 9, limiter: PruneLimiter::default() };
+
+        let provider = db.factory.database_provider_rw().unwrap();
+        let result = super::prune(&provider, input, Some(&filter)).unwrap();
+        provider.commit().expect("commit");
+
+        // only the files of blocks 0..=3 are deleted, the file of blocks 4..=5 contains the exempt
+        // receipt and everything after it is kept as well
+        assert!(result.progress.is_finished());
+        assert_eq!(result.pruned, 8);
+        let checkpoint = result.checkpoint.unwrap();
+        assert_eq!(checkpoint.block_number, Some(3));
+        assert_eq!(checkpoint.tx_number, Some(7));
+        assert_eq!(
+            db.factory.static_file_provider().get_lowest_range_start(StaticFileSegment::Receipts),
+            Some(4)
+        );
+        assert!(db.factory.provider().unwrap().receipt(10).unwrap().is_some());
+    }
 }
@@ -1,6 +1,9 @@
-use crate::segments::{
-    user::ReceiptsByLogs, AccountHistory, Bodies, Segment, SenderRecovery, StorageHistory,
-    TransactionLookup, UserReceipts,
+use crate::{
+    segments::{
+        user::ReceiptsByLogs, AccountHistory, Bodies, Segment, SenderRecovery, StorageHistory,
+        TransactionLookup, UserReceipts,
+    },
+    SharedPruneFilter,
 };
 use alloy_eips::eip2718::Encodable2718;
 use reth_db_api::{table::Value, transaction::DbTxMut};
@@ -62,8 +65,18 @@ where
     /// Creates a [`SegmentSet`] from an existing components, such as [`StaticFileProvider`] and
     /// [`PruneModes`].
     pub fn from_components(
+        static_file_provider: StaticFileProvider<Provider::Primitives>,
+        prune_modes: PruneModes,
+    ) -> Self {
+        Self::from_components_with_filter(static_file_provider, prune_modes, None)
+    }
+
+    /// Creates a [`SegmentSet`] like [`Self::from_components`], with the receipt segments
+    /// consulting the given [`SharedPruneFilter`] before pruning a receipt.
+    pub fn from_components_with_filter(
         _static_file_provider: StaticFileProvider<Provider::Primitives>,
         prune_modes: PruneModes,
+        filter: Option<SharedPruneFilter>,
     ) -> Self {
         let PruneModes {
             sender_recovery,
@@ -86,11 +99,11 @@ where
             // Storage history
             .segment_opt(storage_history.map(StorageHistory::new))
             // User receipts
-            .segment_opt(receipts.map(UserReceipts::new))
+            .segment_opt(receipts.map(|mode| UserReceipts::new(mode).with_filter(filter.clone())))
             // Receipts by logs
             .segment_opt(
                 (!receipts_log_filter.is_empty())
-                    .then(|| ReceiptsByLogs::new(receipts_log_filter.clone())),
+                    .then(|| ReceiptsByLogs::new(receipts_log_filter.clone()).with_filter(filter)),
             )
             // Sender recovery
             .segment_opt(sender_recovery.map(SenderRecovery::new))
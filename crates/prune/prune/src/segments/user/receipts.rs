@@ -1,6 +1,6 @@
 use crate::{
     segments::{PruneInput, Segment},
-    PrunerError,
+    PrunerError, SharedPruneFilter,
 };
 use reth_db_api::{table::Value, transaction::DbTxMut};
 use reth_primitives_traits::NodePrimitives;
@@ -14,11 +14,17 @@ use tracing::instrument;
 #[derive(Debug)]
 pub struct Receipts {
     mode: PruneMode,
+    filter: Option<SharedPruneFilter>,
 }
 
 impl Receipts {
     pub const fn new(mode: PruneMode) -> Self {
-        Self { mode }
+        Self { mode, filter: None }
+    }
+
+    pub fn with_filter(mut self, filter: Option<SharedPruneFilter>) -> Self {
+        self.filter = filter;
+        self
     }
 }
 
@@ -51,7 +57,7 @@ where
         ret(level = "trace")
     )]
     fn prune(&self, provider: &Provider, input: PruneInput) -> Result<SegmentOutput, PrunerError> {
-        crate::segments::receipts::prune(provider, input)
+        crate::segments::receipts::prune(provider, input, self.filter.as_ref())
     }
 
     fn save_checkpoint(
@@ -1,7 +1,7 @@
 use crate::{
     db_ext::DbTxPruneExt,
     segments::{PruneInput, Segment},
-    PrunerError,
+    PrunerError, SharedPruneFilter,
 };
 use alloy_consensus::TxReceipt;
 use reth_db_api::{table::Value, tables, transaction::DbTxMut};
@@ -17,11 +17,17 @@ use tracing::{instrument, trace};
 #[derive(Debug)]
 pub struct ReceiptsByLogs {
     config: ReceiptsLogPruneConfig,
+    filter: Option<SharedPruneFilter>,
 }
 
 impl ReceiptsByLogs {
     pub const fn new(config: ReceiptsLogPruneConfig) -> Self {
-        Self { config }
+        Self { config, filter: None }
+    }
+
+    pub fn with_filter(mut self, filter: Option<SharedPruneFilter>) -> Self {
+        self.filter = filter;
+        self
     }
 }
 
@@ -160,9 +166,12 @@ where
                 tx_range,
                 &mut limiter,
                 |(tx_num, receipt)| {
-                    let skip = num_addresses > 0 &&
+                    let skip = (num_addresses > 0 &&
                         receipt.logs().iter().any(|log| {
                             filtered_addresses[..num_addresses].contains(&&log.address)
+                        })) ||
+                        self.filter.as_ref().is_some_and(|filter| {
+                            filter.is_receipt_exempt(*tx_num, receipt.logs())
                         });
 
                     if skip {
@@ -468,6 +468,13 @@ impl AuthServerHandle {
         Ok(())
     }
 
+    /// Returns a future that resolves once the http and ipc servers have stopped.
+    pub async fn stopped(self) {
+        for handle in [self.handle, self.ipc_handle].into_iter().flatten() {
+            handle.stopped().await;
+        }
+    }
+
     /// Returns the url to the http server
     pub fn http_url(&self) -> String {
         format!("http://{}", self.local_addr)
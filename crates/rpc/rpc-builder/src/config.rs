@@ -9,8 +9,8 @@ use tower::layer::util::Identity;
 use tracing::{debug, warn};
 
 use crate::{
-    auth::AuthServerConfig, error::RpcError, IpcServerBuilder, RpcModuleConfig, RpcServerConfig,
-    TransportRpcModuleConfig,
+    auth::AuthServerConfig, error::RpcError, Http2Config, IpcServerBuilder, RpcModuleConfig,
+    RpcServerConfig, TransportRpcModuleConfig,
 };
 
 /// A trait that provides a configured RPC server.
@@ -159,7 +159,7 @@ impl RethRpcServerConfig for RpcServerArgs {
             );
         }
 
-        if self.is_ipc_enabled() {
+        if self.is_ipc_enabled() || self.rpc_uds_path.is_some() {
             config = config.with_ipc(RpcModuleSelection::default_ipc_modules());
         }
 
@@ -206,7 +206,11 @@ impl RethRpcServerConfig for RpcServerArgs {
                 .with_http_address(socket_address)
                 .with_http(self.http_ws_server_builder())
                 .with_http_cors(self.http_corsdomain.clone())
-                .with_http_disable_compression(self.http_disable_compression);
+                .with_http_disable_compression(self.http_disable_compression)
+                .with_http2(self.http2.then(|| Http2Config {
+                    max_concurrent_streams: self.http2_max_concurrent_streams,
+                    max_connections: Some(self.rpc_max_connections.get()),
+                }));
         }
 
         if self.ws {
@@ -223,6 +227,12 @@ impl RethRpcServerConfig for RpcServerArgs {
                 config.with_ipc(self.ipc_server_builder()).with_ipc_endpoint(self.ipcpath.clone());
         }
 
+        if let Some(uds_path) = &self.rpc_uds_path {
+            config = config
+                .with_uds(self.ipc_server_builder())
+                .with_uds_endpoint(uds_path.to_string_lossy());
+        }
+
         config
     }
 
@@ -127,6 +127,9 @@ pub enum WsHttpSamePortError {
     /// Ws and http server configured on same port but with different modules.
     #[error("{0}")]
     ConflictingModules(Box<ConflictingModules>),
+    /// HTTP/2 was enabled for the http server, but it shares its port with the ws server.
+    #[error("HTTP/2 is not supported when HTTP and WS are on the same port")]
+    Http2NotSupported,
 }
 
 #[cfg(test)]
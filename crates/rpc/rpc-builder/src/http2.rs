@@ -0,0 +1,239 @@
+//! HTTP server with configurable HTTP/2 support.
+//!
+//! The default jsonrpsee server does not expose the HTTP/2 connection settings, this serves the
+//! jsonrpsee service on a custom accept loop instead.
+
+use http::{Request, Response};
+use hyper::body::Incoming;
+use hyper_util::{
+    rt::{TokioExecutor, TokioIo},
+    server::conn::auto::Builder,
+    service::TowerToHyperService,
+};
+use jsonrpsee::{
+    server::{stop_channel, ServerHandle, StopHandle, TowerService, TowerServiceBuilder},
+    Methods,
+};
+use std::error::Error;
+use tokio::{
+    net::{TcpListener, TcpStream},
+    task::JoinSet,
+};
+use tracing::debug;
+
+/// Settings of the HTTP/2 connections of the HTTP RPC server.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Http2Config {
+    /// The maximum number of concurrent streams per connection. If `None`, the default of
+    /// [`hyper`] is used.
+    pub max_concurrent_streams: Option<u32>,
+    /// The maximum number of open connections. Connections accepted beyond this limit are closed
+    /// immediately. If `None`, the number of connections is not limited.
+    pub max_connections: Option<u32>,
+}
+
+impl Http2Config {
+    /// Sets the maximum number of concurrent streams per connection.
+    pub const fn with_max_concurrent_streams(mut self, max_concurrent_streams: u32) -> Self {
+        self.max_concurrent_streams = Some(max_concurrent_streams);
+        self
+    }
+
+    /// Sets the maximum number of open connections.
+    pub const fn with_max_connections(mut self, max_connections: u32) -> Self {
+        self.max_connections = Some(max_connections);
+        self
+    }
+}
+
+/// Serves the service built by `svc_builder` on all connections accepted by `listener`.
+///
+/// Connections are served over HTTP/1.1 or HTTP/2 with prior knowledge, depending on what the
+/// client speaks. Connections that exceed [`Http2Config::max_connections`] are closed right after
+/// they are accepted.
+///
+/// The connection tasks are owned by the accept loop: once the server is stopped, no new
+/// connections are accepted and the open ones are shut down gracefully. The returned handle only
+/// reports the server as stopped after all connections are closed.
+pub(crate) fn start<RpcMiddleware, HttpMiddleware, B>(
+    listener: TcpListener,
+    svc_builder: TowerServiceBuilder<RpcMiddleware, HttpMiddleware>,
+    methods: impl Into<Methods>,
+    config: Http2Config,
+) -> ServerHandle
+where
+    RpcMiddleware: Clone + Send + 'static,
+    HttpMiddleware: Clone + Send + 'static,
+    TowerService<RpcMiddleware, HttpMiddleware>:
+        tower::Service<Request<Incoming>, Response = Response<B>> + Clone + Send + 'static,
+    <TowerService<RpcMiddleware, HttpMiddleware> as tower::Service<Request<Incoming>>>::Future:
+        Send,
+    <TowerService<RpcMiddleware, HttpMiddleware> as tower::Service<Request<Incoming>>>::Error:
+        Into<Box<dyn Error + Send + Sync>>,
+    B: http_body::Body + Send + 'static,
+    B::Data: Send,
+    B::Error: Into<Box<dyn Error + Send + Sync>>,
+{
+    let methods = methods.into();
+    let (stop_handle, server_handle) = stop_channel();
+
+    let mut conn_builder = Builder::new(TokioExecutor::new());
+    conn_builder.http2().max_concurrent_streams(config.max_concurrent_streams);
+
+    tokio::spawn(async move {
+        let mut connections = JoinSet::new();
+        loop {
+            let (sock, remote_addr) = tokio::select! {
+                res = listener.accept() => match res {
+                    Ok(conn) => conn,
+                    Err(err) => {
+                        debug!(target: "rpc", %err, "failed to accept http connection");
+                        continue
+                    }
+                },
+                // reap closed connections
+                Some(_) = connections.join_next(), if !connections.is_empty() => continue,
+                _ = stop_handle.clone().shutdown() => break,
+            };
+
+            // reap connections that were closed in the meantime before checking the limit
+            while connections.try_join_next().is_some() {}
+            if config.max_connections.is_some_and(|max| connections.len() >= max as usize) {
+                debug!(target: "rpc", %remote_addr, "too many http connections, closing connection");
+                drop(sock);
+                continue
+            }
+
+            let svc = svc_builder.clone().build(methods.clone(), stop_handle.clone());
+            connections.spawn(serve_connection(
+                sock,
+                svc,
+                conn_builder.clone(),
+                stop_handle.clone(),
+            ));
+        }
+
+        // stop accepting connections and wait for the open ones to shut down, the server is
+        // stopped once the last stop handle is dropped
+        drop(listener);
+        while connections.join_next().await.is_some() {}
+    });
+
+    server_handle
+}
+
+/// Serves a single connection until it is closed or the server is stopped.
+async fn serve_connection<S, B>(
+    sock: TcpStream,
+    svc: S,
+    conn_builder: Builder<TokioExecutor>,
+    stop_handle: StopHandle,
+) where
+    S: tower::Service<Request<Incoming>, Response = Response<B>> + Clone + Send + 'static,
+    S::Future: Send,
+    S::Error: Into<Box<dyn Error + Send + Sync>>,
+    B: http_body::Body + Send + 'static,
+    B::Data: Send,
+    B::Error: Into<Box<dyn Error + Send + Sync>>,
+{
+    let conn = conn_builder
+        .serve_connection_with_upgrades(TokioIo::new(sock), TowerToHyperService::new(svc));
+    tokio::pin!(conn);
+
+    let res = tokio::select! {
+        res = conn.as_mut() => res,
+        _ = stop_handle.shutdown() => {
+            conn.as_mut().graceful_shutdown();
+            conn.await
+        }
+    };
+    if let Err(err) = res {
+        debug!(target: "rpc", %err, "http connection closed with error");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use http_body_util::BodyExt;
+    use jsonrpsee::{server::Server, RpcModule};
+    use std::time::Duration;
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn serve_http2_and_shut_down() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let mut module = RpcModule::new(());
+        module.register_method("anything", |_, _, _| "succeed").unwrap();
+        let handle = start(
+            listener,
+            Server::builder().to_service_builder(),
+            module,
+            Http2Config::default().with_max_concurrent_streams(1),
+        );
+
+        let stream = TcpStream::connect(addr).await.unwrap();
+        let (mut sender, conn) =
+            hyper::client::conn::http2::handshake(TokioExecutor::new(), TokioIo::new(stream))
+                .await
+                .unwrap();
+        let conn = tokio::spawn(conn);
+
+        let request = Request::post("/")
+            .header(http::header::CONTENT_TYPE, "application/json")
+            .body(r#"{"jsonrpc":"2.0","id":1,"method":"anything","params":[]}"#.to_string())
+            .unwrap();
+        let response = sender.send_request(request).await.unwrap();
+        assert_eq!(response.version(), http::Version::HTTP_2);
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let body: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(body["result"], "succeed");
+
+        // the open connection is closed by the server before it reports that it stopped
+        handle.stop().unwrap();
+        tokio::time::timeout(Duration::from_secs(5), handle.stopped()).await.unwrap();
+        tokio::time::timeout(Duration::from_secs(5), conn).await.unwrap().unwrap().ok();
+        assert!(TcpStream::connect(addr).await.is_err());
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn closes_connections_above_limit() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let mut module = RpcModule::new(());
+        module.register_method("anything", |_, _, _| "succeed").unwrap();
+        let handle = start(
+            listener,
+            Server::builder().to_service_builder(),
+            module,
+            Http2Config::default().with_max_connections(1),
+        );
+
+        let connect = || async {
+            let stream = TcpStream::connect(addr).await?;
+            let (sender, conn) =
+                hyper::client::conn::http2::handshake(TokioExecutor::new(), TokioIo::new(stream))
+                    .await?;
+            tokio::spawn(conn);
+            Ok::<_, Box<dyn Error + Send + Sync>>(sender)
+        };
+        let request = || {
+            Request::post("/")
+                .header(http::header::CONTENT_TYPE, "application/json")
+                .body(r#"{"jsonrpc":"2.0","id":1,"method":"anything","params":[]}"#.to_string())
+                .unwrap()
+        };
+
+        let mut first = connect().await.unwrap();
+        assert!(first.send_request(request()).await.is_ok());
+
+        // the second connection is closed by the server since the first one is still open
+        if let Ok(mut second) = connect().await {
+            assert!(second.send_request(request()).await.is_err());
+        }
+        assert!(first.send_request(request()).await.is_ok());
+
+        handle.stop().unwrap();
+        tokio::time::timeout(Duration::from_secs(5), handle.stopped()).await.unwrap();
+    }
+}
@@ -94,6 +94,10 @@ pub mod middleware;
 /// Cors utilities.
 mod cors;
 
+/// HTTP/2 server utilities.
+mod http2;
+pub use http2::Http2Config;
+
 /// Rpc error utilities.
 pub mod error;
 
@@ -1092,6 +1096,8 @@ pub struct RpcServerConfig<RpcMiddleware = Identity> {
     http_addr: Option<SocketAddr>,
     /// Control whether http responses should be compressed
     http_disable_compression: bool,
+    /// HTTP/2 settings for the http server, if `None` only HTTP/1.1 is served
+    http2_config: Option<Http2Config>,
     /// Configs for WS server
     ws_server_config: Option<ServerConfigBuilder>,
     /// Allowed CORS Domains for ws.
@@ -1102,6 +1108,10 @@ pub struct RpcServerConfig<RpcMiddleware = Identity> {
     ipc_server_config: Option<IpcServerBuilder<Identity, Identity>>,
     /// The Endpoint where to launch the ipc server
     ipc_endpoint: Option<String>,
+    /// Configs for the additional JSON-RPC server on a Unix domain socket
+    uds_server_config: Option<IpcServerBuilder<Identity, Identity>>,
+    /// The Unix domain socket path where to launch the additional server
+    uds_endpoint: Option<String>,
     /// JWT secret for authentication
     jwt_secret: Option<JwtSecret>,
     /// Configurable RPC middleware
@@ -1118,11 +1128,14 @@ impl Default for RpcServerConfig<Identity> {
             http_cors_domains: None,
             http_addr: None,
             http_disable_compression: false,
+            http2_config: None,
             ws_server_config: None,
             ws_cors_domains: None,
             ws_addr: None,
             ipc_server_config: None,
             ipc_endpoint: None,
+            uds_server_config: None,
+            uds_endpoint: None,
             jwt_secret: None,
             rpc_middleware: Default::default(),
         }
@@ -1182,11 +1195,14 @@ impl<RpcMiddleware> RpcServerConfig<RpcMiddleware> {
             http_cors_domains: self.http_cors_domains,
             http_addr: self.http_addr,
             http_disable_compression: self.http_disable_compression,
+            http2_config: self.http2_config,
             ws_server_config: self.ws_server_config,
             ws_cors_domains: self.ws_cors_domains,
             ws_addr: self.ws_addr,
             ipc_server_config: self.ipc_server_config,
             ipc_endpoint: self.ipc_endpoint,
+            uds_server_config: self.uds_server_config,
+            uds_endpoint: self.uds_endpoint,
             jwt_secret: self.jwt_secret,
             rpc_middleware,
         }
@@ -1209,6 +1225,17 @@ impl<RpcMiddleware> RpcServerConfig<RpcMiddleware> {
         self
     }
 
+    /// Enables HTTP/2 for the http server with the given settings.
+    ///
+    /// Connections are then served over HTTP/1.1 or HTTP/2, depending on what the client speaks.
+    ///
+    /// Note: this is not supported if the http server shares its port with the ws server, starting
+    /// the servers then fails.
+    pub const fn with_http2(mut self, config: Option<Http2Config>) -> Self {
+        self.http2_config = config;
+        self
+    }
+
     /// Configure the cors domains for HTTP
     pub fn with_http_cors(mut self, cors_domain: Option<String>) -> Self {
         self.http_cors_domains = cors_domain;
@@ -1261,6 +1288,23 @@ impl<RpcMiddleware> RpcServerConfig<RpcMiddleware> {
         self
     }
 
+    /// Configures an additional JSON-RPC server on a Unix domain socket.
+    ///
+    /// The server serves the same modules as the ipc transport, see also
+    /// [`RpcServerConfig::with_uds_endpoint`].
+    pub fn with_uds(mut self, config: IpcServerBuilder<Identity, Identity>) -> Self {
+        self.uds_server_config = Some(config.set_id_provider(EthSubscriptionIdProvider::default()));
+        self
+    }
+
+    /// Configures the Unix domain socket path of the additional JSON-RPC server.
+    ///
+    /// A stale socket file at this path is removed when the server starts.
+    pub fn with_uds_endpoint(mut self, path: impl Into<String>) -> Self {
+        self.uds_endpoint = Some(path.into());
+        self
+    }
+
     /// Configures the JWT secret for authentication.
     pub const fn with_jwt_secret(mut self, secret: Option<JwtSecret>) -> Self {
         self.jwt_secret = secret;
@@ -1279,7 +1323,11 @@ impl<RpcMiddleware> RpcServerConfig<RpcMiddleware> {
                 Some(ws_server_config.custom_tokio_runtime(tokio_runtime.clone()));
         }
         if let Some(ipc_server_config) = self.ipc_server_config {
-            self.ipc_server_config = Some(ipc_server_config.custom_tokio_runtime(tokio_runtime));
+            self.ipc_server_config =
+                Some(ipc_server_config.custom_tokio_runtime(tokio_runtime.clone()));
+        }
+        if let Some(uds_server_config) = self.uds_server_config {
+            self.uds_server_config = Some(uds_server_config.custom_tokio_runtime(tokio_runtime));
         }
         self
     }
@@ -1290,7 +1338,8 @@ impl<RpcMiddleware> RpcServerConfig<RpcMiddleware> {
     pub const fn has_server(&self) -> bool {
         self.http_server_config.is_some() ||
             self.ws_server_config.is_some() ||
-            self.ipc_server_config.is_some()
+            self.ipc_server_config.is_some() ||
+            self.uds_server_config.is_some()
     }
 
     /// Returns the [`SocketAddr`] of the http server
@@ -1308,6 +1357,11 @@ impl<RpcMiddleware> RpcServerConfig<RpcMiddleware> {
         self.ipc_endpoint.clone()
     }
 
+    /// Returns the Unix domain socket path of the additional JSON-RPC server
+    pub fn uds_endpoint(&self) -> Option<String> {
+        self.uds_endpoint.clone()
+    }
+
     /// Creates the [`CorsLayer`] if any
     fn maybe_cors_layer(cors: Option<String>) -> Result<Option<CorsLayer>, CorsDomainError> {
         cors.as_deref().map(cors::create_cors_layer).transpose()
@@ -1328,7 +1382,7 @@ impl<RpcMiddleware> RpcServerConfig<RpcMiddleware> {
         }
     }
 
-    /// Builds and starts the configured server(s): http, ws, ipc.
+    /// Builds and starts the configured server(s): http, ws, ipc, uds.
     ///
     /// If both http and ws are on the same port, they are combined into one server.
     ///
@@ -1340,6 +1394,7 @@ impl<RpcMiddleware> RpcServerConfig<RpcMiddleware> {
         let mut http_handle = None;
         let mut ws_handle = None;
         let mut ipc_handle = None;
+        let mut uds_handle = None;
 
         let http_socket_addr = self.http_addr.unwrap_or(SocketAddr::V4(SocketAddrV4::new(
             Ipv4Addr::LOCALHOST,
@@ -1357,11 +1412,19 @@ impl<RpcMiddleware> RpcServerConfig<RpcMiddleware> {
 
         if let Some(builder) = self.ipc_server_config {
             let ipc = builder
-                .set_rpc_middleware(IpcRpcServiceBuilder::new().layer(metrics))
+                .set_rpc_middleware(IpcRpcServiceBuilder::new().layer(metrics.clone()))
                 .build(ipc_path);
             ipc_handle = Some(ipc.start(modules.ipc.clone().expect("ipc server error")).await?);
         }
 
+        if let (Some(builder), Some(uds_path)) = (self.uds_server_config, self.uds_endpoint.clone())
+        {
+            let uds = builder
+                .set_rpc_middleware(IpcRpcServiceBuilder::new().layer(metrics))
+                .build(uds_path);
+            uds_handle = Some(uds.start(modules.ipc.clone().expect("uds server error")).await?);
+        }
+
         // If both are configured on the same port, we combine them into one server.
         if self.http_addr == self.ws_addr &&
             self.http_server_config.is_some() &&
@@ -1385,6 +1448,10 @@ impl<RpcMiddleware> RpcServerConfig<RpcMiddleware> {
             // we merge this into one server using the http setup
             modules.config.ensure_ws_http_identical()?;
 
+            if self.http2_config.is_some() {
+                return Err(WsHttpSamePortError::Http2NotSupported.into())
+            }
+
             if let Some(config) = self.http_server_config {
                 let server = ServerBuilder::new()
                     .set_http_middleware(
@@ -1428,6 +1495,8 @@ impl<RpcMiddleware> RpcServerConfig<RpcMiddleware> {
                     ws: ws_handle,
                     ipc_endpoint: self.ipc_endpoint.clone(),
                     ipc: ipc_handle,
+                    uds_endpoint: self.uds_endpoint.clone(),
+                    uds: uds_handle,
                     jwt_secret: self.jwt_secret,
                 });
             }
@@ -1464,7 +1533,7 @@ impl<RpcMiddleware> RpcServerConfig<RpcMiddleware> {
         }
 
         if let Some(config) = self.http_server_config {
-            let server = ServerBuilder::new()
+            let builder = ServerBuilder::new()
                 .set_config(config.http_only().build())
                 .set_http_middleware(
                     tower::ServiceBuilder::new()
@@ -1478,19 +1547,38 @@ impl<RpcMiddleware> RpcServerConfig<RpcMiddleware> {
                             modules.http.as_ref().map(RpcRequestMetrics::http).unwrap_or_default(),
                         )
                         .layer(self.rpc_middleware.clone()),
-                )
-                .build(http_socket_addr)
-                .await
-                .map_err(|err| RpcError::server_error(err, ServerKind::Http(http_socket_addr)))?;
-            let local_addr = server
-                .local_addr()
-                .map_err(|err| RpcError::server_error(err, ServerKind::Http(http_socket_addr)))?;
-            http_local_addr = Some(local_addr);
-            http_server = Some(server);
+                );
+
+            if let Some(http2_config) = self.http2_config {
+                let listener =
+                    tokio::net::TcpListener::bind(http_socket_addr).await.map_err(|err| {
+                        RpcError::server_error(err, ServerKind::Http(http_socket_addr))
+                    })?;
+                let local_addr = listener.local_addr().map_err(|err| {
+                    RpcError::server_error(err, ServerKind::Http(http_socket_addr))
+                })?;
+                http_local_addr = Some(local_addr);
+                http_handle = Some(http2::start(
+                    listener,
+                    builder.to_service_builder(),
+                    modules.http.clone().expect("http server error"),
+                    http2_config,
+                ));
+            } else {
+                let server = builder.build(http_socket_addr).await.map_err(|err| {
+                    RpcError::server_error(err, ServerKind::Http(http_socket_addr))
+                })?;
+                let local_addr = server.local_addr().map_err(|err| {
+                    RpcError::server_error(err, ServerKind::Http(http_socket_addr))
+                })?;
+                http_local_addr = Some(local_addr);
+                http_server = Some(server);
+            }
         }
 
-        http_handle = http_server
-            .map(|http_server| http_server.start(modules.http.clone().expect("http server error")));
+        if let Some(http_server) = http_server {
+            http_handle = Some(http_server.start(modules.http.clone().expect("http server error")));
+        }
         ws_handle = ws_server
             .map(|ws_server| ws_server.start(modules.ws.clone().expect("ws server error")));
         Ok(RpcServerHandle {
@@ -1500,6 +1588,8 @@ impl<RpcMiddleware> RpcServerConfig<RpcMiddleware> {
             ws: ws_handle,
             ipc_endpoint: self.ipc_endpoint.clone(),
             ipc: ipc_handle,
+            uds_endpoint: self.uds_endpoint.clone(),
+            uds: uds_handle,
             jwt_secret: self.jwt_secret,
         })
     }
@@ -2105,6 +2195,8 @@ pub struct RpcServerHandle {
     ws: Option<ServerHandle>,
     ipc_endpoint: Option<String>,
     ipc: Option<jsonrpsee::server::ServerHandle>,
+    uds_endpoint: Option<String>,
+    uds: Option<jsonrpsee::server::ServerHandle>,
     jwt_secret: Option<JwtSecret>,
 }
 
@@ -2151,14 +2243,30 @@ impl RpcServerHandle {
             handle.stop()?
         }
 
+        if let Some(handle) = self.uds {
+            handle.stop()?
+        }
+
         Ok(())
     }
 
+    /// Returns a future that resolves once all servers have stopped.
+    pub async fn stopped(self) {
+        for handle in [self.http, self.ws, self.ipc, self.uds].into_iter().flatten() {
+            handle.stopped().await;
+        }
+    }
+
     /// Returns the endpoint of the launched IPC server, if any
     pub fn ipc_endpoint(&self) -> Option<String> {
         self.ipc_endpoint.clone()
     }
 
+    /// Returns the Unix domain socket path of the launched additional JSON-RPC server, if any
+    pub fn uds_endpoint(&self) -> Option<String> {
+        self.uds_endpoint.clone()
+    }
+
     /// Returns the url to the http server
     pub fn http_url(&self) -> Option<String> {
         self.http_local_addr.map(|addr| format!("http://{addr}"))
@@ -4,7 +4,7 @@ use std::io;
 
 use reth_rpc_builder::{
     error::{RpcError, ServerKind, WsHttpSamePortError},
-    RpcServerConfig, TransportRpcModuleConfig,
+    Http2Config, RpcServerConfig, TransportRpcModuleConfig,
 };
 use reth_rpc_server_types::RethRpcModule;
 use reth_tokio_util::EventSender;
@@ -134,3 +134,25 @@ async fn test_launch_same_port_different_cors() {
         RpcError::WsHttpSamePortError(WsHttpSamePortError::ConflictingCorsDomains { .. })
     ));
 }
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_launch_same_port_http2() {
+    let builder = test_rpc_builder();
+    let eth_api = builder.bootstrap_eth_api();
+    let server = builder.build(
+        TransportRpcModuleConfig::set_ws(vec![RethRpcModule::Eth])
+            .with_http(vec![RethRpcModule::Eth]),
+        eth_api,
+        EventSender::new(1),
+    );
+    let addr = test_address();
+    let res = RpcServerConfig::ws(Default::default())
+        .with_ws_address(addr)
+        .with_http(Default::default())
+        .with_http2(Some(Http2Config::default()))
+        .with_http_address(addr)
+        .start(&server)
+        .await;
+    let err = res.unwrap_err();
+    assert!(matches!(err, RpcError::WsHttpSamePortError(WsHttpSamePortError::Http2NotSupported)));
+}
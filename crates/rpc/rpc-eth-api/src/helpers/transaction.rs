@@ -35,7 +35,13 @@ use reth_storage_api::{
 use reth_transaction_pool::{
     AddedTransactionOutcome, PoolPooledTx, PoolTransaction, TransactionOrigin, TransactionPool,
 };
-use std::{sync::Arc, time::Duration};
+use std::{
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
 
 /// Transaction related functions for the [`EthApiServer`](crate::EthApiServer) trait in
 /// the `eth_` namespace.
@@ -73,6 +79,29 @@ pub trait EthTransactions: LoadTransaction<Provider: BlockReaderIdExt> {
     /// Returns the timeout duration for `send_raw_transaction_sync` RPC method.
     fn send_raw_transaction_sync_timeout(&self) -> Duration;
 
+    /// Returns the flag that controls whether raw transactions submitted through RPC are added to
+    /// the pool as [`TransactionOrigin::Local`].
+    ///
+    /// This is origin-based: when set, the transactions receive the pool's local exemptions
+    /// regardless of their sender.
+    ///
+    /// Returns `None` by default, in which case raw transactions are always submitted as
+    /// [`TransactionOrigin::External`].
+    fn local_rpc_transactions(&self) -> Option<&AtomicBool> {
+        None
+    }
+
+    /// Returns the [`TransactionOrigin`] for raw transactions submitted through RPC.
+    ///
+    /// See also [`EthTransactions::local_rpc_transactions`].
+    fn raw_transaction_origin(&self) -> TransactionOrigin {
+        if self.local_rpc_transactions().is_some_and(|local| local.load(Ordering::Relaxed)) {
+            TransactionOrigin::Local
+        } else {
+            TransactionOrigin::External
+        }
+    }
+
     /// Decodes and recovers the transaction and submits it to the pool.
     ///
     /// Returns the hash of the transaction.
@@ -82,7 +111,7 @@ pub trait EthTransactions: LoadTransaction<Provider: BlockReaderIdExt> {
     ) -> impl Future<Output = Result<B256, Self::Error>> + Send {
         async move {
             let recovered = recover_raw_transaction::<PoolPooledTx<Self::Pool>>(&tx)?;
-            self.send_transaction(TransactionOrigin::External, WithEncoded::new(tx, recovered))
+            self.send_transaction(self.raw_transaction_origin(), WithEncoded::new(tx, recovered))
                 .await
         }
     }
@@ -26,7 +26,7 @@ pub use core::{EthApiServer, FullEthApiServer};
 pub use ext::L2EthApiExtServer;
 pub use filter::{EngineEthFilter, EthFilterApiServer, QueryLimits};
 pub use helpers::config::EthConfigApiServer;
-pub use node::{RpcNodeCore, RpcNodeCoreExt};
+pub use node::{RpcNodeCore, RpcNodeCoreExt, TxStatus};
 pub use pubsub::EthPubSubApiServer;
 pub use reth_rpc_convert::*;
 pub use reth_rpc_eth_types::error::{
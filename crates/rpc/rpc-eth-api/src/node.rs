@@ -1,17 +1,38 @@
 //! Helper trait for interfacing with [`FullNodeComponents`].
 
+use alloy_primitives::{BlockNumber, TxHash};
 use reth_chain_state::CanonStateSubscriptions;
 use reth_chainspec::{ChainSpecProvider, EthChainSpec, EthereumHardforks, Hardforks};
 use reth_evm::ConfigureEvm;
 use reth_network_api::NetworkInfo;
 use reth_node_api::{FullNodeComponents, NodePrimitives, PrimitivesTy};
 use reth_primitives_traits::{BlockTy, HeaderTy, ReceiptTy, TxTy};
+use reth_provider::TransactionBlockProvider;
 use reth_rpc_eth_types::EthStateCache;
 use reth_storage_api::{
-    BlockReader, BlockReaderIdExt, StageCheckpointReader, StateProviderFactory,
+    errors::ProviderResult, BlockReader, BlockReaderIdExt, StageCheckpointReader,
+    StateProviderFactory,
 };
 use reth_transaction_pool::{PoolTransaction, TransactionPool};
 
+/// Where a transaction is known to the node, see [`RpcNodeCore::transaction_status`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TxStatus {
+    /// The transaction is included in a canonical block.
+    Mined {
+        /// Number of the block that includes the transaction.
+        block: BlockNumber,
+        /// Index of the transaction within the block.
+        index: u64,
+    },
+    /// The transaction is in the pool and ready for inclusion in the next block.
+    Pending,
+    /// The transaction is in the pool but can only be included in a future block.
+    Queued,
+    /// The transaction is neither mined nor pooled.
+    Unknown,
+}
+
 /// Helper trait that provides the same interface as [`FullNodeComponents`] but without requiring
 /// implementation of trait bounds.
 ///
@@ -60,6 +81,30 @@ pub trait RpcNodeCore: Clone + Send + Sync + Unpin + 'static {
 
     /// Returns the provider of the node.
     fn provider(&self) -> &Self::Provider;
+
+    /// Returns whether the given transaction hash is known to the node, either mined or pooled,
+    /// without fetching the full transaction.
+    ///
+    /// Mined transactions are located through the transaction hash and transaction to block
+    /// indices, so neither the transaction nor its block are read.
+    ///
+    /// The pool is consulted before the database: a transaction that gets mined between the two
+    /// lookups is then still found in the database instead of being reported as unknown.
+    fn transaction_status(&self, hash: TxHash) -> ProviderResult<TxStatus> {
+        if let Some(tx) = self.pool().get(&hash) {
+            let is_pending = self
+                .pool()
+                .get_pending_transactions_by_sender(tx.sender())
+                .iter()
+                .any(|pending| *pending.hash() == hash);
+            return Ok(if is_pending { TxStatus::Pending } else { TxStatus::Queued })
+        }
+
+        Ok(match self.provider().block_number_for_transaction(hash)? {
+            Some((block, index)) => TxStatus::Mined { block, index: index as u64 },
+            None => TxStatus::Unknown,
+        })
+    }
 }
 
 impl<T> RpcNodeCore for T
@@ -1015,6 +1015,9 @@ pub enum RpcPoolError {
     /// constraint (blob vs normal tx)
     #[error("address already reserved")]
     AddressAlreadyReserved,
+    /// When the transaction is not replay protected but the pool requires a chain ID
+    #[error("only replay-protected (EIP-155) transactions allowed")]
+    MissingChainId,
     /// Other unspecified error
     #[error(transparent)]
     Other(Box<dyn core::error::Error + Send + Sync>),
@@ -1040,7 +1043,8 @@ impl From<RpcPoolError> for jsonrpsee_types::error::ErrorObject<'static> {
             RpcPoolError::PoolTransactionError(_) |
             RpcPoolError::Eip4844(_) |
             RpcPoolError::Eip7702(_) |
-            RpcPoolError::AddressAlreadyReserved => {
+            RpcPoolError::AddressAlreadyReserved |
+            RpcPoolError::MissingChainId => {
                 rpc_error_with_code(EthRpcErrorCode::InvalidInput.code(), error.to_string())
             }
             RpcPoolError::Other(other) => internal_rpc_err(other.to_string()),
@@ -1097,6 +1101,7 @@ impl From<InvalidPoolTransactionError> for RpcPoolError {
                     minimum_priority_fee,
                 })
             }
+            InvalidPoolTransactionError::MissingChainId => Self::MissingChainId,
         }
     }
 }
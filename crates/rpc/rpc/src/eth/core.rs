@@ -1,7 +1,10 @@
 //! Implementation of the [`jsonrpsee`] generated [`EthApiServer`](crate::EthApi) trait
 //! Handles RPC requests for the `eth_` namespace.
 
-use std::{sync::Arc, time::Duration};
+use std::{
+    sync::{atomic::AtomicBool, Arc},
+    time::Duration,
+};
 
 use crate::{eth::helpers::types::EthRpcConverter, EthApiBuilder};
 use alloy_consensus::BlockHeader;
@@ -285,6 +288,9 @@ pub struct EthApiInner<N: RpcNodeCore, Rpc: RpcConvert> {
 
     /// Whether to force upcasting EIP-4844 blob sidecars to EIP-7594 format when Osaka is active.
     force_blob_sidecar_upcasting: bool,
+
+    /// Whether raw transactions submitted through RPC are treated as local.
+    local_rpc_transactions: AtomicBool,
 }
 
 impl<N, Rpc> EthApiInner<N, Rpc>
@@ -359,6 +365,7 @@ where
             blob_sidecar_converter: BlobSidecarConverter::new(),
             evm_memory_limit,
             force_blob_sidecar_upcasting,
+            local_rpc_transactions: AtomicBool::new(false),
         }
     }
 }
@@ -534,6 +541,13 @@ where
         self.send_raw_transaction_sync_timeout
     }
 
+    /// Returns the flag that controls whether raw transactions submitted through RPC are treated
+    /// as local.
+    #[inline]
+    pub const fn local_rpc_transactions(&self) -> &AtomicBool {
+        &self.local_rpc_transactions
+    }
+
     /// Returns a handle to the blob sidecar converter.
     #[inline]
     pub const fn blob_sidecar_converter(&self) -> &BlobSidecarConverter {
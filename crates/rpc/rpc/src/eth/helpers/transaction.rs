@@ -1,6 +1,6 @@
 //! Contains RPC handler implementations specific to transactions
 
-use std::time::Duration;
+use std::{sync::atomic::AtomicBool, time::Duration};
 
 use crate::EthApi;
 use alloy_consensus::BlobTransactionValidationError;
@@ -36,6 +36,11 @@ where
         self.inner.send_raw_transaction_sync_timeout()
     }
 
+    #[inline]
+    fn local_rpc_transactions(&self) -> Option<&AtomicBool> {
+        Some(self.inner.local_rpc_transactions())
+    }
+
     async fn send_transaction(
         &self,
         origin: reth_transaction_pool::TransactionOrigin,
@@ -147,7 +152,10 @@ mod tests {
         ChainSpecProvider,
     };
     use reth_rpc_eth_api::node::RpcNodeCoreAdapter;
-    use reth_transaction_pool::test_utils::{testing_pool, TestPool};
+    use reth_transaction_pool::{
+        test_utils::{testing_pool, TestPool},
+        TransactionOrigin,
+    };
     use revm_primitives::Bytes;
 
     fn mock_eth_api(
@@ -214,6 +222,24 @@ mod tests {
         assert!(pool.get(&tx_2_result).is_some(), "tx2 not found in the pool");
     }
 
+    #[tokio::test]
+    async fn send_raw_transaction_local_origin() {
+        let eth_api = mock_eth_api(Default::default());
+        let pool = eth_api.pool();
+
+        assert_eq!(eth_api.raw_transaction_origin(), TransactionOrigin::External);
+        eth_api.local_rpc_transactions().unwrap().store(true, std::sync::atomic::Ordering::Relaxed);
+        assert_eq!(eth_api.raw_transaction_origin(), TransactionOrigin::Local);
+
+        // https://etherscan.io/tx/0xa694b71e6c128a2ed8e2e0f6770bddbe52e3bb8f10e8472f9a79ab81497a8b5d
+        let tx = Bytes::from(hex!(
+            "02f871018303579880850555633d1b82520894eee27662c2b8eba3cd936a23f039f3189633e4c887ad591c62bdaeb180c080a07ea72c68abfb8fca1bd964f0f99132ed9280261bdca3e549546c0205e800f7d0a05b4ef3039e9c9b9babc179a1878fb825b5aaf5aed2fa8744854150157b08d6f3"
+        ));
+
+        let hash = eth_api.send_raw_transaction(tx).await.unwrap();
+        assert_eq!(pool.get(&hash).unwrap().origin, TransactionOrigin::Local);
+    }
+
     #[tokio::test]
     async fn test_fill_transaction_fills_chain_id() {
         let address = Address::random();
@@ -249,7 +249,15 @@ where
     insert_genesis_state(&provider_rw, alloc.iter())?;
 
     // compute state root to populate trie tables
-    compute_state_root(&provider_rw, None)?;
+    let state_root = compute_state_root(&provider_rw, None)?;
+    if state_root != chain.genesis_header().state_root() {
+        warn!(
+            target: "reth::storage",
+            expected = %chain.genesis_header().state_root(),
+            computed = %state_root,
+            "Computed genesis state root differs from the genesis header"
+        );
+    }
 
     // set stage checkpoint to genesis block number for all stages
     let checkpoint = StageCheckpoint::new(genesis_block_number);
@@ -964,7 +972,7 @@ mod tests {
         HOLESKY_GENESIS_HASH, MAINNET_GENESIS_HASH, SEPOLIA_GENESIS_HASH,
     };
     use alloy_genesis::Genesis;
-    use reth_chainspec::{Chain, ChainSpec, HOLESKY, MAINNET, SEPOLIA};
+    use reth_chainspec::{Chain, ChainSpec, GenesisHeaderMut, HOLESKY, MAINNET, SEPOLIA};
     use reth_db::DatabaseEnv;
     use reth_db_api::{
         cursor::DbCursorRO,
@@ -1044,6 +1052,53 @@ mod tests {
         ))
     }
 
+    #[test]
+    fn init_genesis_with_state_root_override() {
+        let mut chain_spec = SEPOLIA.as_ref().clone();
+        let state_root = B256::with_last_byte(1);
+        chain_spec.set_genesis_state_root(state_root);
+        let chain_spec = Arc::new(chain_spec);
+        assert_ne!(chain_spec.genesis_hash(), SEPOLIA_GENESIS_HASH);
+
+        let factory = create_test_provider_factory_with_chain_spec(chain_spec.clone());
+        let static_file_provider = factory.static_file_provider();
+        let rocksdb_provider = factory.rocksdb_provider();
+        let genesis_hash = init_genesis(&factory).unwrap();
+        assert_eq!(genesis_hash, chain_spec.genesis_hash());
+
+        // the overridden genesis header is written
+        let stored = factory.sealed_header(0).unwrap().unwrap();
+        assert_eq!(stored, chain_spec.sealed_genesis_header());
+        assert_eq!(stored.state_root, state_root);
+        assert_eq!(factory.block_number(genesis_hash).unwrap(), Some(0));
+
+        // the state is initialized from the allocation
+        assert_eq!(
+            factory.get_stage_checkpoint(StageId::MerkleExecute).unwrap().unwrap().block_number,
+            0
+        );
+
+        // restarting with the same override succeeds
+        assert_eq!(init_genesis(&factory).unwrap(), genesis_hash);
+
+        // restarting without the override fails
+        let genesis_hash = init_genesis(
+            &ProviderFactory::<MockNodeTypesWithDB>::new(
+                factory.into_db(),
+                SEPOLIA.clone(),
+                static_file_provider,
+                rocksdb_provider,
+                reth_tasks::Runtime::test(),
+            )
+            .unwrap(),
+        );
+        assert!(matches!(
+            genesis_hash,
+            Err(InitStorageError::GenesisHashMismatch { chainspec_hash, storage_hash })
+                if chainspec_hash == SEPOLIA_GENESIS_HASH && storage_hash == stored.hash()
+        ));
+    }
+
     #[test]
     fn init_genesis_history() {
         let address_with_balance = Address::with_last_byte(1);
@@ -585,7 +585,35 @@ impl DatabaseEnv {
         Ok(())
     }
 
+    /// Tracks the tables defined in the given [`TableSet`] that already exist, without creating
+    /// missing ones.
+    ///
+    /// Returns the names of the tables that don't exist.
+    pub fn track_existing_tables_for<TS: TableSet>(
+        &mut self,
+    ) -> Result<Vec<&'static str>, DatabaseError> {
+        let mut handles = Vec::new();
+        let mut missing = Vec::new();
+        let tx = self.inner.begin_rw_txn().map_err(|e| DatabaseError::InitTx(e.into()))?;
+
+        for table in TS::tables() {
+            match tx.open_db(Some(table.name())) {
+                Ok(db) => handles.push((table.name(), db.dbi())),
+                Err(reth_libmdbx::Error::NotFound) => missing.push(table.name()),
+                Err(e) => return Err(DatabaseError::Open(e.into())),
+            }
+        }
+
+        tx.commit().map_err(|e| DatabaseError::Commit(e.into()))?;
+        Arc::make_mut(&mut self.dbis).extend(handles);
+        Ok(missing)
+    }
+
     /// Creates the tables and returns the identifiers of the tables.
+    ///
+    /// Table flags are derived from the table definition alone and are not configurable: the
+    /// other MDBX flags either change the key or value ordering the cursors rely on, or, like
+    /// `DUP_FIXED`, require fixed size values, while all table values are compressed.
     fn _create_tables<TS: TableSet>(
         &self,
     ) -> Result<Vec<(&'static str, ffi::MDBX_dbi)>, DatabaseError> {
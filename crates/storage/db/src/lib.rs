@@ -32,7 +32,10 @@ pub use reth_storage_errors::db::{DatabaseError, DatabaseWriteOperation};
 pub use utils::is_database_empty;
 
 #[cfg(feature = "mdbx")]
-pub use mdbx::{create_db, init_db, open_db, open_db_read_only, DatabaseEnv, DatabaseEnvKind};
+pub use mdbx::{
+    create_db, init_db, open_db, open_db_read_only, open_db_read_only_concurrent,
+    open_db_rw_strict_for, DatabaseEnv, DatabaseEnvKind,
+};
 
 pub use models::ClientVersion;
 pub use reth_db_api::*;
@@ -182,6 +185,21 @@ pub mod test_utils {
         builder.expect(ERROR_TEMPDIR).keep()
     }
 
+    /// Get a temporary directory path backed by memory to use for the database.
+    ///
+    /// This places the directory on the `/dev/shm` tmpfs if available and falls back to
+    /// [`tempdir_path`] otherwise.
+    pub fn in_memory_tempdir_path() -> PathBuf {
+        let shm = Path::new("/dev/shm");
+        if shm.is_dir() &&
+            let Ok(dir) =
+                tempfile::Builder::new().prefix("reth-test-").rand_bytes(8).tempdir_in(shm)
+        {
+            return dir.keep()
+        }
+        tempdir_path()
+    }
+
     /// Create read/write database for testing
     #[track_caller]
     pub fn create_test_rw_db() -> Arc<TempDatabase<DatabaseEnv>> {
@@ -256,14 +274,19 @@ pub mod test_utils {
 #[cfg(test)]
 mod tests {
     use crate::{
-        init_db,
+        create_db, init_db,
         mdbx::DatabaseArguments,
-        open_db, tables,
+        open_db, open_db_read_only_concurrent, open_db_rw_strict_for, tables,
         version::{db_version_file_path, DatabaseVersionError},
+        Tables,
     };
     use assert_matches::assert_matches;
     use reth_db_api::{
-        cursor::DbCursorRO, database::Database, models::ClientVersion, transaction::DbTx,
+        cursor::DbCursorRO,
+        database::Database,
+        models::ClientVersion,
+        table::Table,
+        transaction::{DbTx, DbTxMut},
     };
     use reth_libmdbx::MaxReadTransactionDuration;
     use std::time::Duration;
@@ -330,6 +353,53 @@ mod tests {
         }
     }
 
+    #[test]
+    fn db_read_only_concurrent() {
+        let path = tempdir().unwrap();
+        let rw = init_db(&path, DatabaseArguments::new(ClientVersion::default())).unwrap();
+
+        let tx = rw.tx_mut().unwrap();
+        tx.put::<tables::CanonicalHeaders>(1, Default::default()).unwrap();
+        tx.commit().unwrap();
+
+        // Open read only while the read/write handle is still alive
+        let ro =
+            open_db_read_only_concurrent(&path, DatabaseArguments::new(ClientVersion::default()))
+                .unwrap();
+        let tx = ro.tx().unwrap();
+        assert_eq!(tx.get::<tables::CanonicalHeaders>(1).unwrap(), Some(Default::default()));
+        drop(tx);
+
+        // Writes committed afterwards are visible to new read only transactions
+        let tx = rw.tx_mut().unwrap();
+        tx.put::<tables::CanonicalHeaders>(2, Default::default()).unwrap();
+        tx.commit().unwrap();
+        assert!(ro.tx().unwrap().get::<tables::CanonicalHeaders>(2).unwrap().is_some());
+    }
+
+    #[test]
+    fn db_rw_strict() {
+        let path = tempdir().unwrap();
+        let args = DatabaseArguments::new(ClientVersion::default());
+
+        // Database is not created
+        let missing = path.path().join("missing");
+        assert!(open_db_rw_strict_for::<_, Tables>(&missing, args.clone()).is_err());
+        assert!(!missing.exists());
+        assert!(open_db_rw_strict_for::<_, Tables>(&path, args.clone()).is_err());
+
+        // Tables are not created
+        drop(create_db(&path, args.clone()).unwrap());
+        let err = open_db_rw_strict_for::<_, Tables>(&path, args.clone()).unwrap_err();
+        assert!(err.to_string().contains(tables::CanonicalHeaders::NAME));
+
+        drop(init_db(&path, args.clone()).unwrap());
+        let db = open_db_rw_strict_for::<_, Tables>(&path, args).unwrap();
+        let tx = db.tx_mut().unwrap();
+        tx.put::<tables::CanonicalHeaders>(1, Default::default()).unwrap();
+        tx.commit().unwrap();
+    }
+
     #[test]
     fn db_client_version() {
         let path = tempdir().unwrap();
@@ -83,6 +83,23 @@ pub fn open_db_read_only(
         .with_context(|| format!("Could not open database at path: {}", path.display()))
 }
 
+/// Opens up an existing database in read only mode, alongside a process that may hold it in
+/// Read/Write mode.
+///
+/// MDBX supports any number of concurrent readers next to a single writer, so this is safe to use
+/// from e.g. monitoring sidecars while the node is running. The environment is always opened in
+/// cooperative (non-exclusive) mode, regardless of [`DatabaseArguments::with_exclusive`], and no
+/// storage lock is acquired.
+///
+/// Note that long-lived read transactions prevent the writer from reusing freed pages and grow the
+/// database file, so readers should keep their transactions short.
+pub fn open_db_read_only_concurrent(
+    path: impl AsRef<Path>,
+    args: DatabaseArguments,
+) -> eyre::Result<DatabaseEnv> {
+    open_db_read_only(path, args.with_exclusive(Some(false)))
+}
+
 /// Opens up an existing database. Read/Write mode with `WriteMap` enabled. It doesn't create it or
 /// create tables if missing.
 pub fn open_db(path: impl AsRef<Path>, args: DatabaseArguments) -> eyre::Result<DatabaseEnv> {
@@ -95,3 +112,30 @@ pub fn open_db(path: impl AsRef<Path>, args: DatabaseArguments) -> eyre::Result<
     }
     open(path.as_ref(), args)
 }
+
+/// Opens up an existing database in Read/Write mode and verifies that all tables defined in the
+/// given [`TableSet`] exist.
+///
+/// Unlike [`init_db_for`], neither the database nor missing tables are created. Instead, an error
+/// is returned if the path does not contain a database, or listing the missing tables.
+pub fn open_db_rw_strict_for<P: AsRef<Path>, TS: TableSet>(
+    path: P,
+    args: DatabaseArguments,
+) -> eyre::Result<DatabaseEnv> {
+    let path = path.as_ref();
+    if is_database_empty(path) {
+        eyre::bail!("No database found at path: {}", path.display())
+    }
+
+    let client_version = args.client_version().clone();
+    let mut db = DatabaseEnv::open(path, DatabaseEnvKind::RW, args)
+        .with_context(|| format!("Could not open database at path: {}", path.display()))?;
+
+    let missing = db.track_existing_tables_for::<TS>()?;
+    if !missing.is_empty() {
+        eyre::bail!("Database at path {} is missing tables: {}", path.display(), missing.join(", "))
+    }
+
+    db.record_client_version(client_version)?;
+    Ok(db)
+}
@@ -0,0 +1,151 @@
+//! Provider helpers for debugging and auditing, not used during regular node operation.
+
+use crate::{
+    BlockIdReader, BlockNumReader, ChangeSetReader, DBProvider, DatabaseProviderFactory,
+    ProviderError, PruneCheckpointReader,
+};
+use alloy_eips::BlockId;
+use reth_prune_types::PruneSegment;
+use reth_storage_api::{StorageChangeSetReader, StorageSettingsCache};
+use reth_trie::{prefix_set::TriePrefixSetsMut, HashedPostStateSorted};
+use reth_trie_db::from_reverts_auto;
+
+/// Extension trait for inspecting the trie paths that are touched by a block.
+pub trait TriePrefixSetsProvider:
+    DatabaseProviderFactory<
+        Provider: DBProvider
+                      + BlockNumReader
+                      + ChangeSetReader
+                      + PruneCheckpointReader
+                      + StorageChangeSetReader
+                      + StorageSettingsCache,
+    > + BlockIdReader
+{
+    /// Returns the trie prefix sets of the accounts and storage slots changed by `block`.
+    ///
+    /// The prefix sets are reconstructed from the changesets of the block without computing the
+    /// state root, which makes it possible to inspect exactly which trie paths a block touches
+    /// when debugging state root mismatches. The changesets only contain the values before the
+    /// block, so [`TriePrefixSetsMut::destroyed_accounts`] is always empty.
+    ///
+    /// Returns an error if `block` is unknown, has not been persisted yet, or its account or
+    /// storage history was pruned, see [`ProviderError::StateAtBlockPruned`].
+    fn prefix_sets_for_block(&self, block: BlockId) -> eyre::Result<TriePrefixSetsMut> {
+        let Some(block_number) = self.block_number_for_id(block)? else {
+            eyre::bail!("block {block:?} not found")
+        };
+
+        let provider = self.database_provider_ro()?;
+        let best_block = provider.best_block_number()?;
+        if block_number > best_block {
+            return Err(ProviderError::BlockNotExecuted {
+                requested: block_number,
+                executed: best_block,
+            }
+            .into())
+        }
+
+        for segment in [PruneSegment::AccountHistory, PruneSegment::StorageHistory] {
+            if let Some(pruned) = provider
+                .get_prune_checkpoint(segment)?
+                .and_then(|checkpoint| checkpoint.block_number) &&
+                block_number <= pruned
+            {
+                return Err(ProviderError::StateAtBlockPruned(block_number).into())
+            }
+        }
+
+        let state: HashedPostStateSorted =
+            from_reverts_auto(&provider, block_number..=block_number)?;
+        let mut prefix_sets = state.construct_prefix_sets();
+        prefix_sets.destroyed_accounts.clear();
+
+        Ok(prefix_sets)
+    }
+}
+
+impl<T> TriePrefixSetsProvider for T where
+    T: DatabaseProviderFactory<
+            Provider: DBProvider
+                          + BlockNumReader
+                          + ChangeSetReader
+                          + PruneCheckpointReader
+                          + StorageChangeSetReader
+                          + StorageSettingsCache,
+        > + BlockIdReader
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        test_utils::create_test_provider_factory, PruneCheckpointWriter, StageCheckpointWriter,
+    };
+    use alloy_primitives::{keccak256, Address, B256, U256};
+    use reth_db_api::{
+        models::{AccountBeforeTx, BlockNumberAddress},
+        tables,
+        transaction::DbTxMut,
+    };
+    use reth_primitives_traits::StorageEntry;
+    use reth_prune_types::{PruneCheckpoint, PruneMode};
+    use reth_stages_types::{StageCheckpoint, StageId};
+    use reth_trie::Nibbles;
+
+    #[test]
+    fn prefix_sets_for_block() {
+        let factory = create_test_provider_factory();
+        let account = Address::with_last_byte(1);
+        let storage_account = Address::with_last_byte(2);
+        let slot = B256::from(U256::from(7));
+
+        let provider = factory.provider_rw().unwrap();
+        let tx = provider.tx_ref();
+        tx.put::<tables::AccountChangeSets>(1, AccountBeforeTx { address: account, info: None })
+            .unwrap();
+        tx.put::<tables::StorageChangeSets>(
+            BlockNumberAddress((1, storage_account)),
+            StorageEntry { key: slot, value: U256::from(1) },
+        )
+        .unwrap();
+        tx.put::<tables::AccountChangeSets>(
+            2,
+            AccountBeforeTx { address: Address::with_last_byte(3), info: None },
+        )
+        .unwrap();
+        provider.save_stage_checkpoint(StageId::Finish, StageCheckpoint::new(2)).unwrap();
+        provider.commit().unwrap();
+
+        let mut prefix_sets = factory.prefix_sets_for_block(1.into()).unwrap();
+        let mut account_prefix_set = prefix_sets.account_prefix_set.clone().freeze();
+        assert_eq!(account_prefix_set.len(), 2);
+        assert!(account_prefix_set.contains(&Nibbles::unpack(keccak256(account))));
+        assert!(account_prefix_set.contains(&Nibbles::unpack(keccak256(storage_account))));
+
+        let mut storage_prefix_set =
+            prefix_sets.storage_prefix_sets.remove(&keccak256(storage_account)).unwrap().freeze();
+        assert_eq!(storage_prefix_set.len(), 1);
+        assert!(storage_prefix_set.contains(&Nibbles::unpack(keccak256(slot))));
+        assert!(prefix_sets.destroyed_accounts.is_empty());
+
+        assert!(factory.prefix_sets_for_block(3.into()).is_err());
+
+        let provider = factory.provider_rw().unwrap();
+        provider
+            .save_prune_checkpoint(
+                PruneSegment::StorageHistory,
+                PruneCheckpoint {
+                    block_number: Some(1),
+                    tx_number: None,
+                    prune_mode: PruneMode::Before(2),
+                },
+            )
+            .unwrap();
+        provider.commit().unwrap();
+
+        let err = factory.prefix_sets_for_block(1.into()).unwrap_err();
+        assert!(matches!(err.downcast_ref(), Some(ProviderError::StateAtBlockPruned(1))));
+        assert!(factory.prefix_sets_for_block(2.into()).is_ok());
+    }
+}
@@ -31,6 +31,8 @@ pub use providers::{
 pub mod changeset_walker;
 pub mod changesets_utils;
 
+pub mod debug;
+
 #[cfg(any(test, feature = "test-utils"))]
 /// Common test helpers for mocking the Provider.
 pub mod test_utils;
@@ -951,6 +951,51 @@ impl<N: NodePrimitives> StaticFileProvider<N> {
         Ok(deleted_headers)
     }
 
+    /// Replaces all static files of the given segment with the static files at `paths`.
+    ///
+    /// Every file is imported together with its sidecar files. The highest file is copied, since
+    /// the next writer of the segment appends to it, the others are hard linked if possible and
+    /// copied otherwise. The cached writer of the segment is dropped, so the next writer continues
+    /// from the imported files.
+    ///
+    /// CAUTION: destructive. Deletes all files on disk for this segment.
+    ///
+    /// WARNING: No static file writer of the segment should be held while calling this function.
+    pub fn import_segment_files(
+        &self,
+        segment: StaticFileSegment,
+        paths: &[PathBuf],
+    ) -> ProviderResult<()> {
+        if self.access.is_read_only() {
+            return Err(ProviderError::ReadOnlyStaticFileAccess)
+        }
+
+        self.writers.remove(segment);
+        self.delete_segment(segment)?;
+
+        for (index, path) in paths.iter().enumerate() {
+            let jar = NippyJar::<SegmentHeader>::load(path).map_err(ProviderError::other)?;
+            let files = [
+                jar.data_path().to_path_buf(),
+                jar.index_path(),
+                jar.offsets_path(),
+                jar.config_path(),
+                jar.changeset_offsets_path(),
+            ];
+            let is_highest = index == paths.len() - 1;
+
+            for source in files.into_iter().filter(|file| file.exists()) {
+                let Some(file_name) = source.file_name() else { continue };
+                let target = self.path.join(file_name);
+                if is_highest || std::fs::hard_link(&source, &target).is_err() {
+                    std::fs::copy(&source, &target).map_err(ProviderError::other)?;
+                }
+            }
+        }
+
+        self.initialize_index()
+    }
+
     /// Given a segment and block range it returns a cached
     /// [`StaticFileJarProvider`]. TODO(joshie): we should check the size and pop N if there's too
     /// many.
@@ -1,17 +1,33 @@
 //! Helper provider traits to encapsulate all provider traits for simplicity.
 
 use crate::{
-    AccountReader, BlockReader, BlockReaderIdExt, ChainSpecProvider, ChangeSetReader,
-    DatabaseProviderFactory, HashedPostStateProvider, PruneCheckpointReader,
-    RocksDBProviderFactory, StageCheckpointReader, StateProviderFactory, StateReader,
-    StaticFileProviderFactory,
+    AccountReader, BlockIdReader, BlockNumReader, BlockReader, BlockReaderIdExt, ChainSpecProvider,
+    ChangeSetReader, DBProvider, DatabaseProviderFactory, HashedPostStateProvider, HeaderProvider,
+    ProviderError, ProviderResult, PruneCheckpointReader, ReceiptProvider, RocksDBProviderFactory,
+    StageCheckpointReader, StateProviderFactory, StateReader, StaticFileProviderFactory,
 };
+use alloy_consensus::{BlockHeader, TxReceipt};
+use alloy_eips::{BlockId, BlockNumberOrTag};
+use alloy_primitives::{Address, BlockNumber, Bytes, Log, B256, U256};
 use reth_chain_state::{
     CanonStateSubscriptions, ForkChoiceSubscriptions, PersistedBlockSubscriptions,
 };
+use reth_chainspec::{EthChainSpec, EthereumHardforks};
+use reth_db_api::{
+    cursor::{DbCursorRO, DbDupCursorRO},
+    models::BlockNumberAddress,
+    tables,
+    transaction::DbTx,
+};
 use reth_node_types::{BlockTy, HeaderTy, NodeTypesWithDB, ReceiptTy, TxTy};
+use reth_primitives_traits::SealedHeader;
+use reth_prune_types::PruneSegment;
 use reth_storage_api::{NodePrimitivesProvider, StorageChangeSetReader, StorageSettingsCache};
-use std::fmt::Debug;
+use std::{
+    collections::{BTreeMap, HashSet},
+    fmt::Debug,
+    ops::RangeInclusive,
+};
 
 /// Helper trait to unify all provider traits for simplicity.
 pub trait FullProvider<N: NodeTypesWithDB>:
@@ -83,3 +99,668 @@ impl<T, N: NodeTypesWithDB> FullProvider<N> for T where
         + 'static
 {
 }
+
+/// Extension trait for looking up the total difficulty of a block.
+///
+/// Total difficulty is no longer stored by the node, so it is derived from the headers and the
+/// chain spec instead.
+pub trait TotalDifficultyProvider:
+    HeaderProvider + ChainSpecProvider<ChainSpec: EthereumHardforks>
+{
+    /// Returns the total difficulty at the given block number.
+    ///
+    /// For pre-merge blocks this sums the difficulty of all headers up to and including `number`,
+    /// which requires walking the entire header range. The headers are read in chunks of
+    /// [`TOTAL_DIFFICULTY_HEADERS_CHUNK`].
+    ///
+    /// Once Paris is active the total difficulty no longer changes, so for post-merge blocks this
+    /// returns the chain spec's final Paris total difficulty.
+    ///
+    /// Returns `None` if the header at `number` is unknown, or if the block is post-merge and the
+    /// chain spec does not know the final total difficulty.
+    fn total_difficulty(&self, number: BlockNumber) -> eyre::Result<Option<U256>> {
+        if self.header_by_number(number)?.is_none() {
+            return Ok(None)
+        }
+
+        let chain_spec = self.chain_spec();
+        if chain_spec.is_paris_active_at_block(number) {
+            return Ok(chain_spec.final_paris_total_difficulty())
+        }
+
+        let mut total_difficulty = U256::ZERO;
+        let mut start = 0;
+        while start <= number {
+            let end = number.min(start + TOTAL_DIFFICULTY_HEADERS_CHUNK - 1);
+            let headers = self.headers_range(start..=end)?;
+            if headers.len() as u64 != end - start + 1 {
+                return Ok(None)
+            }
+            total_difficulty += headers.iter().map(|header| header.difficulty()).sum::<U256>();
+            start = end + 1;
+        }
+
+        Ok(Some(total_difficulty))
+    }
+}
+
+/// Number of headers that [`TotalDifficultyProvider::total_difficulty`] reads at once.
+pub const TOTAL_DIFFICULTY_HEADERS_CHUNK: u64 = 10_000;
+
+impl<T> TotalDifficultyProvider for T where
+    T: HeaderProvider + ChainSpecProvider<ChainSpec: EthereumHardforks>
+{
+}
+
+/// Extension trait for looking up the code of an account at a given block.
+pub trait HistoricalCodeProvider: StateProviderFactory {
+    /// Returns the bytecode of the account at `address` as of `block`.
+    ///
+    /// The account's code hash and the code are read from the same state view.
+    ///
+    /// Returns `None` if the account does not exist or has no code, e.g. for EOAs.
+    fn code_at(&self, address: Address, block: BlockId) -> eyre::Result<Option<Bytes>> {
+        let state = self.state_by_block_id(block)?;
+        Ok(state.account_code(&address)?.map(|code| code.original_bytes()))
+    }
+}
+
+impl<T> HistoricalCodeProvider for T where T: StateProviderFactory {}
+
+/// Extension trait for looking up the nonce of an account at a given block.
+pub trait HistoricalNonceProvider: StateProviderFactory {
+    /// Returns the nonce of the account at `address` as of `block`, or `0` if the account does
+    /// not exist.
+    ///
+    /// For historical blocks this requires the account history at `block`, which non-archive
+    /// nodes prune for most blocks, in which case an error is returned, see
+    /// [`ProviderError::StateAtBlockPruned`].
+    fn account_nonce_at(&self, address: Address, block: BlockId) -> eyre::Result<u64> {
+        let state = self.state_by_block_id(block)?;
+        Ok(state.account_nonce(&address)?.unwrap_or_default())
+    }
+}
+
+impl<T> HistoricalNonceProvider for T where T: StateProviderFactory {}
+
+/// Extension trait for locating the block that contains a transaction.
+pub trait TransactionBlockProvider: BlockReader {
+    /// Returns the number of the block containing the transaction with the given hash and the
+    /// index of the transaction within that block.
+    ///
+    /// This reads the transaction hash and transaction to block indices, so it does not scan any
+    /// blocks. Returns `None` if the transaction is not part of any stored block, e.g. if it is
+    /// unknown or only in the transaction pool.
+    fn block_number_for_transaction(
+        &self,
+        hash: B256,
+    ) -> ProviderResult<Option<(BlockNumber, usize)>> {
+        let Some(tx_num) = self.transaction_id(hash)? else { return Ok(None) };
+        let Some(block_number) = self.block_by_transaction_id(tx_num)? else { return Ok(None) };
+        let indices = self
+            .block_body_indices(block_number)?
+            .ok_or(ProviderError::BlockBodyIndicesNotFound(block_number))?;
+        Ok(Some((block_number, (tx_num - indices.first_tx_num()) as usize)))
+    }
+}
+
+impl<T> TransactionBlockProvider for T where T: BlockReader {}
+
+/// Extension trait for fetching many headers by hash at once.
+pub trait HeadersByHashesProvider: DatabaseProviderFactory<Provider: HeaderProvider> {
+    /// Returns the sealed headers of the given block hashes, in the same order as `hashes`.
+    ///
+    /// All headers are read from a single database transaction, so they come from the same view
+    /// of the chain. Only persisted headers are considered, and the entry of any hash that is
+    /// unknown is `None`.
+    ///
+    /// This is the hash-keyed counterpart to [`HeaderProvider::sealed_headers_range`].
+    fn headers_by_hashes(
+        &self,
+        hashes: &[B256],
+    ) -> eyre::Result<Vec<Option<SealedHeader<<Self::Provider as HeaderProvider>::Header>>>> {
+        let provider = self.database_provider_ro()?;
+        Ok(hashes
+            .iter()
+            .map(|hash| provider.sealed_header_by_hash(*hash))
+            .collect::<Result<_, _>>()?)
+    }
+}
+
+impl<T> HeadersByHashesProvider for T where T: DatabaseProviderFactory<Provider: HeaderProvider> {}
+
+/// Resolves `block` against the persisted state of `provider`, returning its number and the
+/// number of the highest persisted block.
+///
+/// `latest` and `pending` resolve to the highest persisted block, since blocks that are only held
+/// in memory have no persisted state yet.
+fn persisted_block_number<T, P>(
+    this: &T,
+    provider: &P,
+    block: BlockId,
+) -> eyre::Result<(BlockNumber, BlockNumber)>
+where
+    T: BlockIdReader + ?Sized,
+    P: BlockNumReader,
+{
+    let best_block = provider.best_block_number()?;
+    let block_number = match block {
+        BlockId::Number(BlockNumberOrTag::Latest | BlockNumberOrTag::Pending) => best_block,
+        _ => {
+            let Some(block_number) = this.block_number_for_id(block)? else {
+                eyre::bail!("block {block:?} not found")
+            };
+            block_number
+        }
+    };
+
+    if block_number > best_block {
+        return Err(ProviderError::BlockNotExecuted {
+            requested: block_number,
+            executed: best_block,
+        }
+        .into())
+    }
+    Ok((block_number, best_block))
+}
+
+/// Extension trait for enumerating the storage of an account at a given block.
+pub trait StorageEntriesProvider:
+    DatabaseProviderFactory<
+        Provider: DBProvider + BlockNumReader + PruneCheckpointReader + StorageSettingsCache,
+    > + BlockIdReader
+{
+    /// Returns the non-zero storage slots of the account at `address` as of `block`, sorted by
+    /// key.
+    ///
+    /// The current plain storage of the account is reverted to `block` using the storage
+    /// changesets, all read from a single database transaction. Only persisted state is
+    /// considered, `latest` and `pending` refer to the highest persisted block. Reverting walks
+    /// the account's storage changesets of every block after `block`, so this gets more expensive
+    /// the further back `block` is.
+    ///
+    /// Returns an error if:
+    /// - `block` is unknown or has not been persisted yet,
+    /// - the storage history at `block` was pruned, which is the case for most blocks on
+    ///   non-archive nodes, see [`ProviderError::StateAtBlockPruned`],
+    /// - the node stores hashed state only, in which case the plain storage keys are unknown.
+    fn storage_entries(
+        &self,
+        address: Address,
+        block: BlockId,
+    ) -> eyre::Result<impl Iterator<Item = (B256, U256)>> {
+        let provider = self.database_provider_ro()?;
+        if provider.cached_storage_settings().use_hashed_state() {
+            eyre::bail!("storage enumeration is not supported with hashed state")
+        }
+
+        let (block_number, best_block) = persisted_block_number(self, &provider, block)?;
+
+        let tx = provider.tx_ref();
+        let mut storage = tx
+            .cursor_dup_read::<tables::PlainStorageState>()?
+            .walk_dup(Some(address), None)?
+            .map(|entry| entry.map(|(_, entry)| (entry.key, entry.value)))
+            .collect::<Result<BTreeMap<_, _>, _>>()?;
+
+        if block_number < best_block {
+            if let Some(pruned) = provider
+                .get_prune_checkpoint(PruneSegment::StorageHistory)?
+                .and_then(|checkpoint| checkpoint.block_number) &&
+                block_number <= pruned
+            {
+                return Err(ProviderError::StateAtBlockPruned(block_number).into())
+            }
+
+            // Changesets hold the value from before each block, so the first changeset of a slot
+            // after `block_number` holds its value at `block_number`.
+            let mut changesets = tx.cursor_dup_read::<tables::StorageChangeSets>()?;
+            let mut reverted = HashSet::new();
+            for number in block_number + 1..=best_block {
+                for entry in
+                    changesets.walk_dup(Some(BlockNumberAddress((number, address))), None)?
+                {
+                    let (_, entry) = entry?;
+                    if !reverted.insert(entry.key) {
+                        continue
+                    }
+                    if entry.value.is_zero() {
+                        storage.remove(&entry.key);
+                    } else {
+                        storage.insert(entry.key, entry.value);
+                    }
+                }
+            }
+        }
+
+        Ok(storage.into_iter())
+    }
+}
+
+impl<T> StorageEntriesProvider for T where
+    T: DatabaseProviderFactory<
+            Provider: DBProvider + BlockNumReader + PruneCheckpointReader + StorageSettingsCache,
+        > + BlockIdReader
+{
+}
+
+/// Size of the state at a block, see [`StateSizeProvider::state_size_estimate`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct StateSizeEstimate {
+    /// Number of accounts.
+    pub accounts: u64,
+    /// Number of non-zero storage slots across all accounts.
+    pub storage_slots: u64,
+}
+
+/// Extension trait for estimating the size of the state at a given block.
+pub trait StateSizeProvider:
+    DatabaseProviderFactory<
+        Provider: DBProvider + BlockNumReader + PruneCheckpointReader + StorageSettingsCache,
+    > + BlockIdReader
+{
+    /// Returns an estimate of the number of accounts and storage slots in the state as of
+    /// `block`.
+    ///
+    /// The counts are taken from the entry counts of the state tables and only consider persisted
+    /// state, `latest` and `pending` refer to the highest persisted block. For the latest
+    /// persisted block this is cheap. For older blocks, the changesets after `block` are walked
+    /// and every account and storage slot changed since is checked to see whether it was created
+    /// or removed, which gets expensive the further back `block` is. This requires
+    /// the account and storage history at `block`, so on pruned nodes usually only the latest
+    /// state is available.
+    ///
+    /// Returns an error if:
+    /// - `block` is unknown or has not been persisted yet,
+    /// - the history at `block` was pruned, see [`ProviderError::StateAtBlockPruned`],
+    /// - `block` is not the latest persisted block and the node stores hashed state only.
+    fn state_size_estimate(&self, block: BlockId) -> eyre::Result<StateSizeEstimate> {
+        let provider = self.database_provider_ro()?;
+        let (block_number, best_block) = persisted_block_number(self, &provider, block)?;
+
+        let tx = provider.tx_ref();
+        let use_hashed_state = provider.cached_storage_settings().use_hashed_state();
+        let (accounts, storage_slots) = if use_hashed_state {
+            (tx.entries::<tables::HashedAccounts>()?, tx.entries::<tables::HashedStorages>()?)
+        } else {
+            (tx.entries::<tables::PlainAccountState>()?, tx.entries::<tables::PlainStorageState>()?)
+        };
+        let mut estimate =
+            StateSizeEstimate { accounts: accounts as u64, storage_slots: storage_slots as u64 };
+
+        if block_number == best_block {
+            return Ok(estimate)
+        }
+
+        if use_hashed_state {
+            eyre::bail!("historical state size estimates are not supported with hashed state")
+        }
+
+        for segment in [PruneSegment::AccountHistory, PruneSegment::StorageHistory] {
+            if let Some(pruned) = provider
+                .get_prune_checkpoint(segment)?
+                .and_then(|checkpoint| checkpoint.block_number) &&
+                block_number <= pruned
+            {
+                return Err(ProviderError::StateAtBlockPruned(block_number).into())
+            }
+        }
+
+        // The first changeset of an entry after `block_number` holds its value at
+        // `block_number`, comparing it with the current value tells whether the entry was created
+        // or removed since.
+        let mut seen_accounts = HashSet::new();
+        for entry in tx
+            .cursor_read::<tables::AccountChangeSets>()?
+            .walk_range(block_number + 1..=best_block)?
+        {
+            let (_, changeset) = entry?;
+            if !seen_accounts.insert(changeset.address) {
+                continue
+            }
+            let existed = changeset.info.is_some();
+            let exists = tx.get::<tables::PlainAccountState>(changeset.address)?.is_some();
+            match (existed, exists) {
+                (true, false) => estimate.accounts += 1,
+                (false, true) => estimate.accounts = estimate.accounts.saturating_sub(1),
+                _ => {}
+            }
+        }
+
+        let mut storage_cursor = tx.cursor_dup_read::<tables::PlainStorageState>()?;
+        let mut seen_slots = HashSet::new();
+        for entry in tx
+            .cursor_dup_read::<tables::StorageChangeSets>()?
+            .walk_range(BlockNumberAddress::range(block_number + 1..=best_block))?
+        {
+            let (block_address, entry) = entry?;
+            let address = block_address.address();
+            if !seen_slots.insert((address, entry.key)) {
+                continue
+            }
+            let existed = !entry.value.is_zero();
+            let exists = storage_cursor
+                .seek_by_key_subkey(address, entry.key)?
+                .is_some_and(|current| current.key == entry.key && !current.value.is_zero());
+            match (existed, exists) {
+                (true, false) => estimate.storage_slots += 1,
+                (false, true) => estimate.storage_slots = estimate.storage_slots.saturating_sub(1),
+                _ => {}
+            }
+        }
+
+        Ok(estimate)
+    }
+}
+
+impl<T> StateSizeProvider for T where
+    T: DatabaseProviderFactory<
+            Provider: DBProvider + BlockNumReader + PruneCheckpointReader + StorageSettingsCache,
+        > + BlockIdReader
+{
+}
+
+/// The maximum number of blocks [`LogsProvider::logs_in_range`] scans in a single call.
+pub const MAX_LOGS_RANGE_BLOCKS: u64 = 10_000;
+
+/// Extension trait for fetching logs matching an address and topic filter.
+pub trait LogsProvider: ReceiptProvider<Receipt: TxReceipt<Log = Log>> + BlockNumReader {
+    /// Returns the logs emitted in the blocks of `range` that match the given filter, in the order
+    /// they were emitted.
+    ///
+    /// A log matches if it was emitted by one of `addresses`, and for each position `i` its `i`th
+    /// topic is one of `topics[i]`. An empty address list or topic set matches anything.
+    ///
+    /// There are no log bloom checks, all receipts of the range are loaded and scanned, so the
+    /// cost grows linearly with the number of transactions in the range. To keep this bounded,
+    /// ranges are limited to [`MAX_LOGS_RANGE_BLOCKS`] blocks, wider ranges should be split by
+    /// the caller.
+    ///
+    /// Returns an error if the range is wider than [`MAX_LOGS_RANGE_BLOCKS`], extends past the
+    /// best block, or if the receipts of any block in the range are unavailable, e.g. because
+    /// they were pruned.
+    fn logs_in_range(
+        &self,
+        range: RangeInclusive<BlockNumber>,
+        addresses: &[Address],
+        topics: &[Vec<B256>],
+    ) -> eyre::Result<Vec<Log>> {
+        if range.is_empty() {
+            return Ok(Vec::new())
+        }
+
+        let num_blocks = range.end() - range.start() + 1;
+        if num_blocks > MAX_LOGS_RANGE_BLOCKS {
+            eyre::bail!(
+                "block range {range:?} spans {num_blocks} blocks, the maximum is {MAX_LOGS_RANGE_BLOCKS}"
+            )
+        }
+
+        let best_block = self.best_block_number()?;
+        if *range.end() > best_block {
+            return Err(ProviderError::BlockNotExecuted {
+                requested: *range.end(),
+                executed: best_block,
+            }
+            .into())
+        }
+
+        // All receipts are read in a single call so they come from the same view of the chain.
+        let receipts = self.receipts_by_block_range(range.clone())?;
+        if receipts.len() as u64 != num_blocks {
+            eyre::bail!("receipts for block range {range:?} are not available")
+        }
+
+        let matches = |log: &Log| {
+            (addresses.is_empty() || addresses.contains(&log.address)) &&
+                topics.iter().enumerate().all(|(i, topic_set)| {
+                    topic_set.is_empty() ||
+                        log.topics().get(i).is_some_and(|topic| topic_set.contains(topic))
+                })
+        };
+
+        Ok(receipts
+            .iter()
+            .flatten()
+            .flat_map(|receipt| receipt.logs())
+            .filter(|log| matches(log))
+            .cloned()
+            .collect())
+    }
+}
+
+impl<T> LogsProvider for T where T: ReceiptProvider<Receipt: TxReceipt<Log = Log>> + BlockNumReader {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        providers::BlockchainProvider,
+        test_utils::{
+            blocks::BlockchainTestData, create_test_provider_factory, ExtendedAccount,
+            MockEthProvider, MockNodeTypesWithDB,
+        },
+        BlockWriter, StageCheckpointWriter,
+    };
+    use alloy_primitives::bytes;
+    use reth_db_api::{models::AccountBeforeTx, transaction::DbTxMut};
+    use reth_primitives_traits::{Account, StorageEntry};
+    use reth_stages_types::{StageCheckpoint, StageId};
+
+    #[test]
+    fn code_at() {
+        let provider = MockEthProvider::default();
+        let contract = Address::with_last_byte(1);
+        let eoa = Address::with_last_byte(2);
+        let code = bytes!("6000600055");
+        provider
+            .add_account(contract, ExtendedAccount::new(1, U256::ZERO).with_bytecode(code.clone()));
+        provider.add_account(eoa, ExtendedAccount::new(0, U256::from(1)));
+
+        assert_eq!(provider.code_at(contract, BlockId::latest()).unwrap(), Some(code));
+        assert_eq!(provider.code_at(eoa, BlockId::number(1)).unwrap(), None);
+        assert_eq!(provider.code_at(Address::ZERO, BlockId::latest()).unwrap(), None);
+    }
+
+    #[test]
+    fn account_nonce_at() {
+        let provider = MockEthProvider::default();
+        let address = Address::with_last_byte(1);
+        provider.add_account(address, ExtendedAccount::new(7, U256::ZERO));
+
+        assert_eq!(provider.account_nonce_at(address, BlockId::latest()).unwrap(), 7);
+        assert_eq!(provider.account_nonce_at(Address::ZERO, BlockId::latest()).unwrap(), 0);
+    }
+
+    #[test]
+    fn total_difficulty() {
+        let provider = MockEthProvider::default();
+        let tip = TOTAL_DIFFICULTY_HEADERS_CHUNK + 10;
+        provider.extend_headers((0..=tip).map(|number| {
+            let header =
+                alloy_consensus::Header { number, difficulty: U256::from(2), ..Default::default() };
+            (B256::from(U256::from(number)), header)
+        }));
+
+        assert_eq!(provider.total_difficulty(0).unwrap(), Some(U256::from(2)));
+        assert_eq!(provider.total_difficulty(tip).unwrap(), Some(U256::from(2 * (tip + 1))));
+        assert_eq!(provider.total_difficulty(tip + 1).unwrap(), None);
+
+        // a missing header in a previous chunk
+        provider.headers.lock().remove(&B256::from(U256::from(5)));
+        assert_eq!(provider.total_difficulty(tip).unwrap(), None);
+    }
+
+    #[test]
+    fn headers_by_hashes() {
+        let factory = create_test_provider_factory();
+        let data = BlockchainTestData::default();
+        let genesis = data.genesis.try_recover().unwrap();
+        let block = data.blocks[0].0.clone();
+
+        let provider_rw = factory.provider_rw().unwrap();
+        provider_rw.insert_block(&genesis).unwrap();
+        provider_rw.insert_block(&block).unwrap();
+        provider_rw.commit().unwrap();
+
+        let headers = factory
+            .headers_by_hashes(&[block.hash(), B256::with_last_byte(1), genesis.hash()])
+            .unwrap();
+        assert_eq!(
+            headers,
+            vec![
+                Some(SealedHeader::new(block.header().clone(), block.hash())),
+                None,
+                Some(SealedHeader::new(genesis.header().clone(), genesis.hash())),
+            ]
+        );
+        assert!(factory.headers_by_hashes(&[]).unwrap().is_empty());
+    }
+
+    const ADDRESS: Address = Address::with_last_byte(1);
+    const OTHER: Address = Address::with_last_byte(2);
+    const SLOT_A: B256 = B256::with_last_byte(1);
+    const SLOT_B: B256 = B256::with_last_byte(2);
+    const SLOT_C: B256 = B256::with_last_byte(3);
+
+    /// Persists two blocks with state changes, with the canonical head one block ahead of the
+    /// persisted state.
+    ///
+    /// Block 1 creates `OTHER` and the `SLOT_A` and `SLOT_B` slots of `ADDRESS`. Block 2 creates
+    /// `ADDRESS`, changes `SLOT_A`, clears `SLOT_B`, creates `SLOT_C` and the `SLOT_A` slot of
+    /// `OTHER`.
+    fn provider_with_state() -> BlockchainProvider<MockNodeTypesWithDB> {
+        let factory = create_test_provider_factory();
+        let data = BlockchainTestData::default();
+
+        let provider_rw = factory.provider_rw().unwrap();
+        provider_rw.insert_block(&data.genesis.clone().try_recover().unwrap()).unwrap();
+        for (block, _) in &data.blocks[..2] {
+            provider_rw.insert_block(block).unwrap();
+        }
+
+        let tx = provider_rw.tx_ref();
+        for (number, address) in [(1, OTHER), (2, ADDRESS)] {
+            tx.put::<tables::PlainAccountState>(address, Account::default()).unwrap();
+            tx.put::<tables::AccountChangeSets>(number, AccountBeforeTx { address, info: None })
+                .unwrap();
+        }
+        for (address, slot, value) in
+            [(ADDRESS, SLOT_A, 3), (ADDRESS, SLOT_C, 4), (OTHER, SLOT_A, 5)]
+        {
+            let entry = StorageEntry { key: slot, value: U256::from(value) };
+            tx.put::<tables::PlainStorageState>(address, entry).unwrap();
+        }
+        for (number, address, slot, value) in [
+            (1, ADDRESS, SLOT_A, 0),
+            (1, ADDRESS, SLOT_B, 0),
+            (2, ADDRESS, SLOT_A, 1),
+            (2, ADDRESS, SLOT_B, 2),
+            (2, ADDRESS, SLOT_C, 0),
+            (2, OTHER, SLOT_A, 0),
+        ] {
+            let entry = StorageEntry { key: slot, value: U256::from(value) };
+            tx.put::<tables::StorageChangeSets>(BlockNumberAddress((number, address)), entry)
+                .unwrap();
+        }
+        provider_rw.save_stage_checkpoint(StageId::Finish, StageCheckpoint::new(2)).unwrap();
+        provider_rw.commit().unwrap();
+
+        // the canonical head is ahead of the persisted state
+        let provider = BlockchainProvider::new(factory).unwrap();
+        provider.canonical_in_memory_state().set_canonical_head(SealedHeader::seal_slow(
+            alloy_consensus::Header { number: 3, ..Default::default() },
+        ));
+        provider
+    }
+
+    #[test]
+    fn storage_entries() {
+        let provider = provider_with_state();
+
+        let entries = |block| provider.storage_entries(ADDRESS, block).unwrap().collect::<Vec<_>>();
+        assert_eq!(
+            entries(BlockId::latest()),
+            vec![(SLOT_A, U256::from(3)), (SLOT_C, U256::from(4))]
+        );
+        assert_eq!(
+            entries(BlockId::number(1)),
+            vec![(SLOT_A, U256::from(1)), (SLOT_B, U256::from(2))]
+        );
+        assert!(entries(BlockId::number(0)).is_empty());
+
+        let err = provider.storage_entries(ADDRESS, BlockId::number(3)).unwrap_err();
+        assert!(matches!(
+            err.downcast_ref(),
+            Some(ProviderError::BlockNotExecuted { requested: 3, executed: 2 })
+        ));
+    }
+
+    #[test]
+    fn state_size_estimate() {
+        let provider = provider_with_state();
+
+        let estimate = |block| provider.state_size_estimate(block).unwrap();
+        assert_eq!(
+            estimate(BlockId::latest()),
+            StateSizeEstimate { accounts: 2, storage_slots: 3 }
+        );
+        assert_eq!(
+            estimate(BlockId::number(1)),
+            StateSizeEstimate { accounts: 1, storage_slots: 2 }
+        );
+        assert_eq!(estimate(BlockId::number(0)), StateSizeEstimate::default());
+
+        let err = provider.state_size_estimate(BlockId::number(3)).unwrap_err();
+        assert!(matches!(
+            err.downcast_ref(),
+            Some(ProviderError::BlockNotExecuted { requested: 3, executed: 2 })
+        ));
+    }
+
+    #[test]
+    fn logs_in_range() {
+        let provider = MockEthProvider::default();
+        for number in 0..3 {
+            provider.add_header(
+                B256::with_last_byte(number as u8),
+                alloy_consensus::Header { number, ..Default::default() },
+            );
+        }
+
+        let log = |address: u8, topics: Vec<B256>| {
+            Log::new_unchecked(Address::with_last_byte(address), topics, Bytes::new())
+        };
+        let log_a = log(1, vec![B256::with_last_byte(1), B256::with_last_byte(2)]);
+        let log_b = log(2, vec![B256::with_last_byte(1)]);
+        let log_c = log(1, vec![B256::with_last_byte(3)]);
+        let receipt = |logs: Vec<Log>| reth_ethereum_primitives::Receipt {
+            tx_type: alloy_consensus::TxType::Legacy,
+            cumulative_gas_used: 21_000,
+            logs,
+            success: true,
+        };
+        provider.add_receipts(1, vec![receipt(vec![log_a.clone(), log_b.clone()])]);
+        provider.add_receipts(2, vec![receipt(vec![]), receipt(vec![log_c.clone()])]);
+
+        assert_eq!(
+            provider.logs_in_range(0..=2, &[], &[]).unwrap(),
+            vec![log_a.clone(), log_b.clone(), log_c.clone()]
+        );
+        assert_eq!(
+            provider.logs_in_range(0..=2, &[Address::with_last_byte(1)], &[]).unwrap(),
+            vec![log_a.clone(), log_c]
+        );
+        assert_eq!(
+            provider.logs_in_range(0..=2, &[], &[vec![B256::with_last_byte(1)]]).unwrap(),
+            vec![log_a.clone(), log_b]
+        );
+        assert_eq!(
+            provider.logs_in_range(0..=1, &[], &[vec![], vec![B256::with_last_byte(2)]]).unwrap(),
+            vec![log_a]
+        );
+
+        assert!(provider.logs_in_range(0..=3, &[], &[]).is_err());
+        assert!(provider.logs_in_range(0..=MAX_LOGS_RANGE_BLOCKS, &[], &[]).is_err());
+    }
+}
@@ -12,4 +12,9 @@ mod rocksdb_provider;
 pub use rocksdb_provider::RocksDBProviderFactory;
 
 mod full;
-pub use full::FullProvider;
+pub use full::{
+    FullProvider, HeadersByHashesProvider, HistoricalCodeProvider, HistoricalNonceProvider,
+    LogsProvider, StateSizeEstimate, StateSizeProvider, StorageEntriesProvider,
+    TotalDifficultyProvider, TransactionBlockProvider, MAX_LOGS_RANGE_BLOCKS,
+    TOTAL_DIFFICULTY_HEADERS_CHUNK,
+};
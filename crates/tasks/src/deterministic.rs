@@ -0,0 +1,102 @@
+//! Deterministic task scheduling for tests.
+//!
+//! **Not suitable for production.** Only available with the `test-utils` feature.
+
+use crate::{Runtime, RuntimeBuilder, TokioConfig};
+use std::{future::Future, sync::Arc, time::Duration};
+use tokio::runtime::Handle;
+
+/// A single-threaded tokio runtime with paused time that tests drive manually.
+///
+/// Tasks spawned through [`DeterministicScheduler::runtime`] only make progress while the test
+/// drives the scheduler via [`block_on`](Self::block_on), [`step`](Self::step) or
+/// [`advance`](Self::advance). Since all tasks run on a single thread and timers only fire when
+/// time is advanced, execution no longer depends on wall-clock timing, which makes tests that
+/// would otherwise sleep reproducible.
+///
+/// Blocking tasks still run on tokio's blocking thread pool and are therefore not stepped.
+#[derive(Debug, Clone)]
+pub struct DeterministicScheduler {
+    rt: Arc<tokio::runtime::Runtime>,
+}
+
+impl DeterministicScheduler {
+    /// Creates a new scheduler backed by a current-thread tokio runtime with paused time.
+    pub fn new() -> Self {
+        let rt = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .start_paused(true)
+            .build()
+            .expect("failed to build deterministic tokio runtime");
+        Self { rt: Arc::new(rt) }
+    }
+
+    /// Returns the handle of the underlying tokio runtime.
+    pub fn handle(&self) -> &Handle {
+        self.rt.handle()
+    }
+
+    /// Returns a [`Runtime`] that spawns all its tasks on this scheduler.
+    pub fn runtime(&self) -> Runtime {
+        let config =
+            Runtime::test_config().with_tokio(TokioConfig::existing_handle(self.handle().clone()));
+        RuntimeBuilder::new(config).build().expect("failed to build deterministic Runtime")
+    }
+
+    /// Runs the future to completion, driving all spawned tasks in the meantime.
+    ///
+    /// If all tasks are idle, time is automatically advanced to the next pending timer.
+    pub fn block_on<F: Future>(&self, fut: F) -> F::Output {
+        self.rt.block_on(fut)
+    }
+
+    /// Polls every task that is ready to make progress once, without advancing time.
+    pub fn step(&self) {
+        self.rt.block_on(tokio::task::yield_now())
+    }
+
+    /// Advances the paused clock by `duration`, firing all timers that elapse and polling the
+    /// tasks woken by them.
+    pub fn advance(&self, duration: Duration) {
+        self.rt.block_on(tokio::time::advance(duration))
+    }
+}
+
+impl Default for DeterministicScheduler {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[test]
+    fn step_and_advance() {
+        let scheduler = DeterministicScheduler::new();
+        let runtime = scheduler.runtime();
+        let counter = Arc::new(AtomicUsize::new(0));
+
+        let c = counter.clone();
+        runtime.spawn_task(async move {
+            c.fetch_add(1, Ordering::SeqCst);
+            tokio::time::sleep(Duration::from_secs(10)).await;
+            c.fetch_add(1, Ordering::SeqCst);
+        });
+
+        // nothing runs until the scheduler is driven
+        assert_eq!(counter.load(Ordering::SeqCst), 0);
+
+        scheduler.step();
+        assert_eq!(counter.load(Ordering::SeqCst), 1);
+
+        scheduler.advance(Duration::from_secs(5));
+        assert_eq!(counter.load(Ordering::SeqCst), 1);
+
+        scheduler.advance(Duration::from_secs(5));
+        scheduler.step();
+        assert_eq!(counter.load(Ordering::SeqCst), 2);
+    }
+}
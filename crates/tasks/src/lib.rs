@@ -3,6 +3,7 @@
 //! # Feature Flags
 //!
 //! - `rayon`: Enable rayon thread pool for blocking tasks.
+//! - `test-utils`: Export [`DeterministicScheduler`] for stepping tasks manually in tests.
 
 #![doc(
     html_logo_url = "https://raw.githubusercontent.com/paradigmxyz/reth/main/assets/reth-docs.png",
@@ -37,6 +38,11 @@ pub mod shutdown;
 pub mod utils;
 pub(crate) mod worker_map;
 
+#[cfg(feature = "test-utils")]
+pub mod deterministic;
+#[cfg(feature = "test-utils")]
+pub use deterministic::DeterministicScheduler;
+
 #[cfg(feature = "rayon")]
 pub mod pool;
 #[cfg(feature = "rayon")]
@@ -51,7 +57,9 @@ pub use for_each_ordered::ForEachOrdered;
 pub use lazy::LazyHandle;
 #[cfg(feature = "rayon")]
 pub use runtime::RayonConfig;
-pub use runtime::{Runtime, RuntimeBuildError, RuntimeBuilder, RuntimeConfig, TokioConfig};
+pub use runtime::{
+    Runtime, RuntimeBuildError, RuntimeBuilder, RuntimeConfig, TaskPanicHook, TokioConfig,
+};
 
 /// A [`TaskExecutor`] is now an alias for [`Runtime`].
 pub type TaskExecutor = Runtime;
@@ -234,7 +242,10 @@ pub(crate) enum TaskEvent {
 mod tests {
     use super::*;
     use std::{
-        sync::atomic::{AtomicBool, AtomicUsize, Ordering},
+        sync::{
+            atomic::{AtomicBool, AtomicUsize, Ordering},
+            Mutex,
+        },
         time::Duration,
     };
 
@@ -255,6 +266,29 @@ mod tests {
         })
     }
 
+    #[test]
+    fn test_critical_panic_hook() {
+        let rt = Runtime::test();
+        let handle = rt.take_task_manager_handle().unwrap();
+
+        let reported = Arc::new(Mutex::new(None));
+        let reported2 = Arc::clone(&reported);
+        rt.set_task_panic_hook(move |name, payload| {
+            let msg = payload.downcast_ref::<&str>().map(|msg| msg.to_string());
+            *reported2.lock().unwrap() = Some((name.to_string(), msg));
+        });
+
+        rt.spawn_critical_task("this is a critical task", async { panic!("intentionally panic") });
+
+        rt.handle().block_on(async move {
+            assert!(handle.await.unwrap().is_err());
+        });
+        assert_eq!(
+            reported.lock().unwrap().take(),
+            Some(("this is a critical task".to_string(), Some("intentionally panic".to_string())))
+        );
+    }
+
     #[test]
     fn test_manager_shutdown_critical() {
         let rt = Runtime::test();
@@ -340,6 +374,30 @@ mod tests {
         assert!(!val.load(Ordering::Relaxed));
     }
 
+    #[test]
+    fn test_running_graceful_tasks() {
+        let rt = Runtime::test();
+
+        let timeout = Duration::from_millis(100);
+        rt.spawn_critical_with_graceful_shutdown_signal("stuck", async move |shutdown| {
+            let _guard = shutdown.await;
+            tokio::time::sleep(timeout * 10).await;
+        });
+        rt.spawn_with_graceful_shutdown_signal("stuck regular", async move |shutdown| {
+            let _guard = shutdown.await;
+            tokio::time::sleep(timeout * 10).await;
+        });
+        rt.spawn_critical_with_graceful_shutdown_signal("done", async move |_shutdown| {});
+
+        // wait for the completed task to be unregistered
+        while rt.running_graceful_tasks().contains(&"done") {
+            std::thread::yield_now();
+        }
+
+        assert!(!rt.graceful_shutdown_with_timeout(timeout));
+        assert_eq!(rt.running_graceful_tasks(), vec!["stuck", "stuck regular"]);
+    }
+
     #[test]
     fn can_build_runtime() {
         let rt = Runtime::test();
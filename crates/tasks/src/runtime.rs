@@ -17,22 +17,41 @@ use crate::{
 use futures_util::{future::select, Future, FutureExt, TryFutureExt};
 #[cfg(feature = "rayon")]
 use std::sync::OnceLock;
-#[cfg(feature = "rayon")]
-use std::{num::NonZeroUsize, thread::available_parallelism};
 use std::{
+    any::Any,
+    collections::HashMap,
     pin::pin,
     sync::{
         atomic::{AtomicUsize, Ordering},
-        Arc, Mutex,
+        Arc, Mutex, RwLock,
     },
     time::{Duration, Instant},
 };
+#[cfg(feature = "rayon")]
+use std::{num::NonZeroUsize, thread::available_parallelism};
 use tokio::{runtime::Handle, sync::mpsc::UnboundedSender, task::JoinHandle};
-use tracing::{debug, error};
+use tracing::{debug, error, warn};
 use tracing_futures::Instrument;
 
 use tokio::runtime::Runtime as TokioRuntime;
 
+/// Callback invoked with the task name and panic payload when a critical task panics.
+pub type TaskPanicHook = Arc<dyn Fn(&str, &dyn Any) + Send + Sync>;
+
+/// Shared slot for the [`TaskPanicHook`], so a hook registered after a task was spawned still
+/// applies to it.
+#[derive(Clone, Default)]
+struct TaskPanicHookSlot(Arc<RwLock<Option<TaskPanicHook>>>);
+
+impl TaskPanicHookSlot {
+    /// Invokes the registered hook, if any.
+    fn call(&self, name: &str, payload: &dyn Any) {
+        if let Some(hook) = self.0.read().unwrap().as_ref() {
+            hook(name, payload);
+        }
+    }
+}
+
 /// Default thread keep-alive duration for the tokio runtime.
 pub const DEFAULT_THREAD_KEEP_ALIVE: Duration = Duration::from_secs(15);
 
@@ -208,6 +227,13 @@ pub struct RuntimeConfig {
     /// Rayon thread pool configuration.
     #[cfg(feature = "rayon")]
     pub rayon: RayonConfig,
+    /// CPU cores to pin critical threads to. If `None`, critical threads are not pinned.
+    ///
+    /// Only dedicated threads are pinned, see [`Runtime::pin_to_critical_cores`].
+    ///
+    /// Only supported on Linux, see
+    /// [`is_thread_affinity_supported`](crate::utils::is_thread_affinity_supported).
+    pub critical_cpu_cores: Option<Vec<usize>>,
 }
 
 impl RuntimeConfig {
@@ -223,6 +249,12 @@ impl RuntimeConfig {
         self.rayon = rayon;
         self
     }
+
+    /// Set the CPU cores to pin critical threads to.
+    pub fn with_critical_cpu_cores(mut self, cores: Vec<usize>) -> Self {
+        self.critical_cpu_cores = Some(cores);
+        self
+    }
 }
 
 /// Error returned when [`RuntimeBuilder::build`] fails.
@@ -278,8 +310,15 @@ struct RuntimeInner {
     task_events_tx: UnboundedSender<TaskEvent>,
     /// Task executor metrics.
     metrics: TaskExecutorMetrics,
+    /// Hook invoked when a critical task panics.
+    task_panic_hook: TaskPanicHookSlot,
     /// How many [`GracefulShutdown`] tasks are currently active.
     graceful_tasks: Arc<AtomicUsize>,
+    /// Names of the running critical tasks spawned with a [`GracefulShutdown`] signal, with the
+    /// number of running instances per name.
+    graceful_task_names: Arc<Mutex<HashMap<&'static str, usize>>>,
+    /// CPU cores critical threads are pinned to, if any.
+    critical_cpu_cores: Option<Arc<[usize]>>,
     /// General-purpose rayon CPU pool.
     #[cfg(feature = "rayon")]
     cpu_pool: rayon::ThreadPool,
@@ -342,11 +381,45 @@ impl Runtime {
         self.0.task_manager_handle.lock().unwrap().take()
     }
 
+    /// Registers a hook that is invoked with the task name and panic payload whenever a critical
+    /// task panics.
+    ///
+    /// The hook runs in addition to the default error logging and replaces any previously
+    /// registered hook.
+    pub fn set_task_panic_hook<F>(&self, hook: F)
+    where
+        F: Fn(&str, &dyn Any) + Send + Sync + 'static,
+    {
+        *self.0.task_panic_hook.0.write().unwrap() = Some(Arc::new(hook));
+    }
+
+    /// Returns the currently registered task panic hook, if any.
+    pub fn task_panic_hook(&self) -> Option<TaskPanicHook> {
+        self.0.task_panic_hook.0.read().unwrap().clone()
+    }
+
     /// Returns the tokio runtime [`Handle`].
     pub fn handle(&self) -> &Handle {
         &self.0.handle
     }
 
+    /// Returns the CPU cores critical threads are pinned to, if configured.
+    pub fn critical_cpu_cores(&self) -> Option<&[usize]> {
+        self.0.critical_cpu_cores.as_deref()
+    }
+
+    /// Pins the current thread to the configured critical CPU cores.
+    ///
+    /// This is a no-op if no critical cores are configured or thread affinity is not supported on
+    /// this platform.
+    ///
+    /// The pinning is never undone, so this must only be called on dedicated OS threads, e.g. the
+    /// engine thread or a thread of [`Self::spawn_blocking_named`], and not on threads of tokio's
+    /// blocking pool that are reused for unrelated work.
+    pub fn pin_to_critical_cores(&self) {
+        pin_to_cores(self.0.critical_cpu_cores.as_deref());
+    }
+
     /// Get the general-purpose rayon CPU thread pool.
     #[cfg(feature = "rayon")]
     pub fn cpu_pool(&self) -> &rayon::ThreadPool {
@@ -411,7 +484,7 @@ impl Runtime {
         RuntimeBuilder::new(config).build().expect("failed to build test Runtime")
     }
 
-    const fn test_config() -> RuntimeConfig {
+    pub(crate) const fn test_config() -> RuntimeConfig {
         RuntimeConfig {
             tokio: TokioConfig::Owned {
                 worker_threads: Some(2),
@@ -430,6 +503,7 @@ impl Runtime {
                 prewarming_threads: Some(2),
                 bal_streaming_threads: Some(2),
             },
+            critical_cpu_cores: None,
         }
     }
 }
@@ -578,12 +652,14 @@ impl Runtime {
     {
         self.0.metrics.inc_critical_tasks();
         let panicked_tasks_tx = self.0.task_events_tx.clone();
+        let task_panic_hook = self.0.task_panic_hook.clone();
         let on_shutdown = self.0.on_shutdown.clone();
 
         // wrap the task in catch unwind
         let task = std::panic::AssertUnwindSafe(fut)
             .catch_unwind()
             .map_err(move |error| {
+                task_panic_hook.call(name, &*error);
                 let task_error = PanickedTaskError::new(name, error);
                 error!("{task_error}");
                 let _ = panicked_tasks_tx.send(TaskEvent::Panic(task_error));
@@ -652,16 +728,20 @@ impl Runtime {
         F: Future<Output = ()> + Send + 'static,
     {
         let panicked_tasks_tx = self.0.task_events_tx.clone();
+        let task_panic_hook = self.0.task_panic_hook.clone();
         let on_shutdown = GracefulShutdown::new(
             self.0.on_shutdown.clone(),
             GracefulShutdownGuard::new(Arc::clone(&self.0.graceful_tasks)),
         );
         let fut = f(on_shutdown);
+        let running = RunningTaskGuard::new(name, Arc::clone(&self.0.graceful_task_names));
+        let fut = fut.map(move |()| drop(running));
 
         // wrap the task in catch unwind
         let task = std::panic::AssertUnwindSafe(fut)
             .catch_unwind()
             .map_err(move |error| {
+                task_panic_hook.call(name, &*error);
                 let task_error = PanickedTaskError::new(name, error);
                 error!("{task_error}");
                 let _ = panicked_tasks_tx.send(TaskEvent::Panic(task_error));
@@ -681,7 +761,7 @@ impl Runtime {
     /// ```no_run
     /// # async fn t(executor: reth_tasks::TaskExecutor) {
     ///
-    /// executor.spawn_with_graceful_shutdown_signal(async move |shutdown| {
+    /// executor.spawn_with_graceful_shutdown_signal("grace", async move |shutdown| {
     ///     // await the shutdown signal
     ///     let guard = shutdown.await;
     ///     // do work before exiting the program
@@ -693,6 +773,7 @@ impl Runtime {
     /// ```
     pub fn spawn_with_graceful_shutdown_signal<F>(
         &self,
+        name: &'static str,
         f: impl FnOnce(GracefulShutdown) -> F,
     ) -> JoinHandle<()>
     where
@@ -703,6 +784,8 @@ impl Runtime {
             GracefulShutdownGuard::new(Arc::clone(&self.0.graceful_tasks)),
         );
         let fut = f(on_shutdown);
+        let running = RunningTaskGuard::new(name, Arc::clone(&self.0.graceful_task_names));
+        let fut = fut.map(move |()| drop(running));
 
         self.0.handle.spawn(fut)
     }
@@ -724,6 +807,24 @@ impl Runtime {
         ))
     }
 
+    /// Returns the names of the tasks spawned with a [`GracefulShutdown`] signal that are still
+    /// running, sorted by name.
+    ///
+    /// See [`Self::spawn_critical_with_graceful_shutdown_signal`] and
+    /// [`Self::spawn_with_graceful_shutdown_signal`].
+    pub fn running_graceful_tasks(&self) -> Vec<&'static str> {
+        let mut names = self
+            .0
+            .graceful_task_names
+            .lock()
+            .unwrap_or_else(|err| err.into_inner())
+            .keys()
+            .copied()
+            .collect::<Vec<_>>();
+        names.sort_unstable();
+        names
+    }
+
     /// Fires the shutdown signal and waits until all graceful tasks complete.
     pub fn graceful_shutdown(&self) {
         let _ = self.do_graceful_shutdown(None);
@@ -742,7 +843,11 @@ impl Runtime {
         let deadline = timeout.map(|t| Instant::now() + t);
         while self.0.graceful_tasks.load(Ordering::SeqCst) > 0 {
             if deadline.is_some_and(|d| Instant::now() > d) {
-                debug!("graceful shutdown timed out");
+                warn!(
+                    remaining = self.0.graceful_tasks.load(Ordering::SeqCst),
+                    running = ?self.running_graceful_tasks(),
+                    "graceful shutdown timed out"
+                );
                 return false;
             }
             std::thread::yield_now();
@@ -752,6 +857,43 @@ impl Runtime {
     }
 }
 
+/// Tracks a running named task in the shared registry until dropped.
+struct RunningTaskGuard {
+    name: &'static str,
+    names: Arc<Mutex<HashMap<&'static str, usize>>>,
+}
+
+impl RunningTaskGuard {
+    fn new(name: &'static str, names: Arc<Mutex<HashMap<&'static str, usize>>>) -> Self {
+        *names.lock().unwrap_or_else(|err| err.into_inner()).entry(name).or_default() += 1;
+        Self { name, names }
+    }
+}
+
+impl Drop for RunningTaskGuard {
+    fn drop(&mut self) {
+        let mut names = self.names.lock().unwrap_or_else(|err| err.into_inner());
+        if let Some(count) = names.get_mut(self.name) {
+            *count -= 1;
+            if *count == 0 {
+                names.remove(self.name);
+            }
+        }
+    }
+}
+
+/// Pins the current thread to the given CPU cores, if any and supported.
+fn pin_to_cores(cores: Option<&[usize]>) {
+    let Some(cores) = cores else { return };
+    if !crate::utils::is_thread_affinity_supported() {
+        return
+    }
+    if let Err(err) = crate::utils::set_current_thread_affinity(cores) {
+        let thread_name = std::thread::current().name().unwrap_or("unnamed").to_string();
+        warn!(%thread_name, ?cores, %err, "failed to pin thread to critical CPU cores");
+    }
+}
+
 // ── RuntimeBuilder ────────────────────────────────────────────────────
 
 /// Builder for constructing a [`Runtime`].
@@ -886,6 +1028,13 @@ impl RuntimeBuilder {
             )
         };
 
+        let critical_cpu_cores = config.critical_cpu_cores.map(Arc::<[usize]>::from);
+        if critical_cpu_cores.is_some() && !crate::utils::is_thread_affinity_supported() {
+            warn!(
+                "Pinning critical threads to CPU cores is not supported on this platform, ignoring configured cores"
+            );
+        }
+
         let task_manager_handle = handle.spawn(async move {
             let result = task_manager.await;
             if let Err(ref err) = result {
@@ -900,7 +1049,10 @@ impl RuntimeBuilder {
             on_shutdown,
             task_events_tx,
             metrics: Default::default(),
+            task_panic_hook: Default::default(),
             graceful_tasks,
+            graceful_task_names: Default::default(),
+            critical_cpu_cores,
             #[cfg(feature = "rayon")]
             cpu_pool,
             #[cfg(feature = "rayon")]
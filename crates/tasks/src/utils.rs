@@ -34,6 +34,84 @@ pub fn increase_thread_priority() {
     }
 }
 
+/// Returns whether pinning threads to CPU cores is supported on this platform.
+///
+/// | Platform | Supported |
+/// |----------|-----------|
+/// | Linux    | yes       |
+/// | Other    | no        |
+pub const fn is_thread_affinity_supported() -> bool {
+    cfg!(target_os = "linux")
+}
+
+/// Pins the current thread to the given set of CPU cores.
+///
+/// Returns an [`ErrorKind::Unsupported`](std::io::ErrorKind::Unsupported) error on platforms
+/// where thread affinity is not supported, see [`is_thread_affinity_supported`].
+pub fn set_current_thread_affinity(cores: &[usize]) -> std::io::Result<()> {
+    #[cfg(target_os = "linux")]
+    {
+        // SAFETY: `cpu_set_t` is a plain bitmask for which all zeroes is a valid (empty) value,
+        // and `sched_setaffinity` with pid 0 only affects the calling thread.
+        unsafe {
+            let mut set: libc::cpu_set_t = std::mem::zeroed();
+            for &core in cores {
+                if core >= libc::CPU_SETSIZE as usize {
+                    return Err(std::io::Error::new(
+                        std::io::ErrorKind::InvalidInput,
+                        format!("cpu core {core} is out of range"),
+                    ))
+                }
+                libc::CPU_SET(core, &mut set);
+            }
+            if libc::sched_setaffinity(0, size_of::<libc::cpu_set_t>(), std::ptr::from_ref(&set)) !=
+                0
+            {
+                return Err(std::io::Error::last_os_error())
+            }
+        }
+        Ok(())
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    {
+        let _ = cores;
+        Err(std::io::Error::new(
+            std::io::ErrorKind::Unsupported,
+            "thread affinity is not supported on this platform",
+        ))
+    }
+}
+
+/// Returns the CPU cores the current thread is allowed to run on.
+///
+/// Returns an [`ErrorKind::Unsupported`](std::io::ErrorKind::Unsupported) error on platforms
+/// where thread affinity is not supported, see [`is_thread_affinity_supported`].
+pub fn current_thread_affinity() -> std::io::Result<Vec<usize>> {
+    #[cfg(target_os = "linux")]
+    {
+        // SAFETY: `cpu_set_t` is a plain bitmask for which all zeroes is a valid (empty) value,
+        // and `sched_getaffinity` with pid 0 only reads the mask of the calling thread.
+        unsafe {
+            let mut set: libc::cpu_set_t = std::mem::zeroed();
+            if libc::sched_getaffinity(0, size_of::<libc::cpu_set_t>(), &raw mut set) != 0 {
+                return Err(std::io::Error::last_os_error())
+            }
+            Ok((0..libc::CPU_SETSIZE as usize)
+                .filter(|&core| libc::CPU_ISSET(core, &set))
+                .collect())
+        }
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    {
+        Err(std::io::Error::new(
+            std::io::ErrorKind::Unsupported,
+            "thread affinity is not supported on this platform",
+        ))
+    }
+}
+
 /// Deprioritizes known background threads spawned by third-party libraries (`OpenTelemetry`,
 /// `tracing-appender`, `reqwest`) by scanning `/proc/<pid>/task/` for matching thread names and
 /// setting `SCHED_IDLE` scheduling policy + maximum niceness on them.
@@ -103,3 +181,40 @@ fn _deprioritize_background_threads() {
         tracing::debug!(tid, comm, "deprioritized background thread (SCHED_IDLE)");
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[cfg(target_os = "linux")]
+    fn set_current_thread_affinity_pins_thread() {
+        std::thread::spawn(|| {
+            let allowed = current_thread_affinity().unwrap();
+            let core = allowed[0];
+
+            set_current_thread_affinity(&[core]).unwrap();
+            assert_eq!(current_thread_affinity().unwrap(), [core]);
+
+            // restore the previous affinity
+            set_current_thread_affinity(&allowed).unwrap();
+            assert_eq!(current_thread_affinity().unwrap(), allowed);
+        })
+        .join()
+        .unwrap();
+    }
+
+    #[test]
+    #[cfg(target_os = "linux")]
+    fn set_current_thread_affinity_rejects_out_of_range_core() {
+        let err = set_current_thread_affinity(&[libc::CPU_SETSIZE as usize]).unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidInput);
+    }
+
+    #[test]
+    #[cfg(not(target_os = "linux"))]
+    fn set_current_thread_affinity_unsupported() {
+        let err = set_current_thread_affinity(&[0]).unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::Unsupported);
+    }
+}
@@ -72,6 +72,9 @@ pub struct PoolConfig {
     ///
     /// This restricts how many executable transaction a delegated sender can stack.
     pub max_inflight_delegated_slot_limit: usize,
+    /// Whether to reject legacy transactions without a chain ID, i.e. transactions that are not
+    /// replay protected as per EIP-155.
+    pub require_chain_id: bool,
 }
 
 impl PoolConfig {
@@ -100,6 +103,12 @@ impl PoolConfig {
         self
     }
 
+    /// Configures whether legacy transactions without a chain ID are rejected.
+    pub const fn with_require_chain_id(mut self, require_chain_id: bool) -> Self {
+        self.require_chain_id = require_chain_id;
+        self
+    }
+
     /// Returns whether the size and amount constraints in any sub-pools are exceeded.
     #[inline]
     pub const fn is_exceeded(&self, pool_size: PoolSize) -> bool {
@@ -129,6 +138,7 @@ impl Default for PoolConfig {
             max_new_pending_txs_notifications: MAX_NEW_PENDING_TXS_NOTIFICATIONS,
             max_queued_lifetime: MAX_QUEUED_TRANSACTION_LIFETIME,
             max_inflight_delegated_slot_limit: DEFAULT_MAX_INFLIGHT_DELEGATED_SLOTS,
+            require_chain_id: false,
         }
     }
 }
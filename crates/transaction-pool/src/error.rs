@@ -221,6 +221,30 @@ pub enum Eip7702PoolTransactionError {
     AuthorityReserved,
 }
 
+/// The reason a transaction was rejected by a custom admission policy.
+///
+/// Returned as [`InvalidPoolTransactionError::Other`].
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("transaction rejected: {0}")]
+pub struct RejectReason(pub String);
+
+impl RejectReason {
+    /// Creates a new [`RejectReason`] with the given message.
+    pub fn new(reason: impl Into<String>) -> Self {
+        Self(reason.into())
+    }
+}
+
+impl PoolTransactionError for RejectReason {
+    fn is_bad_transaction(&self) -> bool {
+        false
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
 /// Represents errors that can happen when validating transactions for the pool
 ///
 /// See [`TransactionValidator`](crate::TransactionValidator).
@@ -293,6 +317,10 @@ pub enum InvalidPoolTransactionError {
         /// Minimum required priority fee.
         minimum_priority_fee: u128,
     },
+    /// Thrown if the transaction is not replay protected, i.e. a legacy transaction without a
+    /// chain ID, but the pool is configured to require one.
+    #[error("replay-protected (EIP-155) transaction required: missing chain id")]
+    MissingChainId,
 }
 
 // === impl InvalidPoolTransactionError ===
@@ -412,6 +440,10 @@ impl InvalidPoolTransactionError {
                 Eip7702PoolTransactionError::AuthorityReserved => false,
             },
             Self::PriorityFeeBelowMinimum { .. } => false,
+            Self::MissingChainId => {
+                // local setting
+                false
+            }
         }
     }
 
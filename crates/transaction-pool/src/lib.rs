@@ -299,12 +299,13 @@ pub use crate::{
         TransactionValidator, ValidPoolTransaction,
     },
 };
-use crate::{identifier::TransactionId, pool::PoolInner};
+use crate::{error::RejectReason, identifier::TransactionId, pool::PoolInner};
 use alloy_eips::{
+    eip2718::Encodable2718,
     eip4844::{BlobAndProofV1, BlobAndProofV2},
     eip7594::BlobTransactionSidecarVariant,
 };
-use alloy_primitives::{map::AddressSet, Address, TxHash, B256, U256};
+use alloy_primitives::{map::AddressSet, Address, Bytes, TxHash, B256, U256};
 use aquamarine as _;
 use reth_chainspec::{ChainSpecProvider, EthereumHardforks};
 use reth_eth_wire_types::HandleMempoolData;
@@ -386,6 +387,30 @@ where
         self.pool.validator().validate_transaction(origin, transaction).await
     }
 
+    /// Checks whether the pool would accept the given external transaction, without inserting
+    /// it.
+    ///
+    /// This runs the pool's validator against the current state, which checks e.g. the nonce,
+    /// balance, fees and size limits, and discards the result. Transactions that are already in
+    /// the pool are rejected. Checks that depend on the other transactions in the pool, like
+    /// replacement price bumps and pool capacity, are not covered.
+    ///
+    /// Note: a transaction that would be accepted now can still be rejected when it is actually
+    /// submitted, or never be included, if the state changes in the meantime.
+    pub async fn would_accept(&self, transaction: V::Transaction) -> Result<(), RejectReason> {
+        if self.pool.get(transaction.hash()).is_some() {
+            return Err(RejectReason::new("transaction already known"))
+        }
+
+        match self.validate(TransactionOrigin::External, transaction).await {
+            TransactionValidationOutcome::Valid { .. } => Ok(()),
+            TransactionValidationOutcome::Invalid(_, err) => {
+                Err(RejectReason::new(err.to_string()))
+            }
+            TransactionValidationOutcome::Error(_, err) => Err(RejectReason::new(err.to_string())),
+        }
+    }
+
     /// Number of transactions in the entire pool
     pub fn len(&self) -> usize {
         self.pool.len()
@@ -405,6 +430,25 @@ where
     pub fn blob_store(&self) -> &S {
         self.pool.blob_store()
     }
+
+    /// Returns the consensus encoding ([EIP-2718](https://eips.ethereum.org/EIPS/eip-2718)) of all
+    /// pending transactions.
+    ///
+    /// The pending set is snapshotted when this is called, transactions that are added or removed
+    /// afterwards are not reflected in the output. The transactions are ordered by the current
+    /// priority of the pool, as yielded by [`TransactionPool::best_transactions`].
+    pub fn export_pending_rlp(&self) -> impl Future<Output = Vec<Bytes>> + Send + use<V, T, S> {
+        let mut best = self.pool.best_transactions();
+        best.no_updates();
+        let pending = best.collect::<Vec<_>>();
+
+        async move {
+            pending
+                .into_iter()
+                .map(|tx| tx.transaction.clone_into_consensus().encoded_2718().into())
+                .collect()
+        }
+    }
 }
 
 impl<Client, S, Evm> EthTransactionPool<Client, S, Evm>
@@ -476,6 +520,10 @@ where
         self.pool.size()
     }
 
+    fn pool_status(&self) -> PoolStatus {
+        self.pool.status()
+    }
+
     fn block_info(&self) -> BlockInfo {
         self.pool.block_info()
     }
@@ -6,7 +6,7 @@ use crate::{
     metrics::MaintainPoolMetrics,
     traits::{CanonicalStateUpdate, EthPoolTransaction, TransactionPool, TransactionPoolExt},
     AllPoolTransactions, BlobTransactionSidecarVariant, BlockInfo, PoolTransaction, PoolUpdateKind,
-    TransactionOrigin,
+    TransactionOrigin, ValidPoolTransaction,
 };
 use alloy_consensus::{transaction::TxHashRef, BlockHeader, Typed2718};
 use alloy_eips::{BlockNumberOrTag, Decodable2718, Encodable2718};
@@ -692,6 +692,65 @@ where
     Ok(res)
 }
 
+/// Outcome of a manual pool re-validation pass, see [`revalidate_pool`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RevalidationReport {
+    /// Number of senders whose account state was reloaded.
+    pub reloaded_accounts: usize,
+    /// Number of senders whose account state failed to load.
+    pub failed_accounts: usize,
+    /// Number of transactions that were dropped from the pool.
+    pub dropped: usize,
+}
+
+/// Re-validates all transactions in the pool against the state of the last seen block.
+///
+/// This performs the same account reload the maintenance task runs when the pool drifted from the
+/// current state: the nonce and balance of every sender is reloaded from state and transactions
+/// that are no longer valid are discarded. The maintenance task only does this in response to new
+/// blocks, this can be used to trigger it on demand.
+pub async fn revalidate_pool<Client, P>(
+    client: Client,
+    pool: P,
+    task_spawner: Runtime,
+) -> RevalidationReport
+where
+    Client: StateProviderFactory + 'static,
+    P: TransactionPoolExt + 'static,
+{
+    let mut report = RevalidationReport::default();
+    let transactions = pool.all_transaction_hashes();
+    let at = pool.block_info().last_seen_block_hash;
+    let senders = pool.unique_senders();
+
+    let (tx, rx) = oneshot::channel();
+    task_spawner.spawn_blocking_task(async move {
+        let _ = tx.send(load_accounts(client, at, senders));
+    });
+
+    match rx.await {
+        Ok(Ok(LoadedAccounts { accounts, failed_to_load })) => {
+            report.reloaded_accounts = accounts.len();
+            report.failed_accounts = failed_to_load.len();
+            pool.update_accounts(accounts);
+        }
+        Ok(Err(res)) => {
+            let (accs, err) = *res;
+            debug!(target: "txpool", %err, "failed to load accounts for revalidation");
+            report.failed_accounts = accs.len();
+        }
+        Err(_) => {
+            // sender dropped, only possible if task panicked
+            warn!(target: "txpool", "failed to receive accounts for revalidation");
+        }
+    }
+
+    let total = transactions.len();
+    report.dropped = total - pool.get_all(transactions).len();
+    debug!(target: "txpool", ?report, "revalidated pool");
+    report
+}
+
 /// Loads transactions from a file, decodes them from the JSON or RLP format, and
 /// inserts them into the transaction pool on node boot up.
 /// The file is removed after the transactions have been successfully processed.
@@ -713,37 +772,7 @@ where
         return Ok(())
     }
 
-    let pool_transactions: Vec<(TransactionOrigin, <P as TransactionPool>::Transaction)> =
-        if let Ok(tx_backups) = serde_json::from_slice::<Vec<TxBackup>>(&data) {
-            tx_backups
-                .into_iter()
-                .filter_map(|backup| {
-                    let tx_signed =
-                        <P::Transaction as PoolTransaction>::Consensus::decode_2718_exact(
-                            backup.rlp.as_ref(),
-                        )
-                        .ok()?;
-                    let recovered = tx_signed.try_into_recovered().ok()?;
-                    let pool_tx =
-                        <P::Transaction as PoolTransaction>::try_from_consensus(recovered).ok()?;
-
-                    Some((backup.origin, pool_tx))
-                })
-                .collect()
-        } else {
-            let txs_signed: Vec<<P::Transaction as PoolTransaction>::Consensus> =
-                alloy_rlp::Decodable::decode(&mut data.as_slice())?;
-
-            txs_signed
-                .into_iter()
-                .filter_map(|tx| tx.try_into_recovered().ok())
-                .filter_map(|tx| {
-                    <P::Transaction as PoolTransaction>::try_from_consensus(tx)
-                        .ok()
-                        .map(|pool_tx| (TransactionOrigin::Local, pool_tx))
-                })
-                .collect()
-        };
+    let pool_transactions = decode_transactions_backup::<P::Transaction>(&data)?;
 
     let inserted = futures_util::future::join_all(
         pool_transactions.into_iter().map(|(origin, tx)| pool.add_transaction(origin, tx)),
@@ -755,17 +784,48 @@ where
     Ok(())
 }
 
-fn save_local_txs_backup<P>(pool: P, file_path: &Path)
+/// Decodes a transactions backup, either a JSON list of [`TxBackup`]s or an RLP list of
+/// transactions.
+///
+/// Transactions that fail to decode or recover are skipped.
+fn decode_transactions_backup<T>(
+    data: &[u8],
+) -> Result<Vec<(TransactionOrigin, T)>, TransactionsBackupError>
 where
-    P: TransactionPool<Transaction: PoolTransaction<Consensus: Encodable>>,
+    T: PoolTransaction<Consensus: SignedTransaction>,
 {
-    let local_transactions = pool.get_local_transactions();
-    if local_transactions.is_empty() {
-        trace!(target: "txpool", "no local transactions to save");
-        return
-    }
+    let pool_transactions = if let Ok(tx_backups) = serde_json::from_slice::<Vec<TxBackup>>(data) {
+        tx_backups
+            .into_iter()
+            .filter_map(|backup| {
+                let tx_signed = T::Consensus::decode_2718_exact(backup.rlp.as_ref()).ok()?;
+                let recovered = tx_signed.try_into_recovered().ok()?;
+                let pool_tx = T::try_from_consensus(recovered).ok()?;
+
+                Some((backup.origin, pool_tx))
+            })
+            .collect()
+    } else {
+        let txs_signed: Vec<T::Consensus> = alloy_rlp::Decodable::decode(&mut &data[..])?;
+
+        txs_signed
+            .into_iter()
+            .filter_map(|tx| tx.try_into_recovered().ok())
+            .filter_map(|tx| {
+                T::try_from_consensus(tx).ok().map(|pool_tx| (TransactionOrigin::Local, pool_tx))
+            })
+            .collect()
+    };
 
-    let local_transactions = local_transactions
+    Ok(pool_transactions)
+}
+
+/// Encodes the given pool transactions as [`TxBackup`]s.
+fn encode_transactions_backup<T>(transactions: Vec<Arc<ValidPoolTransaction<T>>>) -> Vec<TxBackup>
+where
+    T: PoolTransaction<Consensus: Encodable>,
+{
+    transactions
         .into_iter()
         .map(|tx| {
             let consensus_tx = tx.to_consensus().into_inner();
@@ -773,7 +833,115 @@ where
 
             TxBackup { rlp: rlp_data.into(), origin: tx.origin }
         })
-        .collect::<Vec<_>>();
+        .collect()
+}
+
+/// Writes all transactions currently in the pool to `file_path`, in the same format as the local
+/// transactions backup, see [`TxBackup`].
+///
+/// Serialization and file I/O run on a blocking task of the given runtime.
+///
+/// Returns the number of transactions written.
+pub async fn snapshot_pool<P>(
+    pool: &P,
+    file_path: &Path,
+    task_spawner: Runtime,
+) -> Result<usize, TransactionsBackupError>
+where
+    P: TransactionPool<Transaction: PoolTransaction<Consensus: Encodable>>,
+{
+    let AllPoolTransactions { pending, queued } = pool.all_transactions();
+    let transactions = pending.into_iter().chain(queued).collect();
+    let path = file_path.to_path_buf();
+
+    let (tx, rx) = oneshot::channel();
+    task_spawner.spawn_blocking_task(async move {
+        let _ = tx.send(write_pool_snapshot(transactions, &path));
+    });
+    rx.await.map_err(|_| TransactionsBackupError::TaskDropped)?
+}
+
+/// Encodes the given transactions and writes them to `file_path`.
+fn write_pool_snapshot<T>(
+    transactions: Vec<Arc<ValidPoolTransaction<T>>>,
+    file_path: &Path,
+) -> Result<usize, TransactionsBackupError>
+where
+    T: PoolTransaction<Consensus: Encodable>,
+{
+    let transactions = encode_transactions_backup(transactions);
+    let json_data = serde_json::to_string(&transactions)?;
+
+    if let Some(parent) = file_path.parent() {
+        reth_fs_util::create_dir_all(parent)?;
+    }
+    reth_fs_util::write(file_path, json_data)?;
+
+    info!(target: "txpool", txs_file=?file_path, num_txs=%transactions.len(), "Wrote pool snapshot to file");
+    Ok(transactions.len())
+}
+
+/// Reads a snapshot written by [`snapshot_pool`] or a local transactions backup from `file_path`
+/// and inserts the transactions into the pool with their original origin.
+///
+/// File I/O and decoding run on a blocking task of the given runtime. The transactions are
+/// validated against the current state like any new transaction, so transactions that became
+/// invalid since the snapshot was taken are not inserted. The file is kept.
+///
+/// Returns the number of transactions that were inserted.
+pub async fn restore_pool<P>(
+    pool: &P,
+    file_path: &Path,
+    task_spawner: Runtime,
+) -> Result<usize, TransactionsBackupError>
+where
+    P: TransactionPool<Transaction: PoolTransaction<Consensus: SignedTransaction>>,
+{
+    let path = file_path.to_path_buf();
+    let (tx, rx) = oneshot::channel();
+    task_spawner.spawn_blocking_task(async move {
+        let _ = tx.send(read_pool_snapshot::<P::Transaction>(&path));
+    });
+    let pool_transactions = rx.await.map_err(|_| TransactionsBackupError::TaskDropped)??;
+
+    let total = pool_transactions.len();
+    let inserted = futures_util::future::join_all(
+        pool_transactions.into_iter().map(|(origin, tx)| pool.add_transaction(origin, tx)),
+    )
+    .await
+    .into_iter()
+    .filter(Result::is_ok)
+    .count();
+
+    info!(target: "txpool", txs_file=?file_path, num_txs=%total, inserted, "Restored pool snapshot from file");
+    Ok(inserted)
+}
+
+/// Reads and decodes the transactions of a snapshot or backup file.
+fn read_pool_snapshot<T>(
+    file_path: &Path,
+) -> Result<Vec<(TransactionOrigin, T)>, TransactionsBackupError>
+where
+    T: PoolTransaction<Consensus: SignedTransaction>,
+{
+    let data = reth_fs_util::read(file_path)?;
+    if data.is_empty() {
+        return Ok(Vec::new())
+    }
+    decode_transactions_backup(&data)
+}
+
+fn save_local_txs_backup<P>(pool: P, file_path: &Path)
+where
+    P: TransactionPool<Transaction: PoolTransaction<Consensus: Encodable>>,
+{
+    let local_transactions = pool.get_local_transactions();
+    if local_transactions.is_empty() {
+        trace!(target: "txpool", "no local transactions to save");
+        return
+    }
+
+    let local_transactions = encode_transactions_backup(local_transactions);
 
     let json_data = match serde_json::to_string(&local_transactions) {
         Ok(data) => data,
@@ -821,6 +989,9 @@ pub enum TransactionsBackupError {
     /// Error adding transactions to the transaction pool
     #[error("failed to insert transactions to the transactions pool. Encountered pool error: {0}")]
     Pool(#[from] PoolError),
+    /// The blocking task reading or writing the file was dropped before it finished
+    #[error("transactions backup task was dropped before it finished")]
+    TaskDropped,
 }
 
 /// Task which manages saving local transactions to the persistent file in case of shutdown.
@@ -922,6 +1093,76 @@ mod tests {
         temp_dir.close().unwrap();
     }
 
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_snapshot_and_restore_pool() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let snapshot_path = temp_dir.path().join("snapshot.json");
+        let tx_bytes = hex!(
+            "02f87201830655c2808505ef61f08482565f94388c818ca8b9251b393131c08a736a67ccb192978801049e39c4b5b1f580c001a01764ace353514e8abdfb92446de356b260e3c1225b73fc4c8876a6258d12a129a04f02294aa61ca7676061cd99f29275491218b4754b46a0248e5e42bc5091f507"
+        );
+        let tx = PooledTransactionVariant::decode_2718(&mut &tx_bytes[..]).unwrap();
+        let provider = MockEthProvider::default().with_genesis_block();
+        let transaction = EthPooledTransaction::from_pooled(tx.try_into_recovered().unwrap());
+        let sender = hex!("1f9090aaE28b8a3dCeaDf281B0F12828e676c326").into();
+        provider.add_account(sender, ExtendedAccount::new(42, U256::MAX));
+        let new_pool = || {
+            let blob_store = InMemoryBlobStore::default();
+            let validator =
+                EthTransactionValidatorBuilder::new(provider.clone(), EthEvmConfig::mainnet())
+                    .build(blob_store.clone());
+            Pool::new(validator, CoinbaseTipOrdering::default(), blob_store, Default::default())
+        };
+
+        let rt = Runtime::test();
+
+        let txpool = new_pool();
+        txpool.add_transaction(TransactionOrigin::External, transaction.clone()).await.unwrap();
+        assert_eq!(snapshot_pool(&txpool, &snapshot_path, rt.clone()).await.unwrap(), 1);
+
+        let restored = new_pool();
+        assert_eq!(restore_pool(&restored, &snapshot_path, rt.clone()).await.unwrap(), 1);
+        assert!(restored.contains(transaction.hash()));
+        assert!(snapshot_path.exists());
+
+        // restoring again fails validation since the transaction is already known
+        assert_eq!(restore_pool(&restored, &snapshot_path, rt).await.unwrap(), 0);
+
+        temp_dir.close().unwrap();
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_revalidate_pool() {
+        let tx_bytes = hex!(
+            "02f87201830655c2808505ef61f08482565f94388c818ca8b9251b393131c08a736a67ccb192978801049e39c4b5b1f580c001a01764ace353514e8abdfb92446de356b260e3c1225b73fc4c8876a6258d12a129a04f02294aa61ca7676061cd99f29275491218b4754b46a0248e5e42bc5091f507"
+        );
+        let tx = PooledTransactionVariant::decode_2718(&mut &tx_bytes[..]).unwrap();
+        let provider = MockEthProvider::default().with_genesis_block();
+        let transaction = EthPooledTransaction::from_pooled(tx.try_into_recovered().unwrap());
+        let sender = hex!("1f9090aaE28b8a3dCeaDf281B0F12828e676c326").into();
+        provider.add_account(sender, ExtendedAccount::new(42, U256::MAX));
+        let blob_store = InMemoryBlobStore::default();
+        let validator =
+            EthTransactionValidatorBuilder::new(provider.clone(), EthEvmConfig::mainnet())
+                .build(blob_store.clone());
+
+        let txpool =
+            Pool::new(validator, CoinbaseTipOrdering::default(), blob_store, Default::default());
+        txpool.add_transaction(TransactionOrigin::External, transaction.clone()).await.unwrap();
+
+        let rt = Runtime::test();
+
+        // nothing changed, the transaction is still valid
+        let report = revalidate_pool(provider.clone(), txpool.clone(), rt.clone()).await;
+        assert_eq!(report, RevalidationReport { reloaded_accounts: 1, ..Default::default() });
+        assert_eq!(txpool.len(), 1);
+
+        // the sender's nonce moved past the transaction's nonce
+        provider.add_account(sender, ExtendedAccount::new(transaction.nonce() + 1, U256::MAX));
+        let report = revalidate_pool(provider, txpool.clone(), rt).await;
+        assert_eq!(report.dropped, 1);
+        assert!(txpool.is_empty());
+    }
+
     #[test]
     fn test_update_with_higher_finalized_block() {
         let mut tracker = FinalizedBlockTracker::new(Some(10));
@@ -11,8 +11,9 @@ use crate::{
     validate::ValidTransaction,
     AddedTransactionOutcome, AllPoolTransactions, AllTransactionsEvents, BestTransactions,
     BlockInfo, EthPoolTransaction, EthPooledTransaction, NewTransactionEvent, PoolResult, PoolSize,
-    PoolTransaction, PropagatedTransactions, TransactionEvents, TransactionOrigin, TransactionPool,
-    TransactionValidationOutcome, TransactionValidator, ValidPoolTransaction,
+    PoolStatus, PoolTransaction, PropagatedTransactions, SubPoolLimit, SubPoolStatus,
+    TransactionEvents, TransactionOrigin, TransactionPool, TransactionValidationOutcome,
+    TransactionValidator, ValidPoolTransaction,
 };
 use alloy_eips::{
     eip1559::ETHEREUM_BLOCK_GAS_LIMIT_30M,
@@ -56,6 +57,11 @@ impl<T: EthPoolTransaction> TransactionPool for NoopTransactionPool<T> {
         Default::default()
     }
 
+    fn pool_status(&self) -> PoolStatus {
+        let empty = SubPoolStatus { txs: 0, size: 0, limit: SubPoolLimit::new(0, 0) };
+        PoolStatus { pending: empty, basefee: empty, queued: empty, blob: empty, unique_senders: 0 }
+    }
+
     fn block_info(&self) -> BlockInfo {
         BlockInfo {
             block_gas_limit: ETHEREUM_BLOCK_GAS_LIMIT_30M,
@@ -81,7 +81,8 @@ use crate::{
     },
     traits::{
         AllPoolTransactions, BestTransactionsAttributes, BlockInfo, GetPooledTransactionLimit,
-        NewBlobSidecar, PoolSize, PoolTransaction, PropagatedTransactions, TransactionOrigin,
+        NewBlobSidecar, PoolSize, PoolStatus, PoolTransaction, PropagatedTransactions,
+        TransactionOrigin,
     },
     validate::{TransactionValidationOutcome, ValidPoolTransaction, ValidTransaction},
     CanonicalStateUpdate, EthPoolTransaction, PoolConfig, TransactionOrdering,
@@ -200,6 +201,11 @@ where
         self.get_pool_data().size()
     }
 
+    /// Returns the utilization of all sub-pools against the configured limits.
+    pub fn status(&self) -> PoolStatus {
+        self.get_pool_data().status()
+    }
+
     /// Returns the currently tracked block
     pub fn block_info(&self) -> BlockInfo {
         self.get_pool_data().block_info()
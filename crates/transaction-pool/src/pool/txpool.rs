@@ -17,7 +17,7 @@ use crate::{
         update::{Destination, PoolUpdate, UpdateOutcome},
         AddedPendingTransaction, AddedTransaction, OnNewCanonicalStateOutcome,
     },
-    traits::{BestTransactionsAttributes, BlockInfo, PoolSize},
+    traits::{BestTransactionsAttributes, BlockInfo, PoolSize, PoolStatus, SubPoolStatus},
     PoolConfig, PoolResult, PoolTransaction, PoolUpdateKind, PriceBumpConfig, TransactionOrdering,
     ValidPoolTransaction, U256,
 };
@@ -210,6 +210,34 @@ impl<T: TransactionOrdering> TxPool<T> {
         }
     }
 
+    /// Returns the utilization of all sub-pools against the configured limits.
+    pub fn status(&self) -> PoolStatus {
+        let size = self.size();
+        PoolStatus {
+            pending: SubPoolStatus {
+                txs: size.pending,
+                size: size.pending_size,
+                limit: self.config.pending_limit,
+            },
+            basefee: SubPoolStatus {
+                txs: size.basefee,
+                size: size.basefee_size,
+                limit: self.config.basefee_limit,
+            },
+            queued: SubPoolStatus {
+                txs: size.queued,
+                size: size.queued_size,
+                limit: self.config.queued_limit,
+            },
+            blob: SubPoolStatus {
+                txs: size.blob,
+                size: size.blob_size,
+                limit: self.config.blob_limit,
+            },
+            unique_senders: self.all_transactions.tx_counter.len(),
+        }
+    }
+
     /// Returns the currently tracked block values
     pub const fn block_info(&self) -> BlockInfo {
         BlockInfo {
@@ -2696,6 +2724,31 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_pool_status() {
+        let mut f = MockTransactionFactory::default();
+        let mut pool = TxPool::new(MockOrdering::default(), Default::default());
+
+        let tx = MockTransaction::eip1559().inc_price().inc_limit();
+        pool.add_transaction(f.validated(tx.clone()), U256::MAX, 0, None).unwrap();
+        pool.add_transaction(f.validated(tx.next()), U256::MAX, 0, None).unwrap();
+        let other = MockTransaction::eip1559().inc_price().inc_limit().with_nonce(1);
+        pool.add_transaction(f.validated(other), U256::MAX, 0, None).unwrap();
+
+        let size = pool.size();
+        let status = pool.status();
+        assert_eq!(status.pending.txs, 2);
+        assert_eq!(status.pending.size, size.pending_size);
+        assert_eq!(status.queued.txs, 1);
+        assert_eq!(status.queued.size, size.queued_size);
+        assert_eq!(status.basefee.txs, 0);
+        assert_eq!(status.blob.txs, 0);
+        assert_eq!(status.pending.limit, pool.config.pending_limit);
+        assert_eq!(status.blob.limit, pool.config.blob_limit);
+        assert_eq!(status.unique_senders, 2);
+        assert!(!status.pending.is_exceeded());
+    }
+
     #[test]
     fn test_insert_pending() {
         let on_chain_balance = U256::MAX;
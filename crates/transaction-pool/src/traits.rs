@@ -58,7 +58,7 @@ use crate::{
         TransactionListenerKind,
     },
     validate::ValidPoolTransaction,
-    AddedTransactionOutcome, AllTransactionsEvents,
+    AddedTransactionOutcome, AllTransactionsEvents, SubPoolLimit,
 };
 use alloy_consensus::{error::ValueError, transaction::TxHashRef, BlockHeader, Signed, Typed2718};
 use alloy_eips::{
@@ -115,6 +115,23 @@ pub trait TransactionPool: Clone + Debug + Send + Sync {
     /// Returns stats about the pool and all sub-pools.
     fn pool_size(&self) -> PoolSize;
 
+    /// Returns the utilization of all sub-pools against their configured limits.
+    ///
+    /// By default this is built from [`Self::pool_size`] and [`Self::unique_senders`], and reports
+    /// [`SubPoolLimit::max`] as the limit of every sub-pool because the configured limits are not
+    /// known.
+    fn pool_status(&self) -> PoolStatus {
+        let size = self.pool_size();
+        let status = |txs, size| SubPoolStatus { txs, size, limit: SubPoolLimit::max() };
+        PoolStatus {
+            pending: status(size.pending, size.pending_size),
+            basefee: status(size.basefee, size.basefee_size),
+            queued: status(size.queued, size.queued_size),
+            blob: status(size.blob, size.blob_size),
+            unique_senders: self.unique_senders().len(),
+        }
+    }
+
     /// Returns the block the pool is currently tracking.
     ///
     /// This tracks the block that the pool has last seen.
@@ -625,6 +642,13 @@ pub trait TransactionPool: Clone + Debug + Send + Sync {
         nonce: u64,
     ) -> Option<Arc<ValidPoolTransaction<Self::Transaction>>>;
 
+    /// Returns the hash of the transaction that occupies the given sender and nonce slot, if any.
+    ///
+    /// This can be used to check whether a submitted transaction would replace an existing one.
+    fn transaction_by_sender_nonce(&self, sender: Address, nonce: u64) -> Option<TxHash> {
+        self.get_transaction_by_sender_and_nonce(sender, nonce).map(|tx| *tx.hash())
+    }
+
     /// Returns all transactions that where submitted with the given [`TransactionOrigin`]
     fn get_transactions_by_origin(
         &self,
@@ -1739,6 +1763,39 @@ impl PoolSize {
     }
 }
 
+/// Utilization of a single sub-pool against its configured [`SubPoolLimit`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SubPoolStatus {
+    /// Number of transactions in the sub-pool.
+    pub txs: usize,
+    /// Reported size of transactions in the sub-pool.
+    pub size: usize,
+    /// The configured limit of the sub-pool.
+    pub limit: SubPoolLimit,
+}
+
+impl SubPoolStatus {
+    /// Returns whether the sub-pool exceeds its configured limit.
+    pub const fn is_exceeded(&self) -> bool {
+        self.limit.is_exceeded(self.txs, self.size)
+    }
+}
+
+/// Utilization of all sub-pools, see [`TransactionPool::pool_status`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PoolStatus {
+    /// Status of the _pending_ sub-pool.
+    pub pending: SubPoolStatus,
+    /// Status of the _basefee_ sub-pool.
+    pub basefee: SubPoolStatus,
+    /// Status of the _queued_ sub-pool.
+    pub queued: SubPoolStatus,
+    /// Status of the _blob_ sub-pool.
+    pub blob: SubPoolStatus,
+    /// Number of unique senders with transactions in the pool.
+    pub unique_senders: usize,
+}
+
 /// Represents the current status of the pool.
 #[derive(Default, Debug, Clone, Copy, Eq, PartialEq)]
 pub struct BlockInfo {
@@ -97,6 +97,8 @@ pub struct EthTransactionValidator<Client, T, Evm> {
     tx_fee_cap: Option<u128>,
     /// Minimum priority fee to enforce for acceptance into the pool.
     minimum_priority_fee: Option<u128>,
+    /// Whether to reject legacy transactions without a chain ID.
+    require_chain_id: bool,
     /// Stores the setup and parameters needed for validating KZG proofs.
     kzg_settings: EnvKzgSettings,
     /// How to handle [`TransactionOrigin::Local`](TransactionOrigin) transactions.
@@ -138,6 +140,7 @@ impl<Client, Tx, Evm> fmt::Debug for EthTransactionValidator<Client, Tx, Evm> {
             .field("block_gas_limit", &self.block_gas_limit)
             .field("tx_fee_cap", &self.tx_fee_cap)
             .field("minimum_priority_fee", &self.minimum_priority_fee)
+            .field("require_chain_id", &self.require_chain_id)
             .field("max_tx_input_bytes", &self.max_tx_input_bytes)
             .field("max_tx_gas_limit", &self.max_tx_gas_limit)
             .field("disable_balance_check", &self.disable_balance_check)
@@ -216,6 +219,11 @@ impl<Client, Tx, Evm> EthTransactionValidator<Client, Tx, Evm> {
         &self.minimum_priority_fee
     }
 
+    /// Returns whether legacy transactions without a chain ID are rejected.
+    pub const fn require_chain_id(&self) -> bool {
+        self.require_chain_id
+    }
+
     /// Returns the setup and parameters needed for validating KZG proofs.
     pub const fn kzg_settings(&self) -> &EnvKzgSettings {
         &self.kzg_settings
@@ -530,10 +538,15 @@ where
         }
 
         // Checks for chainid
-        if let Some(chain_id) = transaction.chain_id() &&
-            chain_id != self.chain_id()
-        {
-            return Err(InvalidTransactionError::ChainIdMismatch.into())
+        match transaction.chain_id() {
+            Some(chain_id) if chain_id != self.chain_id() => {
+                return Err(InvalidTransactionError::ChainIdMismatch.into())
+            }
+            // only pre-EIP-155 legacy transactions can omit the chain id
+            None if self.require_chain_id => {
+                return Err(InvalidPoolTransactionError::MissingChainId)
+            }
+            _ => {}
         }
 
         if transaction.is_eip7702() {
@@ -974,6 +987,8 @@ pub struct EthTransactionValidatorBuilder<Client, Evm> {
     tx_fee_cap: Option<u128>,
     /// Minimum priority fee to enforce for acceptance into the pool.
     minimum_priority_fee: Option<u128>,
+    /// Whether to reject legacy transactions without a chain ID.
+    require_chain_id: bool,
     /// Determines how many additional tasks to spawn
     ///
     /// Default is 1
@@ -1030,6 +1045,7 @@ impl<Client, Evm> EthTransactionValidatorBuilder<Client, Evm> {
             client,
             evm_config,
             minimum_priority_fee: None,
+            require_chain_id: false,
             additional_tasks: 1,
             kzg_settings: EnvKzgSettings::Default,
             local_transactions_config: Default::default(),
@@ -1196,6 +1212,13 @@ impl<Client, Evm> EthTransactionValidatorBuilder<Client, Evm> {
         self
     }
 
+    /// Sets whether legacy transactions without a chain ID, i.e. transactions that are not replay
+    /// protected as per EIP-155, are rejected.
+    pub const fn with_require_chain_id(mut self, require_chain_id: bool) -> Self {
+        self.require_chain_id = require_chain_id;
+        self
+    }
+
     /// Sets the number of additional tasks to spawn.
     pub const fn with_additional_tasks(mut self, additional_tasks: usize) -> Self {
         self.additional_tasks = additional_tasks;
@@ -1262,6 +1285,7 @@ impl<Client, Evm> EthTransactionValidatorBuilder<Client, Evm> {
             block_gas_limit,
             tx_fee_cap,
             minimum_priority_fee,
+            require_chain_id,
             kzg_settings,
             local_transactions_config,
             max_tx_input_bytes,
@@ -1296,6 +1320,7 @@ impl<Client, Evm> EthTransactionValidatorBuilder<Client, Evm> {
             block_gas_limit,
             tx_fee_cap,
             minimum_priority_fee,
+            require_chain_id,
             blob_store: Box::new(blob_store),
             kzg_settings,
             local_transactions_config,
@@ -1428,12 +1453,12 @@ mod tests {
         blobstore::InMemoryBlobStore, error::PoolErrorKind, traits::PoolTransaction,
         CoinbaseTipOrdering, EthPooledTransaction, Pool, TransactionPool,
     };
-    use alloy_consensus::Transaction;
+    use alloy_consensus::{Signed, Transaction, TxLegacy};
     use alloy_eips::eip2718::Decodable2718;
-    use alloy_primitives::{hex, U256};
+    use alloy_primitives::{hex, Address, Signature, TxKind, U256};
     use reth_ethereum_primitives::PooledTransactionVariant;
     use reth_evm_ethereum::EthEvmConfig;
-    use reth_primitives_traits::SignedTransaction;
+    use reth_primitives_traits::{Recovered, SignedTransaction};
     use reth_provider::test_utils::{ExtendedAccount, MockEthProvider};
     use revm_primitives::eip3860::MAX_INITCODE_SIZE;
 
@@ -1889,4 +1914,37 @@ mod tests {
         let outcome = validator.validate_one(TransactionOrigin::External, transaction);
         assert!(outcome.is_valid()); // Should be valid because balance check is disabled
     }
+
+    #[tokio::test]
+    async fn invalid_on_missing_chain_id_when_required() {
+        let sender = Address::random();
+        let legacy = TxLegacy {
+            chain_id: None,
+            gas_price: 20_000_000_000,
+            gas_limit: 21_000,
+            to: TxKind::Call(Address::ZERO),
+            value: U256::from(1),
+            ..Default::default()
+        };
+        let tx = PooledTransactionVariant::Legacy(Signed::new_unhashed(
+            legacy,
+            Signature::test_signature(),
+        ));
+        let transaction = EthPooledTransaction::from_pooled(Recovered::new_unchecked(tx, sender));
+
+        let provider = MockEthProvider::default().with_genesis_block();
+        provider.add_account(sender, ExtendedAccount::new(0, U256::MAX));
+
+        // Transactions without a chain id are accepted by default
+        let validator = EthTransactionValidatorBuilder::new(provider.clone(), test_evm_config())
+            .build(InMemoryBlobStore::default());
+        let outcome = validator.validate_one(TransactionOrigin::External, transaction.clone());
+        assert!(outcome.is_valid());
+
+        let validator = EthTransactionValidatorBuilder::new(provider, test_evm_config())
+            .with_require_chain_id(true)
+            .build(InMemoryBlobStore::default());
+        let outcome = validator.validate_one(TransactionOrigin::External, transaction);
+        assert!(matches!(outcome.as_invalid(), Some(InvalidPoolTransactionError::MissingChainId)));
+    }
 }
@@ -1,7 +1,8 @@
+use alloy_eips::eip2718::Encodable2718;
 use assert_matches::assert_matches;
 use reth_transaction_pool::{
     test_utils::{MockTransactionFactory, TestPoolBuilder},
-    TransactionOrigin, TransactionPool,
+    PoolTransaction, TransactionOrigin, TransactionPool,
 };
 
 #[tokio::test(flavor = "multi_thread")]
@@ -23,3 +24,69 @@ async fn txpool_new_pending_txs() {
     assert_matches!(added_result, Ok(outcome) if outcome.hash == *transaction.transaction.get_hash());
     assert_matches!(best_txns.next(), Some(tx) if tx.transaction.get_hash() == transaction.transaction.get_hash());
 }
+
+#[tokio::test(flavor = "multi_thread")]
+async fn txpool_transaction_by_sender_nonce() {
+    let txpool = TestPoolBuilder::default();
+    let mut mock_tx_factory = MockTransactionFactory::default();
+    let transaction = mock_tx_factory.create_eip1559();
+    let sender = *transaction.transaction.get_sender();
+    let nonce = *transaction.transaction.get_nonce();
+
+    assert_eq!(txpool.transaction_by_sender_nonce(sender, nonce), None);
+
+    txpool
+        .add_transaction(TransactionOrigin::External, transaction.transaction.clone())
+        .await
+        .unwrap();
+
+    assert_eq!(
+        txpool.transaction_by_sender_nonce(sender, nonce),
+        Some(*transaction.transaction.get_hash())
+    );
+    assert_eq!(txpool.transaction_by_sender_nonce(sender, nonce + 1), None);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn txpool_export_pending_rlp() {
+    let txpool = TestPoolBuilder::default();
+    let mut mock_tx_factory = MockTransactionFactory::default();
+    let transaction = mock_tx_factory.create_eip1559();
+
+    assert!(txpool.export_pending_rlp().await.is_empty());
+
+    txpool
+        .add_transaction(TransactionOrigin::External, transaction.transaction.clone())
+        .await
+        .unwrap();
+
+    let export = txpool.export_pending_rlp();
+
+    // transactions added after the snapshot are not included
+    let other = mock_tx_factory.create_eip1559();
+    txpool.add_transaction(TransactionOrigin::External, other.transaction.clone()).await.unwrap();
+
+    let exported = export.await;
+    assert_eq!(exported.len(), 1);
+    assert_eq!(
+        exported[0].as_ref(),
+        transaction.transaction.clone_into_consensus().encoded_2718().as_slice()
+    );
+    assert_eq!(txpool.export_pending_rlp().await.len(), 2);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn txpool_would_accept() {
+    let txpool = TestPoolBuilder::default();
+    let mut mock_tx_factory = MockTransactionFactory::default();
+    let transaction = mock_tx_factory.create_eip1559();
+
+    assert_matches!(txpool.would_accept(transaction.transaction.clone()).await, Ok(()));
+    assert!(txpool.is_empty());
+
+    txpool
+        .add_transaction(TransactionOrigin::External, transaction.transaction.clone())
+        .await
+        .unwrap();
+    assert_matches!(txpool.would_accept(transaction.transaction.clone()).await, Err(_));
+}
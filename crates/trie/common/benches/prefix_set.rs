@@ -0,0 +1,61 @@
+#![allow(missing_docs, unreachable_pub)]
+use criterion::{criterion_group, criterion_main, BatchSize, BenchmarkId, Criterion};
+use proptest::{prelude::*, strategy::ValueTree, test_runner::TestRunner};
+use reth_trie_common::{
+    prefix_set::{DedupPrefixSetMut, PrefixSetMut},
+    Nibbles,
+};
+
+/// Generate `size` keys out of `unique` distinct keys, so that the duplicate volume grows with
+/// `size / unique`.
+fn generate_keys(size: usize, unique: usize) -> Vec<Nibbles> {
+    let mut runner = TestRunner::deterministic();
+    let distinct = proptest::collection::vec(any::<[u8; 32]>(), unique)
+        .new_tree(&mut runner)
+        .unwrap()
+        .current()
+        .into_iter()
+        .map(Nibbles::unpack)
+        .collect::<Vec<_>>();
+    (0..size).map(|i| distinct[i % unique]).collect()
+}
+
+pub fn prefix_set_insert_and_freeze(c: &mut Criterion) {
+    let mut group = c.benchmark_group("Prefix Set Insert and Freeze");
+
+    for (size, unique) in [(10_000, 10_000), (100_000, 1_000), (1_000_000, 1_000)] {
+        let keys = generate_keys(size, unique);
+        let bench_name = format!("size {size} | unique {unique}");
+
+        group.bench_function(BenchmarkId::new("PrefixSetMut", &bench_name), |b| {
+            b.iter_batched(
+                || keys.clone(),
+                |keys| {
+                    let mut set = PrefixSetMut::default();
+                    for key in keys {
+                        set.insert(key);
+                    }
+                    set.freeze()
+                },
+                BatchSize::LargeInput,
+            );
+        });
+
+        group.bench_function(BenchmarkId::new("DedupPrefixSetMut", &bench_name), |b| {
+            b.iter_batched(
+                || keys.clone(),
+                |keys| {
+                    let mut set = DedupPrefixSetMut::default();
+                    for key in keys {
+                        set.insert(key);
+                    }
+                    set.freeze()
+                },
+                BatchSize::LargeInput,
+            );
+        });
+    }
+}
+
+criterion_group!(prefix_set, prefix_set_insert_and_freeze);
+criterion_main!(prefix_set);
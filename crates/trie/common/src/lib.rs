@@ -45,8 +45,8 @@ pub use key::{KeccakKeyHasher, KeyHasher};
 
 mod nibbles;
 pub use nibbles::{
-    depth_first_cmp, Nibbles, PackedStoredNibbles, PackedStoredNibblesSubKey, StoredNibbles,
-    StoredNibblesSubKey,
+    depth_first_cmp, HexPrefixEncoding, Nibbles, PackedStoredNibbles, PackedStoredNibblesSubKey,
+    StoredNibbles, StoredNibblesSubKey,
 };
 
 mod storage;
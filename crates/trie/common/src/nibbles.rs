@@ -23,6 +23,69 @@ pub fn depth_first_cmp(a: &Nibbles, b: &Nibbles) -> Ordering {
     a.get_unchecked(common_prefix_len).cmp(&b.get_unchecked(common_prefix_len))
 }
 
+/// Hex-prefix (HP) encoding of [`Nibbles`] as specified in Appendix C of the Ethereum yellow
+/// paper.
+///
+/// The first nibble of the encoding holds the node type flag (leaf or extension) and the parity
+/// of the path length. This is the path encoding used by leaf and extension nodes of the Merkle
+/// Patricia Trie and is distinct from the raw packing used for storage.
+pub trait HexPrefixEncoding: Sized {
+    /// Returns the hex-prefix encoding of the nibbles, flagged as a leaf if `is_leaf` is set and
+    /// as an extension otherwise.
+    fn to_hex_prefix_encoded(&self, is_leaf: bool) -> Vec<u8>;
+
+    /// Decodes hex-prefix encoded nibbles, returning the nibbles and whether they were flagged as
+    /// a leaf.
+    ///
+    /// Returns `None` if the input is empty, the flag nibble is invalid, the padding nibble of an
+    /// even-length path is not zero, or the path is longer than 64 nibbles.
+    fn from_hex_prefix_encoded(encoded: &[u8]) -> Option<(Self, bool)>;
+}
+
+impl HexPrefixEncoding for Nibbles {
+    fn to_hex_prefix_encoded(&self, is_leaf: bool) -> Vec<u8> {
+        let odd = !self.len().is_multiple_of(2);
+        let flag = (u8::from(is_leaf) << 1) | u8::from(odd);
+
+        let mut encoded = Vec::with_capacity(self.len() / 2 + 1);
+        let mut nibbles = self.iter();
+        let first = if odd { nibbles.next().unwrap_or_default() } else { 0 };
+        encoded.push((flag << 4) | first);
+        while let Some(hi) = nibbles.next() {
+            let lo = nibbles.next().unwrap_or_default();
+            encoded.push((hi << 4) | lo);
+        }
+        encoded
+    }
+
+    fn from_hex_prefix_encoded(encoded: &[u8]) -> Option<(Self, bool)> {
+        let (&first, rest) = encoded.split_first()?;
+        let flag = first >> 4;
+        if flag > 3 {
+            return None
+        }
+        let is_leaf = flag & 0b10 != 0;
+        let odd = flag & 0b01 != 0;
+
+        let len = rest.len() * 2 + usize::from(odd);
+        if len > 64 {
+            return None
+        }
+
+        let mut nibbles = Vec::with_capacity(len);
+        if odd {
+            nibbles.push(first & 0x0f);
+        } else if first & 0x0f != 0 {
+            return None
+        }
+        for byte in rest {
+            nibbles.push(byte >> 4);
+            nibbles.push(byte & 0x0f);
+        }
+        Some((Self::from_nibbles_unchecked(nibbles), is_leaf))
+    }
+}
+
 /// The representation of nibbles of the merkle trie stored in the database.
 #[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash, derive_more::Index)]
 #[cfg_attr(any(test, feature = "serde"), derive(serde::Serialize, serde::Deserialize))]
@@ -304,6 +367,40 @@ mod tests {
     use bytes::BytesMut;
     use reth_codecs::Compact;
 
+    #[test]
+    fn test_hex_prefix_encoding() {
+        // Test vectors from the yellow paper (Appendix C) and the Ethereum wiki.
+        let cases: [(&[u8], bool, &[u8]); 6] = [
+            (&[0x1, 0x2, 0x3, 0x4, 0x5], false, &[0x11, 0x23, 0x45]),
+            (&[0x0, 0x1, 0x2, 0x3, 0x4, 0x5], false, &[0x00, 0x01, 0x23, 0x45]),
+            (&[0x0, 0xf, 0x1, 0xc, 0xb, 0x8], true, &[0x20, 0x0f, 0x1c, 0xb8]),
+            (&[0xf, 0x1, 0xc, 0xb, 0x8], true, &[0x3f, 0x1c, 0xb8]),
+            (&[], false, &[0x00]),
+            (&[], true, &[0x20]),
+        ];
+
+        for (nibbles, is_leaf, expected) in cases {
+            let nibbles = Nibbles::from_nibbles(nibbles);
+            assert_eq!(nibbles.to_hex_prefix_encoded(is_leaf), expected);
+            assert_eq!(Nibbles::from_hex_prefix_encoded(expected), Some((nibbles, is_leaf)));
+        }
+
+        // empty input, invalid flag, and non-zero padding of an even path
+        assert_eq!(Nibbles::from_hex_prefix_encoded(&[]), None);
+        assert_eq!(Nibbles::from_hex_prefix_encoded(&[0x40]), None);
+        assert_eq!(Nibbles::from_hex_prefix_encoded(&[0x21, 0x23]), None);
+
+        // paths of up to 64 nibbles
+        let mut encoded = vec![0x20; 33];
+        let (nibbles, is_leaf) = Nibbles::from_hex_prefix_encoded(&encoded).unwrap();
+        assert_eq!((nibbles.len(), is_leaf), (64, true));
+        encoded[0] = 0x30;
+        assert_eq!(Nibbles::from_hex_prefix_encoded(&encoded), None);
+        encoded[0] = 0x20;
+        encoded.push(0);
+        assert_eq!(Nibbles::from_hex_prefix_encoded(&encoded), None);
+    }
+
     #[test]
     fn test_stored_nibbles_from_nibbles() {
         let nibbles = Nibbles::from_nibbles_unchecked(vec![0x02, 0x04, 0x06]);
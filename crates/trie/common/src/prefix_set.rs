@@ -1,7 +1,14 @@
 use crate::Nibbles;
-use alloc::{sync::Arc, vec::Vec};
-use alloy_primitives::map::{B256Map, B256Set};
-use core::ops::Range;
+use alloc::{
+    collections::{BTreeSet, BinaryHeap},
+    sync::Arc,
+    vec::Vec,
+};
+use alloy_primitives::{
+    map::{B256Map, B256Set},
+    B256,
+};
+use core::{cmp::Reverse, ops::Range};
 
 /// Collection of mutable prefix sets.
 #[derive(Clone, Default, Debug)]
@@ -32,6 +39,24 @@ impl TriePrefixSetsMut {
         self.destroyed_accounts.extend(other.destroyed_accounts);
     }
 
+    /// Extends the storage prefix sets with the given `(hashed_address, nibbles)` pairs.
+    ///
+    /// The input does not need to be grouped by address, but consecutive pairs for the same address
+    /// only require a single map lookup.
+    pub fn extend_storage<I>(&mut self, items: I)
+    where
+        I: IntoIterator<Item = (B256, Nibbles)>,
+    {
+        let mut items = items.into_iter().peekable();
+        while let Some((hashed_address, nibbles)) = items.next() {
+            let prefix_set = self.storage_prefix_sets.entry(hashed_address).or_default();
+            prefix_set.insert(nibbles);
+            while let Some((_, nibbles)) = items.next_if(|(next, _)| *next == hashed_address) {
+                prefix_set.insert(nibbles);
+            }
+        }
+    }
+
     /// Returns a `TriePrefixSets` with the same elements as these sets.
     ///
     /// If not yet sorted, the elements will be sorted and deduplicated.
@@ -67,6 +92,112 @@ pub struct TriePrefixSets {
     pub destroyed_accounts: B256Set,
 }
 
+impl TriePrefixSets {
+    /// Merges `other` into these prefix sets without converting back to [`TriePrefixSetsMut`].
+    ///
+    /// The account prefix sets and the storage prefix sets of addresses present on both sides are
+    /// merged with [`PrefixSet::merge_many`], keeping the keys sorted. Destroyed accounts are
+    /// combined.
+    pub fn merge(mut self, other: Self) -> Self {
+        self.account_prefix_set =
+            PrefixSet::merge_many([self.account_prefix_set, other.account_prefix_set]);
+        for (hashed_address, storage_prefix_set) in other.storage_prefix_sets {
+            let merged = match self.storage_prefix_sets.remove(&hashed_address) {
+                Some(existing) => PrefixSet::merge_many([existing, storage_prefix_set]),
+                None => storage_prefix_set,
+            };
+            self.storage_prefix_sets.insert(hashed_address, merged);
+        }
+        self.destroyed_accounts.extend(other.destroyed_accounts);
+        self
+    }
+}
+
+#[cfg(any(test, feature = "test-utils"))]
+impl TriePrefixSets {
+    /// Returns the keys that are present in one of the prefix sets but not in the other.
+    ///
+    /// Storage prefix sets of addresses only present on one side are compared against an empty
+    /// set.
+    pub fn diff(&self, other: &Self) -> PrefixSetsDiff {
+        let mut storage_prefix_sets = B256Map::default();
+        let empty = PrefixSet::default();
+        for hashed_address in
+            self.storage_prefix_sets.keys().chain(other.storage_prefix_sets.keys())
+        {
+            if storage_prefix_sets.contains_key(hashed_address) {
+                continue
+            }
+            let ours = self.storage_prefix_sets.get(hashed_address).unwrap_or(&empty);
+            let theirs = other.storage_prefix_sets.get(hashed_address).unwrap_or(&empty);
+            let diff = ours.diff(theirs);
+            if !diff.is_empty() {
+                storage_prefix_sets.insert(*hashed_address, diff);
+            }
+        }
+
+        PrefixSetsDiff {
+            account_prefix_set: self.account_prefix_set.diff(&other.account_prefix_set),
+            storage_prefix_sets,
+            destroyed_accounts_only_in_self: self
+                .destroyed_accounts
+                .difference(&other.destroyed_accounts)
+                .copied()
+                .collect(),
+            destroyed_accounts_only_in_other: other
+                .destroyed_accounts
+                .difference(&self.destroyed_accounts)
+                .copied()
+                .collect(),
+        }
+    }
+}
+
+/// Difference between two [`TriePrefixSets`], see [`TriePrefixSets::diff`].
+#[cfg(any(test, feature = "test-utils"))]
+#[derive(Clone, Default, Debug, PartialEq, Eq)]
+pub struct PrefixSetsDiff {
+    /// Difference between the account prefix sets.
+    pub account_prefix_set: PrefixSetDiff,
+    /// Difference between the storage prefix sets, for every hashed address where they differ.
+    pub storage_prefix_sets: B256Map<PrefixSetDiff>,
+    /// Destroyed accounts only present in the first prefix sets.
+    pub destroyed_accounts_only_in_self: B256Set,
+    /// Destroyed accounts only present in the second prefix sets.
+    pub destroyed_accounts_only_in_other: B256Set,
+}
+
+#[cfg(any(test, feature = "test-utils"))]
+impl PrefixSetsDiff {
+    /// Returns `true` if both prefix sets invalidate the same keys.
+    pub fn is_empty(&self) -> bool {
+        self.account_prefix_set.is_empty() &&
+            self.storage_prefix_sets.is_empty() &&
+            self.destroyed_accounts_only_in_self.is_empty() &&
+            self.destroyed_accounts_only_in_other.is_empty()
+    }
+}
+
+/// Difference between two [`PrefixSet`]s, see [`PrefixSet::diff`].
+#[cfg(any(test, feature = "test-utils"))]
+#[derive(Clone, Default, Debug, PartialEq, Eq)]
+pub struct PrefixSetDiff {
+    /// Whether only one of the sets considers all keys changed.
+    pub all_differs: bool,
+    /// Keys only present in the first set, in sorted order.
+    pub only_in_self: Vec<Nibbles>,
+    /// Keys only present in the second set, in sorted order.
+    pub only_in_other: Vec<Nibbles>,
+}
+
+#[cfg(any(test, feature = "test-utils"))]
+impl PrefixSetDiff {
+    /// Returns `true` if both sets are equal.
+    pub const fn is_empty(&self) -> bool {
+        !self.all_differs && self.only_in_self.is_empty() && self.only_in_other.is_empty()
+    }
+}
+
 /// A container for efficiently storing and checking for the presence of key prefixes.
 ///
 /// This data structure stores a set of `Nibbles` and provides methods to insert
@@ -153,6 +284,20 @@ impl PrefixSetMut {
         !self.all && self.keys.is_empty()
     }
 
+    /// Returns `true` if the set considers all keys changed.
+    pub const fn is_all(&self) -> bool {
+        self.all
+    }
+
+    /// Returns an estimate of the heap memory held by this set, in bytes.
+    ///
+    /// [`Nibbles`] stores its nibbles inline, so every allocated slot of the inner vec accounts for
+    /// `size_of::<Nibbles>()` bytes. Spare capacity is included since it remains allocated until
+    /// the set is frozen.
+    pub const fn memory_footprint(&self) -> usize {
+        self.keys.capacity() * core::mem::size_of::<Nibbles>()
+    }
+
     /// Clears the inner vec for reuse, setting `all` to `false`.
     pub fn clear(&mut self) {
         self.all = false;
@@ -175,6 +320,102 @@ impl PrefixSetMut {
     }
 }
 
+/// A mutable prefix set that deduplicates keys on insertion.
+///
+/// Unlike [`PrefixSetMut`], which defers deduplication to [`PrefixSetMut::freeze`], this keeps
+/// the keys in a [`BTreeSet`], so memory is bounded by the number of unique keys at the cost of a
+/// more expensive insert. This should only be preferred over [`PrefixSetMut`] if the volume of
+/// duplicate keys is expected to be large, e.g. for huge reorgs.
+///
+/// ```
+/// use reth_trie_common::{prefix_set::DedupPrefixSetMut, Nibbles};
+///
+/// let mut prefix_set_mut = DedupPrefixSetMut::default();
+/// prefix_set_mut.insert(Nibbles::from_nibbles_unchecked(&[0xa, 0xb]));
+/// prefix_set_mut.insert(Nibbles::from_nibbles_unchecked(&[0xa, 0xb]));
+/// assert_eq!(prefix_set_mut.len(), 1);
+/// let mut prefix_set = prefix_set_mut.freeze();
+/// assert!(prefix_set.contains(&Nibbles::from_nibbles_unchecked([0xa, 0xb])));
+/// ```
+#[derive(PartialEq, Eq, Clone, Default, Debug)]
+pub struct DedupPrefixSetMut {
+    /// Flag indicating that any entry should be considered changed.
+    /// If set, the keys will be discarded.
+    all: bool,
+    keys: BTreeSet<Nibbles>,
+}
+
+impl<I> From<I> for DedupPrefixSetMut
+where
+    I: IntoIterator<Item = Nibbles>,
+{
+    fn from(value: I) -> Self {
+        Self { all: false, keys: value.into_iter().collect() }
+    }
+}
+
+impl DedupPrefixSetMut {
+    /// Create [`DedupPrefixSetMut`] that considers all key changed.
+    pub const fn all() -> Self {
+        Self { all: true, keys: BTreeSet::new() }
+    }
+
+    /// Inserts the given `nibbles` into the set, if not already present.
+    pub fn insert(&mut self, nibbles: Nibbles) {
+        if !self.all {
+            self.keys.insert(nibbles);
+        }
+    }
+
+    /// Extend prefix set with contents of another prefix set.
+    pub fn extend(&mut self, other: Self) {
+        self.all |= other.all;
+        if self.all {
+            self.keys.clear();
+        } else {
+            self.keys.extend(other.keys);
+        }
+    }
+
+    /// Extend prefix set keys with contents of provided iterator.
+    pub fn extend_keys<I>(&mut self, keys: I)
+    where
+        I: IntoIterator<Item = Nibbles>,
+    {
+        if !self.all {
+            self.keys.extend(keys);
+        }
+    }
+
+    /// Returns the number of unique elements in the set.
+    pub fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    /// Returns `true` if the set is empty and `all` flag is not set.
+    pub fn is_empty(&self) -> bool {
+        !self.all && self.keys.is_empty()
+    }
+
+    /// Clears the inner set for reuse, setting `all` to `false`.
+    pub fn clear(&mut self) {
+        self.all = false;
+        self.keys.clear();
+    }
+
+    /// Returns a `PrefixSet` with the same elements as this set.
+    ///
+    /// The keys are already sorted and unique, so this is equivalent to [`PrefixSetMut::freeze`]
+    /// for the same keys.
+    pub fn freeze(self) -> PrefixSet {
+        if self.all {
+            PrefixSet { index: 0, all: true, keys: Arc::new(Vec::new()) }
+        } else {
+            PrefixSet { index: 0, all: false, keys: Arc::new(self.keys.into_iter().collect()) }
+        }
+    }
+}
+
 /// A sorted prefix set that has an immutable _sorted_ list of unique keys.
 ///
 /// See also [`PrefixSetMut::freeze`].
@@ -261,6 +502,27 @@ impl PrefixSet {
         self.keys.iter()
     }
 
+    /// Returns an iterator over the keys greater than or equal to `start`, regardless of cursor
+    /// position.
+    ///
+    /// The start position is found with a binary search, so iteration can be resumed from a
+    /// checkpoint without scanning the preceding keys. If the `all` flag is set, the set holds no
+    /// explicit keys and the iterator yields nothing.
+    pub fn iter_from<'a>(&'a self, start: &Nibbles) -> impl Iterator<Item = &'a Nibbles> + 'a {
+        let position = self.keys.partition_point(|key| key < start);
+        self.keys[position..].iter()
+    }
+
+    /// Returns the number of keys in the set that start with the given prefix, regardless of
+    /// cursor position.
+    ///
+    /// The range of matching keys is found with a binary search. If the `all` flag is set, the set
+    /// holds no explicit keys and `0` is returned, even though every key is considered changed.
+    pub fn count_with_prefix(&self, prefix: &Nibbles) -> usize {
+        let start = self.keys.partition_point(|key| key < prefix);
+        self.keys[start..].partition_point(|key| key.starts_with(prefix))
+    }
+
     /// Returns true if every entry should be considered changed.
     pub const fn all(&self) -> bool {
         self.all
@@ -275,6 +537,116 @@ impl PrefixSet {
     pub fn is_empty(&self) -> bool {
         !self.all && self.keys.is_empty()
     }
+
+    /// Returns an estimate of the heap memory held by the keys of this set, in bytes.
+    ///
+    /// [`Nibbles`] stores its nibbles inline, so the estimate is the number of keys times
+    /// `size_of::<Nibbles>()`. The keys are shrunk to fit on [`PrefixSetMut::freeze`], so there is
+    /// no spare capacity to account for. The keys are shared between clones, so the full amount is
+    /// reported by every clone.
+    pub fn memory_footprint(&self) -> usize {
+        self.keys.len() * core::mem::size_of::<Nibbles>()
+    }
+
+    /// Splits the set at the given `pivot` into the keys strictly lower than the pivot and the
+    /// keys greater than or equal to it.
+    ///
+    /// If the `all` flag is set, both halves have it set as well. The index of both returned sets
+    /// is reset.
+    pub fn split_at(&self, pivot: &Nibbles) -> (Self, Self) {
+        if self.all {
+            return (
+                Self { index: 0, all: true, keys: Arc::new(Vec::new()) },
+                Self { index: 0, all: true, keys: Arc::new(Vec::new()) },
+            )
+        }
+
+        let mid = self.keys.partition_point(|key| key < pivot);
+        let (lower, upper) = self.keys.split_at(mid);
+        (
+            Self { index: 0, all: false, keys: Arc::new(lower.to_vec()) },
+            Self { index: 0, all: false, keys: Arc::new(upper.to_vec()) },
+        )
+    }
+
+    /// Merges the given sets into a single sorted and deduplicated set.
+    ///
+    /// The keys of every set are already sorted, so they are k-way merged instead of being
+    /// collected and sorted again. If any of the sets has the `all` flag set, the result has it set
+    /// as well. The index of the returned set is reset.
+    pub fn merge_many(sets: impl IntoIterator<Item = Self>) -> Self {
+        let mut inputs = Vec::new();
+        for set in sets {
+            if set.all {
+                return Self { index: 0, all: true, keys: Arc::new(Vec::new()) }
+            }
+            if !set.keys.is_empty() {
+                inputs.push(set.keys);
+            }
+        }
+
+        if inputs.len() <= 1 {
+            return Self { index: 0, all: false, keys: inputs.pop().unwrap_or_default() }
+        }
+
+        let mut keys = Vec::with_capacity(inputs.iter().map(|keys| keys.len()).sum());
+        let mut heap = BinaryHeap::with_capacity(inputs.len());
+        for (input, input_keys) in inputs.iter().enumerate() {
+            heap.push(Reverse((&input_keys[0], input, 0)));
+        }
+
+        while let Some(Reverse((key, input, position))) = heap.pop() {
+            if keys.last() != Some(key) {
+                keys.push(*key);
+            }
+            if let Some(next) = inputs[input].get(position + 1) {
+                heap.push(Reverse((next, input, position + 1)));
+            }
+        }
+
+        keys.shrink_to_fit();
+        Self { index: 0, all: false, keys: Arc::new(keys) }
+    }
+
+    /// Converts the set back into a [`PrefixSetMut`], so more keys can be inserted before it is
+    /// frozen again.
+    ///
+    /// The `all` flag is preserved. The keys are moved out if this is the only reference to them,
+    /// otherwise, e.g. if the set was cloned, all keys are cloned.
+    pub fn thaw(self) -> PrefixSetMut {
+        PrefixSetMut { all: self.all, keys: Arc::unwrap_or_clone(self.keys) }
+    }
+}
+
+#[cfg(any(test, feature = "test-utils"))]
+impl PrefixSet {
+    /// Returns the keys that are present in one of the sets but not in the other.
+    ///
+    /// Relies on the keys of both sets being sorted and unique, as guaranteed by
+    /// [`PrefixSetMut::freeze`].
+    pub fn diff(&self, other: &Self) -> PrefixSetDiff {
+        let mut diff = PrefixSetDiff { all_differs: self.all != other.all, ..Default::default() };
+        let mut ours = self.keys.iter().peekable();
+        let mut theirs = other.keys.iter().peekable();
+        loop {
+            match (ours.peek(), theirs.peek()) {
+                (Some(a), Some(b)) => match a.cmp(b) {
+                    core::cmp::Ordering::Less => diff.only_in_self.extend(ours.next().copied()),
+                    core::cmp::Ordering::Greater => {
+                        diff.only_in_other.extend(theirs.next().copied())
+                    }
+                    core::cmp::Ordering::Equal => {
+                        ours.next();
+                        theirs.next();
+                    }
+                },
+                (Some(_), None) => diff.only_in_self.extend(ours.next().copied()),
+                (None, Some(_)) => diff.only_in_other.extend(theirs.next().copied()),
+                (None, None) => break,
+            }
+        }
+        diff
+    }
 }
 
 impl<'a> IntoIterator for &'a PrefixSet {
@@ -289,6 +661,19 @@ impl<'a> IntoIterator for &'a PrefixSet {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_prefix_set_mut_is_all() {
+        let mut prefix_set_mut = PrefixSetMut::default();
+        prefix_set_mut.insert(Nibbles::from_nibbles([1, 2, 3]));
+        assert!(!prefix_set_mut.is_all());
+
+        prefix_set_mut.extend(PrefixSetMut::all());
+        assert!(prefix_set_mut.is_all());
+
+        prefix_set_mut.clear();
+        assert!(!prefix_set_mut.is_all());
+    }
+
     #[test]
     fn test_contains_with_multiple_inserts_and_duplicates() {
         let mut prefix_set_mut = PrefixSetMut::default();
@@ -304,6 +689,116 @@ mod tests {
         assert_eq!(prefix_set.len(), 3); // Length should be 3 (excluding duplicate)
     }
 
+    #[test]
+    fn test_split_at() {
+        let prefix_set = PrefixSetMut::from([
+            Nibbles::from_nibbles([1, 2]),
+            Nibbles::from_nibbles([1, 2, 3]),
+            Nibbles::from_nibbles([1, 3]),
+            Nibbles::from_nibbles([2]),
+            Nibbles::from_nibbles([2, 0, 1]),
+        ])
+        .freeze();
+
+        let pivot = Nibbles::from_nibbles([1, 3]);
+        let (lower, upper) = prefix_set.split_at(&pivot);
+        assert_eq!(
+            lower.keys.as_slice(),
+            [Nibbles::from_nibbles([1, 2]), Nibbles::from_nibbles([1, 2, 3])]
+        );
+        assert_eq!(
+            upper.keys.as_slice(),
+            [
+                Nibbles::from_nibbles([1, 3]),
+                Nibbles::from_nibbles([2]),
+                Nibbles::from_nibbles([2, 0, 1])
+            ]
+        );
+        assert!(lower.keys.iter().all(|key| key < &pivot));
+        assert!(upper.keys.iter().all(|key| key >= &pivot));
+
+        let (lower, upper) = prefix_set.split_at(&Nibbles::default());
+        assert!(lower.is_empty());
+        assert_eq!(upper.len(), prefix_set.len());
+
+        let (lower, upper) = PrefixSetMut::all().freeze().split_at(&pivot);
+        assert!(lower.all && upper.all);
+    }
+
+    #[test]
+    fn test_iter_from() {
+        let prefix_set = PrefixSetMut::from([
+            Nibbles::from_nibbles([0xa]),
+            Nibbles::from_nibbles([0xa, 0x0, 0x1]),
+            Nibbles::from_nibbles([0xb, 0x3]),
+            Nibbles::from_nibbles([0xc]),
+        ])
+        .freeze();
+
+        // starts at the first key that is not less than the given one
+        let keys = prefix_set.iter_from(&Nibbles::from_nibbles([0xa, 0x0])).collect::<Vec<_>>();
+        assert_eq!(
+            keys,
+            [
+                &Nibbles::from_nibbles([0xa, 0x0, 0x1]),
+                &Nibbles::from_nibbles([0xb, 0x3]),
+                &Nibbles::from_nibbles([0xc])
+            ]
+        );
+        // an exact match is included
+        assert_eq!(prefix_set.iter_from(&Nibbles::from_nibbles([0xb, 0x3])).count(), 2);
+        assert!(prefix_set.iter_from(&Nibbles::default()).eq(prefix_set.iter()));
+        assert_eq!(prefix_set.iter_from(&Nibbles::from_nibbles([0xc, 0x0])).count(), 0);
+
+        assert_eq!(PrefixSetMut::all().freeze().iter_from(&Nibbles::default()).count(), 0);
+    }
+
+    #[test]
+    fn test_count_with_prefix() {
+        let prefix_set = PrefixSetMut::from([
+            Nibbles::from_nibbles([0x4]),
+            Nibbles::from_nibbles([0x4, 0x5]),
+            Nibbles::from_nibbles([0x4, 0x5, 0x6]),
+            Nibbles::from_nibbles([0x4, 0x6]),
+            Nibbles::from_nibbles([0x5, 0x4]),
+            Nibbles::from_nibbles([0x5, 0x4]), // Duplicate
+        ])
+        .freeze();
+
+        // the prefix itself counts
+        assert_eq!(prefix_set.count_with_prefix(&Nibbles::from_nibbles([0x4])), 4);
+        assert_eq!(prefix_set.count_with_prefix(&Nibbles::from_nibbles([0x4, 0x5])), 2);
+        assert_eq!(prefix_set.count_with_prefix(&Nibbles::from_nibbles([0x4, 0x5, 0x6, 0x7])), 0);
+        // keys sharing the nibbles at another position don't count
+        assert_eq!(prefix_set.count_with_prefix(&Nibbles::from_nibbles([0x5, 0x4])), 1);
+        assert_eq!(prefix_set.count_with_prefix(&Nibbles::from_nibbles([0x6])), 0);
+        assert_eq!(prefix_set.count_with_prefix(&Nibbles::default()), 5);
+
+        assert_eq!(PrefixSetMut::all().freeze().count_with_prefix(&Nibbles::default()), 0);
+    }
+
+    #[test]
+    fn test_dedup_freeze_matches_vec() {
+        let keys = [[1, 2, 3], [4, 5, 6], [1, 2, 4], [1, 2, 3], [4, 5, 6]]
+            .into_iter()
+            .map(Nibbles::from_nibbles)
+            .collect::<Vec<_>>();
+
+        let mut dedup = DedupPrefixSetMut::default();
+        dedup.extend_keys(keys.iter().copied());
+        assert_eq!(dedup.len(), 3);
+
+        let dedup = dedup.freeze();
+        let vec = PrefixSetMut::from(keys).freeze();
+        assert_eq!(dedup.keys, vec.keys);
+        assert!(!dedup.all);
+
+        let mut all = DedupPrefixSetMut::default();
+        all.insert(Nibbles::from_nibbles([1]));
+        all.extend(DedupPrefixSetMut::all());
+        assert!(all.freeze().all);
+    }
+
     #[test]
     fn test_freeze_shrinks_capacity() {
         let mut prefix_set_mut = PrefixSetMut::default();
@@ -343,10 +838,165 @@ mod tests {
         assert_eq!(prefix_set.keys.capacity(), 3); // Capacity should be 3 after shrinking
     }
 
+    #[test]
+    fn test_memory_footprint() {
+        let mut prefix_set_mut = PrefixSetMut::with_capacity(10);
+        prefix_set_mut.insert(Nibbles::from_nibbles([1, 2, 3]));
+        prefix_set_mut.insert(Nibbles::from_nibbles([1, 2, 3])); // Duplicate
+        assert_eq!(prefix_set_mut.memory_footprint(), 10 * core::mem::size_of::<Nibbles>());
+
+        let prefix_set = prefix_set_mut.freeze();
+        assert_eq!(prefix_set.memory_footprint(), core::mem::size_of::<Nibbles>());
+        assert_eq!(PrefixSetMut::all().freeze().memory_footprint(), 0);
+    }
+
+    #[test]
+    fn test_extend_storage() {
+        let address_a = B256::with_last_byte(1);
+        let address_b = B256::with_last_byte(2);
+
+        let mut prefix_sets = TriePrefixSetsMut::default();
+        prefix_sets.extend_storage([
+            (address_a, Nibbles::from_nibbles([1])),
+            (address_a, Nibbles::from_nibbles([2])),
+            (address_b, Nibbles::from_nibbles([3])),
+            (address_a, Nibbles::from_nibbles([4])),
+            (address_b, Nibbles::from_nibbles([3])),
+        ]);
+
+        let prefix_sets = prefix_sets.freeze();
+        assert_eq!(prefix_sets.storage_prefix_sets.len(), 2);
+        assert_eq!(
+            prefix_sets.storage_prefix_sets[&address_a].iter().copied().collect::<Vec<_>>(),
+            vec![
+                Nibbles::from_nibbles([1]),
+                Nibbles::from_nibbles([2]),
+                Nibbles::from_nibbles([4])
+            ]
+        );
+        assert_eq!(
+            prefix_sets.storage_prefix_sets[&address_b].iter().copied().collect::<Vec<_>>(),
+            vec![Nibbles::from_nibbles([3])]
+        );
+    }
+
+    #[test]
+    fn test_trie_prefix_sets_diff() {
+        let address_a = B256::with_last_byte(1);
+        let address_b = B256::with_last_byte(2);
+
+        let mut ours = TriePrefixSetsMut::default();
+        ours.account_prefix_set.insert(Nibbles::from_nibbles([1, 2]));
+        ours.account_prefix_set.insert(Nibbles::from_nibbles([3, 4]));
+        ours.storage_prefix_sets.entry(address_a).or_default().insert(Nibbles::from_nibbles([5]));
+        ours.destroyed_accounts.insert(address_a);
+        let ours = ours.freeze();
+
+        let mut theirs = TriePrefixSetsMut::default();
+        theirs.account_prefix_set.insert(Nibbles::from_nibbles([1, 2]));
+        theirs.account_prefix_set.insert(Nibbles::from_nibbles([5, 6]));
+        theirs.storage_prefix_sets.entry(address_a).or_default().insert(Nibbles::from_nibbles([5]));
+        theirs.storage_prefix_sets.insert(address_b, PrefixSetMut::all());
+        let theirs = theirs.freeze();
+
+        assert!(ours.diff(&ours).is_empty());
+
+        let diff = ours.diff(&theirs);
+        assert_eq!(diff.account_prefix_set.only_in_self, vec![Nibbles::from_nibbles([3, 4])]);
+        assert_eq!(diff.account_prefix_set.only_in_other, vec![Nibbles::from_nibbles([5, 6])]);
+        assert_eq!(diff.storage_prefix_sets.len(), 1);
+        assert!(diff.storage_prefix_sets[&address_b].all_differs);
+        assert_eq!(diff.destroyed_accounts_only_in_self, B256Set::from_iter([address_a]));
+        assert!(diff.destroyed_accounts_only_in_other.is_empty());
+    }
+
     #[test]
     fn test_prefix_set_all_extend() {
         let mut prefix_set_mut = PrefixSetMut::default();
         prefix_set_mut.extend(PrefixSetMut::all());
         assert!(prefix_set_mut.all);
     }
+
+    #[test]
+    fn test_merge_many() {
+        let freeze =
+            |keys: &[&[u8]]| PrefixSetMut::from(keys.iter().map(Nibbles::from_nibbles)).freeze();
+        let a = freeze(&[&[1, 2], &[3], &[7, 8]]);
+        let b = freeze(&[&[0], &[3], &[5, 6]]);
+        let c = freeze(&[&[1, 2], &[5, 6], &[9]]);
+
+        let mut merged = PrefixSet::merge_many([a.clone(), b, c]);
+        assert!(!merged.all());
+        assert_eq!(
+            merged.iter().copied().collect::<Vec<_>>(),
+            vec![
+                Nibbles::from_nibbles([0]),
+                Nibbles::from_nibbles([1, 2]),
+                Nibbles::from_nibbles([3]),
+                Nibbles::from_nibbles([5, 6]),
+                Nibbles::from_nibbles([7, 8]),
+                Nibbles::from_nibbles([9]),
+            ]
+        );
+        assert!(merged.contains(&Nibbles::from_nibbles([5])));
+
+        let merged = PrefixSet::merge_many([a, PrefixSetMut::all().freeze()]);
+        assert!(merged.all());
+    }
+
+    #[test]
+    fn test_thaw() {
+        let prefix_set =
+            PrefixSetMut::from([Nibbles::from_nibbles([1, 2]), Nibbles::from_nibbles([3])])
+                .freeze();
+        let shared = prefix_set.clone();
+
+        let mut prefix_set_mut = prefix_set.thaw();
+        assert!(!prefix_set_mut.is_all());
+        prefix_set_mut.insert(Nibbles::from_nibbles([0]));
+        prefix_set_mut.insert(Nibbles::from_nibbles([3]));
+
+        let mut refrozen = prefix_set_mut.freeze();
+        assert_eq!(refrozen.len(), 3);
+        assert!(refrozen.contains(&Nibbles::from_nibbles([0])));
+        assert!(refrozen.contains(&Nibbles::from_nibbles([1])));
+        assert_eq!(shared.len(), 2);
+
+        assert!(PrefixSetMut::all().freeze().thaw().is_all());
+    }
+
+    #[test]
+    fn test_trie_prefix_sets_merge() {
+        let freeze =
+            |keys: &[&[u8]]| PrefixSetMut::from(keys.iter().map(Nibbles::from_nibbles)).freeze();
+        let keys = |set: &PrefixSet| set.iter().copied().collect::<Vec<_>>();
+        let shared = B256::with_last_byte(1);
+        let only_left = B256::with_last_byte(2);
+        let only_right = B256::with_last_byte(3);
+
+        let left = TriePrefixSets {
+            account_prefix_set: freeze(&[&[1], &[4]]),
+            storage_prefix_sets: B256Map::from_iter([
+                (shared, freeze(&[&[1], &[3]])),
+                (only_left, freeze(&[&[5]])),
+            ]),
+            destroyed_accounts: B256Set::from_iter([only_left]),
+        };
+        let right = TriePrefixSets {
+            account_prefix_set: freeze(&[&[2], &[4]]),
+            storage_prefix_sets: B256Map::from_iter([
+                (shared, freeze(&[&[2], &[3]])),
+                (only_right, freeze(&[&[6]])),
+            ]),
+            destroyed_accounts: B256Set::from_iter([only_right]),
+        };
+
+        let merged = left.merge(right);
+        assert_eq!(keys(&merged.account_prefix_set), keys(&freeze(&[&[1], &[2], &[4]])));
+        assert_eq!(merged.storage_prefix_sets.len(), 3);
+        assert_eq!(keys(&merged.storage_prefix_sets[&shared]), keys(&freeze(&[&[1], &[2], &[3]])));
+        assert_eq!(keys(&merged.storage_prefix_sets[&only_left]), keys(&freeze(&[&[5]])));
+        assert_eq!(keys(&merged.storage_prefix_sets[&only_right]), keys(&freeze(&[&[6]])));
+        assert_eq!(merged.destroyed_accounts, B256Set::from_iter([only_left, only_right]));
+    }
 }